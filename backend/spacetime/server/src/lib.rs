@@ -1,155 +1,6366 @@
 // stolen from spacetimedb quickstart chat example and expanded to allow multiple group chats
+//
+// new columns are added without defaults, so publishing a schema change over an existing database
+// fails and the database has to be cleared (`publish_local.sh` publishes with `--delete-data`)
 
-use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use unicode_normalization::UnicodeNormalization;
+
+use spacetimedb::{ConnectionId, Filter, Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp};
 
 #[spacetimedb::table(name = user, public)]
 pub struct User {
     #[primary_key]
     identity: Identity,
     name: Option<String>,
+    // what other users see, never `Invisible` since invisible users are shown as `Offline`
+    presence: Presence,
+    last_seen: Timestamp,
+    // refreshed by `heartbeat`, users who stop sending heartbeats are marked offline by `sweep_presence`
+    last_active: Timestamp,
+    bio: Option<String>,
+    avatar_url: Option<String>,
+    status: Option<String>,
+    is_bot: bool,
+    // official accounts, only superadmins can change this
+    verified: bool,
+    // latest terms of service version the user agreed to, 0 if none
+    tos_accepted_version: u32,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Presence {
+    Offline,
+    Online,
+    Away,
+    DoNotDisturb,
+    Invisible,
+}
+
+// the presence a user picked with `set_presence`, only visible to themselves so invisibility isn't revealed
+#[spacetimedb::table(name = presence_preference, public)]
+pub struct PresencePreference {
+    #[primary_key]
+    identity: Identity,
+    presence: Presence
+}
+
+#[spacetimedb::client_visibility_filter]
+const PRESENCE_PREFERENCE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM presence_preference WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const PRESENCE_PREFERENCE_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT presence_preference.* FROM linked_device JOIN presence_preference ON linked_device.account = presence_preference.identity WHERE linked_device.device = :sender"
+);
+
+// issued to brand-new identities, which can't create group chats or invites until they solve it or
+// `CHALLENGE_COOLDOWN_SECS` have passed. A wrong answer replaces the question, after
+// `MAX_CHALLENGE_FAILURES` of them only the cooldown is left
+#[spacetimedb::table(name = challenge, public)]
+pub struct Challenge {
+    #[primary_key]
+    identity: Identity,
+    question: String,
+    issued_at: Timestamp,
+    failed_attempts: u32
+}
+
+#[spacetimedb::client_visibility_filter]
+const CHALLENGE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM challenge WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const CHALLENGE_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT challenge.* FROM linked_device JOIN challenge ON linked_device.account = challenge.identity WHERE linked_device.device = :sender"
+);
+
+// kept apart from `challenge` so the expected answer is never replicated to clients
+#[spacetimedb::table(name = challenge_answer)]
+pub struct ChallengeAnswer {
+    #[primary_key]
+    identity: Identity,
+    answer: String
+}
+
+// additional connection identities that belong to the same account, a linked device doesn't get a `User` of its own
+// and owns no rows, so every visibility filter matching `:sender` has a `_LINKED_DEVICE_` twin resolving the device to its account
+#[spacetimedb::table(name = linked_device, public)]
+pub struct LinkedDevice {
+    #[primary_key]
+    device: Identity,
+    #[index(btree)]
+    account: Identity,
+    linked_at: Timestamp,
+    last_connected_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM linked_device WHERE account = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const SIBLING_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT sibling.* FROM linked_device own JOIN linked_device sibling ON own.account = sibling.account WHERE own.device = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const OWN_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM linked_device WHERE device = :sender"
+);
+
+// open connections of an account, including those of its linked devices
+#[spacetimedb::table(name = session, public)]
+pub struct Session {
+    #[primary_key]
+    connection_id: ConnectionId,
+    #[index(btree)]
+    identity: Identity,
+    // connection identity the session was opened with, differs from `identity` for linked devices
+    device: Identity,
+    connected_at: Timestamp,
+    // free-form description such as "Firefox on Linux", reported by the client after connecting
+    client_hint: Option<String>,
+    // set by `terminate_session`, the row is kept until the connection actually closes
+    terminated_at: Option<Timestamp>
+}
+
+#[spacetimedb::client_visibility_filter]
+const SESSION_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM session WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const SESSION_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT session.* FROM linked_device JOIN session ON linked_device.account = session.identity WHERE linked_device.device = :sender"
+);
+
+// one-time tokens an account shows on an existing device and enters on the new one
+#[spacetimedb::table(name = link_token, public)]
+pub struct LinkToken {
+    #[primary_key]
+    token: String,
+    #[index(btree)]
+    account: Identity,
+    expires_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const LINK_TOKEN_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM link_token WHERE account = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const LINK_TOKEN_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT link_token.* FROM linked_device JOIN link_token ON linked_device.account = link_token.account WHERE linked_device.device = :sender"
+);
+
+// client preferences that roam across devices, rows only exist once a user changed their settings
+#[spacetimedb::table(name = user_settings, public)]
+pub struct UserSettings {
+    #[primary_key]
+    identity: Identity,
+    theme: Theme,
+    // BCP 47 language tag such as "en" or "de-AT"
+    locale: String,
+    enter_to_send: bool,
+    compact_mode: bool,
+    show_typing_indicators: bool,
+    // opt-in to see and join group chats marked as nsfw, indexed for the `groupchat` visibility filter
+    #[index(btree)]
+    show_nsfw: bool
+}
+
+#[spacetimedb::client_visibility_filter]
+const USER_SETTINGS_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM user_settings WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const USER_SETTINGS_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT user_settings.* FROM linked_device JOIN user_settings ON linked_device.account = user_settings.identity WHERE linked_device.device = :sender"
+);
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Theme {
+    System,
+    Light,
+    Dark,
+}
+
+#[spacetimedb::table(name = karma, public)]
+pub struct Karma {
+    #[primary_key]
+    identity: Identity,
+    points: u64
+}
+
+// every `award_points` call, also used to rate limit awards per giver
+#[spacetimedb::table(name = karma_transaction, public)]
+pub struct KarmaTransaction {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    giver: Identity,
+    #[index(btree)]
+    recipient: Identity,
+    amount: u32,
+    reason: Option<String>,
+    created_at: Timestamp
+}
+
+// reserves a normalized name for a single user, the display casing stays on `User::name`
+#[spacetimedb::table(name = username, public)]
+pub struct Username {
+    #[primary_key]
+    normalized_name: String,
+    #[unique]
+    identity: Identity,
+}
+
+
+#[spacetimedb::table(name = groupchat_membership,
+    index(name = user_and_groupchat, btree(columns = [identity, groupchat_id])),
+    public)]
+pub struct GroupChatMembership {
+    #[primary_key]
+    #[auto_inc]
+    id: u32,
+    #[index(btree)]
+    identity: Identity,
+    #[index(btree)]
+    groupchat_id: String,
+    role: MembershipRole,
+    // mirrors `role >= MembershipRole::Admin` for visibility filters, which can't compare roles
+    moderator: bool,
+    nickname: Option<String>,
+    last_posted_at: Option<Timestamp>,
+    joined_at: Timestamp,
+    // the member's own sidebar placement, pinned chats are listed first
+    pinned: bool,
+    sort_order: u32
+}
+
+// variants are ordered from least to most privileged so roles can be compared
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MembershipRole {
+    Member,
+    Admin,
+    Owner,
+}
+
+#[spacetimedb::table(name = message,
+    index(name = groupchat_and_seq, btree(columns = [groupchat_id, seq])),
+    index(name = groupchat_and_day, btree(columns = [groupchat_id, day])),
+    public)]
+pub struct Message {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    sender: Identity,
+    sent: Timestamp,
+    text: String,
+    #[index(btree)]
+    groupchat_id: String,
+    edited_at: Option<Timestamp>,
+    deleted: bool,
+    // root message of the thread this message replies to
+    reply_to: Option<u64>,
+    // position of the message within its group chat, strictly increasing but with gaps where messages were unsent or purged
+    seq: u64,
+    // set on the system message announcing a poll
+    poll_id: Option<u64>,
+    // message this one was forwarded from and who originally wrote it
+    forwarded_from: Option<u64>,
+    original_sender: Option<Identity>,
+    // author name on the platform an imported message was migrated from
+    imported_sender: Option<String>,
+    kind: MessageKind,
+    // random id chosen by the client so retried sends aren't posted twice
+    client_id: Option<u128>,
+    // None for the group chat's main stream
+    topic_id: Option<u64>,
+    // BCP 47 language tag declared by the sender's client
+    lang: Option<String>,
+    // end-to-end encrypted messages carry an empty `text`, only members holding the `group_key` can decrypt them
+    ciphertext: Option<Vec<u8>>,
+    nonce: Option<Vec<u8>>,
+    key_id: Option<String>,
+    // `day_of(sent)`, lets the daily roll-ups read only recent messages instead of the whole history.
+    // Not defaulted or backfilled, see the note at the top of this file
+    day: u32
+}
+
+// system messages are sent by the module identity and describe group chat lifecycle events
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageKind {
+    UserText,
+    System,
+}
+
+// members only see memberships and messages of group chats they are a member of themselves
+#[spacetimedb::client_visibility_filter]
+const GROUPCHAT_MEMBERSHIP_VISIBILITY: Filter = Filter::Sql(
+    "SELECT other.* FROM groupchat_membership own JOIN groupchat_membership other ON own.groupchat_id = other.groupchat_id WHERE own.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUPCHAT_MEMBERSHIP_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT other.* FROM linked_device JOIN groupchat_membership own ON linked_device.account = own.identity JOIN groupchat_membership other ON own.groupchat_id = other.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const MESSAGE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT message.* FROM groupchat_membership JOIN message ON groupchat_membership.groupchat_id = message.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const MESSAGE_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT message.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN message ON groupchat_membership.groupchat_id = message.groupchat_id WHERE linked_device.device = :sender"
+);
+
+// client ids of each sender's recent messages, entries older than `CLIENT_ID_WINDOW_SECS` are pruned on send
+#[spacetimedb::table(name = sent_client_id,
+    index(name = sender_and_client_id, btree(columns = [sender, client_id])))]
+pub struct SentClientId {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    sender: Identity,
+    client_id: u128,
+    sent: Timestamp
+}
+
+// separate conversation streams within a group chat, archived topics refuse new messages
+#[spacetimedb::table(name = topic,
+    index(name = groupchat_and_name, btree(columns = [groupchat_id, name])),
+    public)]
+pub struct Topic {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    name: String,
+    created_by: Identity,
+    archived: bool
+}
+
+#[spacetimedb::client_visibility_filter]
+const TOPIC_VISIBILITY: Filter = Filter::Sql(
+    "SELECT topic.* FROM groupchat_membership JOIN topic ON groupchat_membership.groupchat_id = topic.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const TOPIC_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT topic.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN topic ON groupchat_membership.groupchat_id = topic.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = thread_meta, public)]
+pub struct ThreadMeta {
+    #[primary_key]
+    root_message_id: u64,
+    reply_count: u32,
+    last_reply_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const THREAD_META_VISIBILITY: Filter = Filter::Sql(
+    "SELECT thread_meta.* FROM message JOIN thread_meta ON message.id = thread_meta.root_message_id"
+);
+
+#[spacetimedb::table(name = groupchat, public)]
+pub struct GroupChat {
+    #[primary_key]
+    id: String,
+    name: String,
+    // url-safe identifier derived from the name at creation, stays the same across renames
+    #[unique]
+    slug: String,
+    created_by: Identity,
+    visibility: GroupVisibility,
+    // sequence number assigned to the next message posted in this group chat
+    next_seq: u64,
+    // activity summary for sorting the sidebar, `last_message_at` starts out as the creation time
+    last_message_at: Timestamp,
+    message_count: u64,
+    // messages older than this are removed by `apply_retention_policies`
+    retention_days: Option<u32>,
+    description: Option<String>,
+    topic: Option<String>,
+    avatar_url: Option<String>,
+    // minimum number of seconds between two messages of the same member, 0 disables slow mode
+    slowmode_seconds: u32,
+    post_policy: PostPolicy,
+    // archived group chats stay readable but refuse new messages and members
+    archived: bool,
+    // kept in sync by `add_member` and `remove_member`
+    member_count: u32,
+    // members currently online, kept in sync by `update_online_counts`
+    online_count: u32,
+    // listed on the discovery page
+    discoverable: bool,
+    // age-restricted, hidden from users who haven't enabled `show_nsfw` unless they are already a member
+    #[index(btree)]
+    nsfw: bool,
+    max_members: Option<u32>,
+    // disappearing messages, removed by `expire_disappearing_messages` once they are older than this
+    message_ttl_seconds: Option<u64>,
+    // every `strike_threshold` warnings a member is punished with `strike_escalation`, 0 disables escalation
+    strike_threshold: u32,
+    strike_escalation: StrikeEscalation,
+    // what happens to the messages of members who delete their account
+    deleted_account_policy: DeletedAccountPolicy,
+    // latest `group_key_epoch`, 0 until a member publishes the first key
+    key_epoch: u64,
+    // set when members joined or left since `key_epoch` was published, clients should rotate the key
+    key_rotation_pending: bool,
+    // set by the first user message `post_message` inserts, system messages don't count
+    has_live_messages: bool
+}
+
+// nsfw group chats are only replicated to their members and to users who opted in to see them
+#[spacetimedb::client_visibility_filter]
+const GROUPCHAT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM groupchat WHERE nsfw = false"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUPCHAT_MEMBER_VISIBILITY: Filter = Filter::Sql(
+    "SELECT groupchat.* FROM groupchat_membership JOIN groupchat ON groupchat_membership.groupchat_id = groupchat.id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUPCHAT_MEMBER_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT groupchat.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN groupchat ON groupchat_membership.groupchat_id = groupchat.id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUPCHAT_NSFW_VISIBILITY: Filter = Filter::Sql(
+    "SELECT groupchat.* FROM user_settings JOIN groupchat ON user_settings.show_nsfw = groupchat.nsfw WHERE user_settings.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUPCHAT_NSFW_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT groupchat.* FROM linked_device JOIN user_settings ON linked_device.account = user_settings.identity JOIN groupchat ON user_settings.show_nsfw = groupchat.nsfw WHERE linked_device.device = :sender"
+);
+
+// overrides the minimum role needed for an action, actions without a row use `default_min_role`
+#[spacetimedb::table(name = group_permission, public)]
+pub struct GroupPermission {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    action: GroupAction,
+    min_role: MembershipRole
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupAction {
+    Post,
+    Invite,
+    Pin,
+    Rename,
+    Kick,
+}
+
+// public group chats can be joined freely, private ones only via invite or approval
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupVisibility {
+    Public,
+    Private,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StrikeEscalation {
+    Mute,
+    Ban,
+}
+
+// `Anonymize` keeps the text and attributes it to the deleted user tombstone, `Scrub` also removes the content
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeletedAccountPolicy {
+    Anonymize,
+    Scrub,
+}
+
+// announcement channels only let admins and the owner post
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PostPolicy {
+    Everyone,
+    AdminsOnly,
+}
+
+#[spacetimedb::table(name = groupchat_ban,
+    index(name = groupchat_and_user, btree(columns = [groupchat_id, identity])),
+    public)]
+pub struct GroupChatBan {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    identity: Identity,
+    banned_by: Identity,
+    reason: Option<String>,
+    banned_at: Timestamp
+}
+
+// muted members can still read but not post until `until`, like bans mutes outlive the membership
+#[spacetimedb::table(name = mute,
+    index(name = groupchat_and_user, btree(columns = [groupchat_id, identity])),
+    public)]
+pub struct Mute {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    identity: Identity,
+    muted_by: Identity,
+    until: Timestamp
+}
+
+#[spacetimedb::table(name = warning,
+    index(name = groupchat_and_user, btree(columns = [groupchat_id, identity])),
+    public)]
+pub struct Warning {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    identity: Identity,
+    warned_by: Identity,
+    reason: Option<String>,
+    warned_at: Timestamp
+}
+
+#[spacetimedb::table(name = mute_expiry_sweep, scheduled(expire_mutes))]
+pub struct MuteExpirySweep {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt
+}
+
+#[spacetimedb::table(name = groupchat_invite, public)]
+pub struct GroupChatInvite {
+    #[primary_key]
+    code: String,
+    #[index(btree)]
+    groupchat_id: String,
+    created_by: Identity,
+    created_at: Timestamp,
+    expires_at: Option<Timestamp>,
+    max_uses: Option<u32>,
+    uses: u32
+}
+
+// invite codes are shared out of band, only members of the group chat can list them
+#[spacetimedb::client_visibility_filter]
+const GROUPCHAT_INVITE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT groupchat_invite.* FROM groupchat_membership JOIN groupchat_invite ON groupchat_membership.groupchat_id = groupchat_invite.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUPCHAT_INVITE_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT groupchat_invite.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN groupchat_invite ON groupchat_membership.groupchat_id = groupchat_invite.groupchat_id WHERE linked_device.device = :sender"
+);
+
+// short numeric code for joining a group chat by typing it in, only members can see it
+#[spacetimedb::table(name = group_join_code, public)]
+pub struct GroupJoinCode {
+    #[primary_key]
+    groupchat_id: String,
+    #[unique]
+    join_code: String,
+    created_by: Identity,
+    created_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const GROUP_JOIN_CODE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT group_join_code.* FROM groupchat_membership JOIN group_join_code ON groupchat_membership.groupchat_id = group_join_code.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUP_JOIN_CODE_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT group_join_code.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN group_join_code ON groupchat_membership.groupchat_id = group_join_code.groupchat_id WHERE linked_device.device = :sender"
+);
+
+// each identity's recent wrong join codes, `join_by_code` refuses further attempts once the window is full
+#[spacetimedb::table(name = join_code_attempt, public)]
+pub struct JoinCodeAttempt {
+    #[primary_key]
+    identity: Identity,
+    failed_attempts: Vec<Timestamp>
+}
+
+#[spacetimedb::client_visibility_filter]
+const JOIN_CODE_ATTEMPT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM join_code_attempt WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const JOIN_CODE_ATTEMPT_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT join_code_attempt.* FROM linked_device JOIN join_code_attempt ON linked_device.account = join_code_attempt.identity WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = join_request,
+    index(name = groupchat_and_user, btree(columns = [groupchat_id, identity])),
+    public)]
+pub struct JoinRequest {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    identity: Identity,
+    requested_at: Timestamp
+}
+
+// requesters see their own requests, admins and the owner see the requests they can approve
+#[spacetimedb::client_visibility_filter]
+const JOIN_REQUEST_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM join_request WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const JOIN_REQUEST_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT join_request.* FROM linked_device JOIN join_request ON linked_device.account = join_request.identity WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const JOIN_REQUEST_MODERATOR_VISIBILITY: Filter = Filter::Sql(
+    "SELECT join_request.* FROM groupchat_membership JOIN join_request ON groupchat_membership.groupchat_id = join_request.groupchat_id WHERE groupchat_membership.identity = :sender AND groupchat_membership.moderator = true"
+);
+
+#[spacetimedb::client_visibility_filter]
+const JOIN_REQUEST_MODERATOR_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT join_request.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN join_request ON groupchat_membership.groupchat_id = join_request.groupchat_id WHERE linked_device.device = :sender AND groupchat_membership.moderator = true"
+);
+
+#[spacetimedb::table(name = reaction,
+    index(name = message_user_emoji, btree(columns = [message_id, identity, emoji])),
+    public)]
+pub struct Reaction {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    message_id: u64,
+    identity: Identity,
+    emoji: String
+}
+
+#[spacetimedb::client_visibility_filter]
+const REACTION_VISIBILITY: Filter = Filter::Sql(
+    "SELECT reaction.* FROM message JOIN reaction ON message.id = reaction.message_id"
+);
+
+// number of reactions per emoji on a message, kept in sync by `add_reaction` and `remove_reaction`
+#[spacetimedb::table(name = reaction_count,
+    index(name = message_and_emoji, btree(columns = [message_id, emoji])),
+    public)]
+pub struct ReactionCount {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    message_id: u64,
+    emoji: String,
+    count: u32
+}
+
+#[spacetimedb::client_visibility_filter]
+const REACTION_COUNT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT reaction_count.* FROM message JOIN reaction_count ON message.id = reaction_count.message_id"
+);
+
+// referenced as `:shortcode:` in reactions and message texts of its group chat
+#[spacetimedb::table(name = custom_emoji,
+    index(name = groupchat_and_shortcode, btree(columns = [groupchat_id, shortcode])),
+    public)]
+pub struct CustomEmoji {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    shortcode: String,
+    image_url: String,
+    uploaded_by: Identity
+}
+
+#[spacetimedb::client_visibility_filter]
+const CUSTOM_EMOJI_VISIBILITY: Filter = Filter::Sql(
+    "SELECT custom_emoji.* FROM groupchat_membership JOIN custom_emoji ON groupchat_membership.groupchat_id = custom_emoji.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const CUSTOM_EMOJI_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT custom_emoji.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN custom_emoji ON groupchat_membership.groupchat_id = custom_emoji.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = pinned_message, public)]
+pub struct PinnedMessage {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    #[unique]
+    message_id: u64,
+    pinned_by: Identity,
+    pinned_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const PINNED_MESSAGE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT pinned_message.* FROM groupchat_membership JOIN pinned_message ON groupchat_membership.groupchat_id = pinned_message.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const PINNED_MESSAGE_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT pinned_message.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN pinned_message ON groupchat_membership.groupchat_id = pinned_message.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = typing_indicator,
+    index(name = groupchat_and_user, btree(columns = [groupchat_id, identity])),
+    public)]
+pub struct TypingIndicator {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    identity: Identity,
+    started_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const TYPING_INDICATOR_VISIBILITY: Filter = Filter::Sql(
+    "SELECT typing_indicator.* FROM groupchat_membership JOIN typing_indicator ON groupchat_membership.groupchat_id = typing_indicator.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const TYPING_INDICATOR_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT typing_indicator.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN typing_indicator ON groupchat_membership.groupchat_id = typing_indicator.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = typing_indicator_cleanup, scheduled(purge_typing_indicators))]
+pub struct TypingIndicatorCleanup {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt
+}
+
+#[spacetimedb::table(name = read_state,
+    index(name = user_and_groupchat, btree(columns = [identity, groupchat_id])),
+    public)]
+pub struct ReadState {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity: Identity,
+    #[index(btree)]
+    groupchat_id: String,
+    last_read_message_id: u64,
+    last_read_at: Timestamp,
+    // number of messages by other senders after `last_read_message_id`
+    unread_count: u32
+}
+
+// read state, mentions, notification preferences and blocks are only replicated to their owner
+#[spacetimedb::client_visibility_filter]
+const READ_STATE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM read_state WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const READ_STATE_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT read_state.* FROM linked_device JOIN read_state ON linked_device.account = read_state.identity WHERE linked_device.device = :sender"
+);
+
+// delivery and read receipts, only recorded in group chats of up to `MAX_RECEIPT_GROUP_SIZE` members
+#[spacetimedb::table(name = message_receipt,
+    index(name = message_and_user, btree(columns = [message_id, identity])),
+    public)]
+pub struct MessageReceipt {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    message_id: u64,
+    identity: Identity,
+    state: ReceiptState,
+    updated_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const MESSAGE_RECEIPT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT message_receipt.* FROM message JOIN message_receipt ON message.id = message_receipt.message_id"
+);
+
+// receipts only ever move forward from `Delivered` to `Read`
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReceiptState {
+    Delivered,
+    Read,
+}
+
+// user-defined sidebar folders, ordered by `sort_order`
+#[spacetimedb::table(name = chat_folder, public)]
+pub struct ChatFolder {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity: Identity,
+    name: String,
+    sort_order: u32
+}
+
+#[spacetimedb::client_visibility_filter]
+const CHAT_FOLDER_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM chat_folder WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const CHAT_FOLDER_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT chat_folder.* FROM linked_device JOIN chat_folder ON linked_device.account = chat_folder.identity WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = folder_membership,
+    index(name = folder_and_groupchat, btree(columns = [folder_id, groupchat_id])),
+    public)]
+pub struct FolderMembership {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    folder_id: u64,
+    #[index(btree)]
+    identity: Identity,
+    groupchat_id: String
+}
+
+#[spacetimedb::client_visibility_filter]
+const FOLDER_MEMBERSHIP_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM folder_membership WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const FOLDER_MEMBERSHIP_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT folder_membership.* FROM linked_device JOIN folder_membership ON linked_device.account = folder_membership.identity WHERE linked_device.device = :sender"
+);
+
+// unsent message text per group chat so it follows the user across devices
+#[spacetimedb::table(name = draft,
+    index(name = user_and_groupchat, btree(columns = [identity, groupchat_id])),
+    public)]
+pub struct Draft {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity: Identity,
+    #[index(btree)]
+    groupchat_id: String,
+    text: String,
+    updated_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const DRAFT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM draft WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const DRAFT_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT draft.* FROM linked_device JOIN draft ON linked_device.account = draft.identity WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = bookmark,
+    index(name = user_and_message, btree(columns = [identity, message_id])),
+    public)]
+pub struct Bookmark {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity: Identity,
+    #[index(btree)]
+    message_id: u64,
+    created_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const BOOKMARK_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM bookmark WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const BOOKMARK_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT bookmark.* FROM linked_device JOIN bookmark ON linked_device.account = bookmark.identity WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = mention, public)]
+pub struct Mention {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    mentioned_identity: Identity,
+    #[index(btree)]
+    message_id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    seen: bool
+}
+
+#[spacetimedb::client_visibility_filter]
+const MENTION_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM mention WHERE mentioned_identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const MENTION_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT mention.* FROM linked_device JOIN mention ON linked_device.account = mention.mentioned_identity WHERE linked_device.device = :sender"
+);
+
+// a single inbox feed per user, written by every reducer that should notify someone
+#[spacetimedb::table(name = notification, public)]
+pub struct Notification {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    recipient: Identity,
+    kind: NotificationKind,
+    // user whose action caused the notification
+    actor: Identity,
+    groupchat_id: Option<String>,
+    // a `message` id, or a `dm_message` id for `NotificationKind::DirectMessage`
+    message_id: Option<u64>,
+    created_at: Timestamp,
+    read: bool
+}
+
+#[spacetimedb::client_visibility_filter]
+const NOTIFICATION_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM notification WHERE recipient = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const NOTIFICATION_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT notification.* FROM linked_device JOIN notification ON linked_device.account = notification.recipient WHERE linked_device.device = :sender"
+);
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationKind {
+    Mention,
+    DirectMessage,
+    InviteUsed,
+    Kicked,
+    JoinApproved,
+    Reminder,
+}
+
+// pending `remind_me` reminders, each fires once and turns into a notification
+#[spacetimedb::table(name = reminder, scheduled(fire_reminder), public)]
+pub struct Reminder {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    #[index(btree)]
+    identity: Identity,
+    groupchat_id: String,
+    message_id: u64
+}
+
+#[spacetimedb::client_visibility_filter]
+const REMINDER_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM reminder WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const REMINDER_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT reminder.* FROM linked_device JOIN reminder ON linked_device.account = reminder.identity WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = retention_cleanup, scheduled(apply_retention_policies))]
+pub struct RetentionCleanup {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt
+}
+
+// timestamps of each identity's recent sends, used for rate limiting
+#[spacetimedb::table(name = rate_limit)]
+pub struct RateLimit {
+    #[primary_key]
+    identity: Identity,
+    recent_sends: Vec<Timestamp>
+}
+
+// creation times of the group chats each identity created within the last hour
+#[spacetimedb::table(name = group_creation_limit)]
+pub struct GroupCreationLimit {
+    #[primary_key]
+    identity: Identity,
+    recent_creations: Vec<Timestamp>
+}
+
+// module-wide limits, a single row with id 0 seeded in `init`
+#[spacetimedb::table(name = config, public)]
+pub struct Config {
+    #[primary_key]
+    id: u32,
+    max_message_len: u32,
+    max_name_len: u32,
+    max_group_name_len: u32,
+    max_pins_per_group: u32,
+    rate_limit_max_messages: u32,
+    rate_limit_window_secs: u32,
+    // bots post on behalf of integrations and get a separate, usually larger, budget
+    bot_rate_limit_max_messages: u32,
+    // how long after sending a message its sender can still unsend it, 0 disables unsending
+    unsend_window_secs: u32,
+    // limits on `create_groupchat` per identity
+    max_groups_created_per_hour: u32,
+    max_groups_owned: u32,
+    // memberships per identity, bounds how much a single client subscribes to
+    max_memberships_per_user: u32,
+    // users must accept this terms of service version before posting or creating group chats, 0 disables the gate
+    tos_version: u32
+}
+
+// identities allowed to manage module-wide settings, seeded with the publisher in `init`
+#[spacetimedb::table(name = superadmin, public)]
+pub struct Superadmin {
+    #[primary_key]
+    identity: Identity
+}
+
+// module-wide announcements by operators, shown to every client regardless of their group chats
+#[spacetimedb::table(name = announcement, public)]
+pub struct Announcement {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    text: String,
+    created_by: Identity,
+    created_at: Timestamp
+}
+
+#[spacetimedb::table(name = banned_word, public)]
+pub struct BannedWord {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    // None applies the banned word to every group chat and direct message
+    groupchat_id: Option<String>,
+    word: String,
+    added_by: Identity
+}
+
+#[spacetimedb::table(name = audit_log, public)]
+pub struct AuditLog {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    actor: Identity,
+    action: AuditAction,
+    target: Option<Identity>,
+    #[index(btree)]
+    groupchat_id: String,
+    timestamp: Timestamp,
+    details: Option<String>
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditAction {
+    RenameGroupChat,
+    SetVisibility,
+    SetRetention,
+    PromoteMember,
+    DemoteMember,
+    KickMember,
+    BanMember,
+    UnbanMember,
+    ApproveJoinRequest,
+    RejectJoinRequest,
+    PinMessage,
+    UnpinMessage,
+    DeleteMessage,
+    AddBannedWord,
+    RemoveBannedWord,
+    SetDescription,
+    SetTopic,
+    SetAvatar,
+    SetSlowmode,
+    SetPostPolicy,
+    TransferOwnership,
+    ArchiveGroupChat,
+    UnarchiveGroupChat,
+    SetDiscoverable,
+    SetMemberLimit,
+    SetMessageTtl,
+    AddWebhook,
+    RemoveWebhook,
+    ImportMessages,
+    ResolveReport,
+    DismissReport,
+    MuteMember,
+    UnmuteMember,
+    WarnMember,
+    SetStrikePolicy,
+    AddCustomEmoji,
+    RemoveCustomEmoji,
+    CreateBadge,
+    AwardBadge,
+    SetVerified,
+    Broadcast,
+    CreateTopic,
+    ArchiveTopic,
+    SetPermission,
+    SetDeletedAccountPolicy,
+    SetNsfw,
+}
+
+// audit log entries are only replicated to the owner and admins of the group chat they belong to
+#[spacetimedb::client_visibility_filter]
+const AUDIT_LOG_VISIBILITY: Filter = Filter::Sql(
+    "SELECT audit_log.* FROM groupchat_membership JOIN audit_log ON groupchat_membership.groupchat_id = audit_log.groupchat_id WHERE groupchat_membership.identity = :sender AND groupchat_membership.moderator = true"
+);
+
+#[spacetimedb::client_visibility_filter]
+const AUDIT_LOG_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT audit_log.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN audit_log ON groupchat_membership.groupchat_id = audit_log.groupchat_id WHERE linked_device.device = :sender AND groupchat_membership.moderator = true"
+);
+
+#[spacetimedb::table(name = block,
+    index(name = blocker_and_blocked, btree(columns = [blocker, blocked])),
+    public)]
+pub struct Block {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    blocker: Identity,
+    #[index(btree)]
+    blocked: Identity,
+    created_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const BLOCK_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM block WHERE blocker = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const BLOCK_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT block.* FROM linked_device JOIN block ON linked_device.account = block.blocker WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = friend_request,
+    index(name = sender_and_recipient, btree(columns = [sender, recipient])),
+    public)]
+pub struct FriendRequest {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    sender: Identity,
+    #[index(btree)]
+    recipient: Identity,
+    created_at: Timestamp
+}
+
+// friend requests are visible to both sides, multiple filters on a table are combined
+#[spacetimedb::client_visibility_filter]
+const FRIEND_REQUEST_SENDER_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM friend_request WHERE sender = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const FRIEND_REQUEST_SENDER_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT friend_request.* FROM linked_device JOIN friend_request ON linked_device.account = friend_request.sender WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const FRIEND_REQUEST_RECIPIENT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM friend_request WHERE recipient = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const FRIEND_REQUEST_RECIPIENT_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT friend_request.* FROM linked_device JOIN friend_request ON linked_device.account = friend_request.recipient WHERE linked_device.device = :sender"
+);
+
+// like direct conversations, friendships store their identities in canonical order
+#[spacetimedb::table(name = friendship,
+    index(name = friends, btree(columns = [identity_a, identity_b])),
+    public)]
+pub struct Friendship {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity_a: Identity,
+    #[index(btree)]
+    identity_b: Identity,
+    since: Timestamp
+}
+
+#[spacetimedb::table(name = notification_pref,
+    index(name = user_and_groupchat, btree(columns = [identity, groupchat_id])),
+    public)]
+pub struct NotificationPref {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity: Identity,
+    #[index(btree)]
+    groupchat_id: String,
+    mode: NotificationMode,
+    // while set and in the future, the group chat behaves as if `mode` was `None`
+    muted_until: Option<Timestamp>
+}
+
+#[spacetimedb::client_visibility_filter]
+const NOTIFICATION_PREF_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM notification_pref WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const NOTIFICATION_PREF_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT notification_pref.* FROM linked_device JOIN notification_pref ON linked_device.account = notification_pref.identity WHERE linked_device.device = :sender"
+);
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationMode {
+    All,
+    Mentions,
+    None,
+}
+
+#[spacetimedb::table(name = group_tag,
+    index(name = groupchat_and_tag, btree(columns = [groupchat_id, tag])),
+    public)]
+pub struct GroupTag {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    #[index(btree)]
+    tag: String
+}
+
+#[spacetimedb::table(name = presence_sweep, scheduled(sweep_presence))]
+pub struct PresenceSweep {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt
+}
+
+#[spacetimedb::table(name = message_expiry_sweep, scheduled(expire_disappearing_messages))]
+pub struct MessageExpirySweep {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt
+}
+
+#[spacetimedb::table(name = poll, public)]
+pub struct Poll {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    created_by: Identity,
+    question: String,
+    options: Vec<String>,
+    created_at: Timestamp,
+    closed: bool
+}
+
+#[spacetimedb::client_visibility_filter]
+const POLL_VISIBILITY: Filter = Filter::Sql(
+    "SELECT poll.* FROM groupchat_membership JOIN poll ON groupchat_membership.groupchat_id = poll.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const POLL_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT poll.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN poll ON groupchat_membership.groupchat_id = poll.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = poll_vote,
+    index(name = poll_and_user, btree(columns = [poll_id, identity])),
+    public)]
+pub struct PollVote {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    poll_id: u64,
+    identity: Identity,
+    option_index: u32
+}
+
+#[spacetimedb::client_visibility_filter]
+const POLL_VOTE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT poll_vote.* FROM poll JOIN poll_vote ON poll.id = poll_vote.poll_id"
+);
+
+#[spacetimedb::table(name = group_event, public)]
+pub struct GroupEvent {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    title: String,
+    starts_at: Timestamp,
+    created_by: Identity
+}
+
+#[spacetimedb::client_visibility_filter]
+const GROUP_EVENT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT group_event.* FROM groupchat_membership JOIN group_event ON groupchat_membership.groupchat_id = group_event.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUP_EVENT_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT group_event.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN group_event ON groupchat_membership.groupchat_id = group_event.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = event_rsvp,
+    index(name = event_and_user, btree(columns = [event_id, identity])),
+    public)]
+pub struct EventRsvp {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    event_id: u64,
+    identity: Identity,
+    status: RsvpStatus
+}
+
+#[spacetimedb::client_visibility_filter]
+const RSVP_VISIBILITY: Filter = Filter::Sql(
+    "SELECT event_rsvp.* FROM group_event JOIN event_rsvp ON group_event.id = event_rsvp.event_id"
+);
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RsvpStatus {
+    Going,
+    Maybe,
+    NotGoing,
+}
+
+// one-shot schedule per event, fires `EVENT_REMINDER_LEAD_SECS` before it starts
+#[spacetimedb::table(name = event_reminder, scheduled(send_event_reminder))]
+pub struct EventReminder {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    #[index(btree)]
+    event_id: u64
+}
+
+// summary of the last day of activity in a group chat, written by `generate_daily_digests`
+#[spacetimedb::table(name = daily_digest, public)]
+pub struct DailyDigest {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    period_start: Timestamp,
+    period_end: Timestamp,
+    message_count: u32,
+    // most active members first
+    most_active: Vec<Identity>,
+    new_members: u32
+}
+
+#[spacetimedb::client_visibility_filter]
+const DAILY_DIGEST_VISIBILITY: Filter = Filter::Sql(
+    "SELECT daily_digest.* FROM groupchat_membership JOIN daily_digest ON groupchat_membership.groupchat_id = daily_digest.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const DAILY_DIGEST_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT daily_digest.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN daily_digest ON groupchat_membership.groupchat_id = daily_digest.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = daily_digest_schedule, scheduled(generate_daily_digests))]
+pub struct DailyDigestSchedule {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt
+}
+
+// built-in badges have no group chat and are created the first time they are awarded
+#[spacetimedb::table(name = badge, public)]
+pub struct Badge {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    groupchat_id: Option<String>,
+    builtin: Option<BuiltinBadge>,
+    name: String,
+    description: Option<String>,
+    icon_url: Option<String>,
+    created_by: Identity
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuiltinBadge {
+    FirstMessage,
+    HundredMessages,
+    // one of the first `FOUNDER_MEMBER_LIMIT` members of a group chat
+    Founder,
+}
+
+#[spacetimedb::table(name = awarded_badge,
+    index(name = badge_and_user, btree(columns = [badge_id, identity])),
+    public)]
+pub struct AwardedBadge {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    badge_id: u64,
+    #[index(btree)]
+    identity: Identity,
+    awarded_by: Identity,
+    awarded_at: Timestamp
+}
+
+// messages posted per member for the activity leaderboard, cleared by `reset_leaderboard`
+#[spacetimedb::table(name = member_stats,
+    index(name = groupchat_and_user, btree(columns = [groupchat_id, identity])),
+    public)]
+pub struct MemberStats {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    #[index(btree)]
+    identity: Identity,
+    message_count: u64
+}
+
+#[spacetimedb::client_visibility_filter]
+const MEMBER_STATS_VISIBILITY: Filter = Filter::Sql(
+    "SELECT member_stats.* FROM groupchat_membership JOIN member_stats ON groupchat_membership.groupchat_id = member_stats.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const MEMBER_STATS_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT member_stats.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN member_stats ON groupchat_membership.groupchat_id = member_stats.groupchat_id WHERE linked_device.device = :sender"
+);
+
+// consecutive utc days a member posted in a group chat, broken streaks are reset by `reset_broken_streaks`
+#[spacetimedb::table(name = streak,
+    index(name = groupchat_and_user, btree(columns = [groupchat_id, identity])),
+    public)]
+pub struct Streak {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    #[index(btree)]
+    identity: Identity,
+    current: u32,
+    longest: u32,
+    // day of the member's last message, see `day_of`
+    last_day: u32
+}
+
+#[spacetimedb::client_visibility_filter]
+const STREAK_VISIBILITY: Filter = Filter::Sql(
+    "SELECT streak.* FROM groupchat_membership JOIN streak ON groupchat_membership.groupchat_id = streak.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const STREAK_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT streak.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN streak ON groupchat_membership.groupchat_id = streak.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = streak_reset_sweep, scheduled(reset_broken_streaks))]
+pub struct StreakResetSweep {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt
+}
+
+// per-day analytics of a group chat, rolled up by `roll_up_group_stats`
+#[spacetimedb::table(name = group_stats,
+    index(name = groupchat_and_day, btree(columns = [groupchat_id, day])),
+    public)]
+pub struct GroupStats {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    // days since the unix epoch in UTC
+    day: u32,
+    message_count: u32,
+    unique_posters: u32,
+    // highest `GroupChat::online_count` seen during the day, sampled on every roll-up
+    peak_online: u32
+}
+
+// like the audit log, statistics are only replicated to the owner and admins of the group chat
+#[spacetimedb::client_visibility_filter]
+const GROUP_STATS_VISIBILITY: Filter = Filter::Sql(
+    "SELECT group_stats.* FROM groupchat_membership JOIN group_stats ON groupchat_membership.groupchat_id = group_stats.groupchat_id WHERE groupchat_membership.identity = :sender AND groupchat_membership.moderator = true"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUP_STATS_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT group_stats.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN group_stats ON groupchat_membership.groupchat_id = group_stats.groupchat_id WHERE linked_device.device = :sender AND groupchat_membership.moderator = true"
+);
+
+#[spacetimedb::table(name = group_stats_rollup, scheduled(roll_up_group_stats))]
+pub struct GroupStatsRollup {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt
+}
+
+#[spacetimedb::table(name = attachment, public)]
+pub struct Attachment {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    message_id: u64,
+    kind: AttachmentKind,
+    url: Option<String>,
+    content_hash: Option<String>,
+    size: u64,
+    mime_type: String
+}
+
+// visibility filters on joined tables are applied as well, so these follow `MESSAGE_VISIBILITY`
+#[spacetimedb::client_visibility_filter]
+const ATTACHMENT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT attachment.* FROM message JOIN attachment ON message.id = attachment.message_id"
+);
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttachmentKind {
+    Image,
+    Video,
+    Audio,
+    File,
+}
+
+// attachment metadata as sent by clients, the file itself is stored elsewhere
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct AttachmentInput {
+    kind: AttachmentKind,
+    url: Option<String>,
+    content_hash: Option<String>,
+    size: u64,
+    mime_type: String,
+}
+
+// a message migrated from another chat platform
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct ImportedMessage {
+    sender_label: String,
+    sent: Timestamp,
+    text: String,
+}
+
+// results of the caller's latest `search_messages` call
+#[spacetimedb::table(name = search_result, public)]
+pub struct SearchResult {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity: Identity,
+    message_id: u64,
+    groupchat_id: String
+}
+
+#[spacetimedb::client_visibility_filter]
+const SEARCH_RESULT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM search_result WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const SEARCH_RESULT_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT search_result.* FROM linked_device JOIN search_result ON linked_device.account = search_result.identity WHERE linked_device.device = :sender"
+);
+
+// results of the caller's latest `search_users` call
+#[spacetimedb::table(name = user_search_result, public)]
+pub struct UserSearchResult {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity: Identity,
+    user_identity: Identity
+}
+
+#[spacetimedb::client_visibility_filter]
+const USER_SEARCH_RESULT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM user_search_result WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const USER_SEARCH_RESULT_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT user_search_result.* FROM linked_device JOIN user_search_result ON linked_device.account = user_search_result.identity WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = message_edit, public)]
+pub struct MessageEdit {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    message_id: u64,
+    editor: Identity,
+    old_text: String,
+    edited_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const MESSAGE_EDIT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT message_edit.* FROM message JOIN message_edit ON message.id = message_edit.message_id"
+);
+
+// a group chat's message key wrapped for a single member, uploaded by the client that generated it
+#[spacetimedb::table(name = group_key,
+    index(name = groupchat_key_and_recipient, btree(columns = [groupchat_id, key_id, recipient])),
+    public)]
+pub struct GroupKey {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    key_id: String,
+    #[index(btree)]
+    recipient: Identity,
+    wrapped_key: Vec<u8>,
+    created_by: Identity,
+    created_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const GROUP_KEY_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM group_key WHERE recipient = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUP_KEY_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT group_key.* FROM linked_device JOIN group_key ON linked_device.account = group_key.recipient WHERE linked_device.device = :sender"
+);
+
+// each epoch's key is wrapped for exactly the members at the time it was published, so members who
+// join later can't decrypt earlier messages and members who left can't decrypt later ones
+#[spacetimedb::table(name = group_key_epoch,
+    index(name = groupchat_and_epoch, btree(columns = [groupchat_id, epoch])),
+    public)]
+pub struct GroupKeyEpoch {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    epoch: u64,
+    published_by: Identity,
+    published_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const GROUP_KEY_EPOCH_VISIBILITY: Filter = Filter::Sql(
+    "SELECT group_key_epoch.* FROM groupchat_membership JOIN group_key_epoch ON groupchat_membership.groupchat_id = group_key_epoch.groupchat_id WHERE groupchat_membership.identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const GROUP_KEY_EPOCH_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT group_key_epoch.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN group_key_epoch ON groupchat_membership.groupchat_id = group_key_epoch.groupchat_id WHERE linked_device.device = :sender"
+);
+
+#[derive(SpacetimeType)]
+pub struct WrappedKey {
+    recipient: Identity,
+    wrapped_key: Vec<u8>
+}
+
+// translations of a message shared by all members, stale entries are dropped when the message is edited
+#[spacetimedb::table(name = translation_cache,
+    index(name = message_and_lang, btree(columns = [message_id, target_lang])),
+    public)]
+pub struct TranslationCache {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    message_id: u64,
+    target_lang: String,
+    text: String,
+    translated_by: Identity,
+    created_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const TRANSLATION_CACHE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT translation_cache.* FROM message JOIN translation_cache ON message.id = translation_cache.message_id"
+);
+
+#[spacetimedb::table(name = report,
+    index(name = message_and_reporter, btree(columns = [message_id, reporter])),
+    public)]
+pub struct Report {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    message_id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    reporter: Identity,
+    reason: String,
+    status: ReportStatus,
+    created_at: Timestamp,
+    handled_by: Option<Identity>
+}
+
+// reporters see their own reports, the moderation queue follows `AUDIT_LOG_VISIBILITY`
+#[spacetimedb::client_visibility_filter]
+const REPORT_REPORTER_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM report WHERE reporter = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const REPORT_REPORTER_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT report.* FROM linked_device JOIN report ON linked_device.account = report.reporter WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const REPORT_MODERATOR_VISIBILITY: Filter = Filter::Sql(
+    "SELECT report.* FROM groupchat_membership JOIN report ON groupchat_membership.groupchat_id = report.groupchat_id WHERE groupchat_membership.identity = :sender AND groupchat_membership.moderator = true"
+);
+
+#[spacetimedb::client_visibility_filter]
+const REPORT_MODERATOR_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT report.* FROM linked_device JOIN groupchat_membership ON linked_device.account = groupchat_membership.identity JOIN report ON groupchat_membership.groupchat_id = report.groupchat_id WHERE linked_device.device = :sender AND groupchat_membership.moderator = true"
+);
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportStatus {
+    Open,
+    Resolved,
+    Dismissed,
+}
+
+// not public because of the signing secret, external workers read it with the module owner's identity
+#[spacetimedb::table(name = webhook)]
+pub struct Webhook {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    groupchat_id: String,
+    url: String,
+    secret: String,
+    // bitmask of WEBHOOK_EVENT_* values the webhook is subscribed to
+    event_mask: u32,
+    created_by: Identity,
+    created_at: Timestamp
+}
+
+// pending webhook deliveries, drained by an external worker
+#[spacetimedb::table(name = webhook_outbox)]
+pub struct WebhookOutbox {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    webhook_id: u64,
+    event: u32,
+    groupchat_id: String,
+    message_id: u64,
+    created_at: Timestamp
+}
+
+// chunks of the caller's latest `request_data_export`, one JSON record per line
+#[spacetimedb::table(name = data_export, public)]
+pub struct DataExport {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    identity: Identity,
+    // clients concatenate the chunks in this order to get the full export
+    chunk_index: u32,
+    data: String,
+    created_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const DATA_EXPORT_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM data_export WHERE identity = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const DATA_EXPORT_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT data_export.* FROM linked_device JOIN data_export ON linked_device.account = data_export.identity WHERE linked_device.device = :sender"
+);
+
+// participants are stored in a canonical order (participant_a < participant_b)
+// so there is exactly one conversation per pair of identities,
+// a conversation with participant_a == participant_b holds a user's notes to self
+#[spacetimedb::table(name = direct_conversation,
+    index(name = participants, btree(columns = [participant_a, participant_b])),
+    public)]
+pub struct DirectConversation {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    participant_a: Identity,
+    #[index(btree)]
+    participant_b: Identity,
+    created_at: Timestamp
+}
+
+#[spacetimedb::client_visibility_filter]
+const DIRECT_CONVERSATION_A_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM direct_conversation WHERE participant_a = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const DIRECT_CONVERSATION_A_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT direct_conversation.* FROM linked_device JOIN direct_conversation ON linked_device.account = direct_conversation.participant_a WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const DIRECT_CONVERSATION_B_VISIBILITY: Filter = Filter::Sql(
+    "SELECT * FROM direct_conversation WHERE participant_b = :sender"
+);
+
+#[spacetimedb::client_visibility_filter]
+const DIRECT_CONVERSATION_B_LINKED_DEVICE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT direct_conversation.* FROM linked_device JOIN direct_conversation ON linked_device.account = direct_conversation.participant_b WHERE linked_device.device = :sender"
+);
+
+#[spacetimedb::table(name = dm_message, public)]
+pub struct DmMessage {
+    #[primary_key]
+    #[auto_inc]
+    id: u64,
+    #[index(btree)]
+    conversation_id: u64,
+    sender: Identity,
+    sent: Timestamp,
+    text: String
+}
+
+// follows the visibility of the conversation the message belongs to
+#[spacetimedb::client_visibility_filter]
+const DM_MESSAGE_VISIBILITY: Filter = Filter::Sql(
+    "SELECT dm_message.* FROM direct_conversation JOIN dm_message ON direct_conversation.id = dm_message.conversation_id"
+);
+
+
+fn validate_name(ctx: &ReducerContext, name: String) -> Result<String, String> {
+    let name = name.trim().nfc().collect::<String>();
+    let max_name_len = config(ctx).max_name_len;
+    if name.is_empty() {
+        Err("Names must not be empty".to_string())
+    } else if name.chars().count() > max_name_len as usize {
+        Err(format!("Names must be at most {max_name_len} characters"))
+    } else {
+        Ok(name)
+    }
+}
+
+// names are compared case-insensitively after trimming and unicode normalization
+fn normalize_name(name: &str) -> String {
+    name.trim().nfc().collect::<String>().to_lowercase()
+}
+
+#[spacetimedb::reducer]
+pub fn accept_tos(ctx: &ReducerContext, version: u32) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let user = ctx.db.user().identity().find(sender).ok_or("User does not exist")?;
+    let tos_version = config(ctx).tos_version;
+    // clients must show the current terms, accepting an outdated version doesn't count
+    if version != tos_version {
+        return Err(format!("Terms of service version {version} is not the current version {tos_version}"));
+    }
+    ctx.db.user().identity().update(User {
+        tos_accepted_version: version,
+        ..user
+    });
+    Ok(())
+}
+
+fn check_tos_accepted(ctx: &ReducerContext) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let tos_version = config(ctx).tos_version;
+    let accepted = ctx.db.user().identity().find(sender).is_some_and(|user| user.tos_accepted_version >= tos_version);
+    if !accepted {
+        return Err(format!("Terms of service version {tos_version} must be accepted first"));
+    }
+    Ok(())
+}
+
+fn issue_challenge(ctx: &ReducerContext) {
+    let (question, answer) = random_challenge(ctx);
+    ctx.db.challenge().insert(Challenge {
+        identity: ctx.sender,
+        question,
+        issued_at: ctx.timestamp,
+        failed_attempts: 0
+    });
+    ctx.db.challenge_answer().insert(ChallengeAnswer {
+        identity: ctx.sender,
+        answer
+    });
+}
+
+fn random_challenge(ctx: &ReducerContext) -> (String, String) {
+    let a = ctx.random::<u32>() % CHALLENGE_MAX_OPERAND + 1;
+    let b = ctx.random::<u32>() % CHALLENGE_MAX_OPERAND + 1;
+    (format!("What is {a} + {b}?"), (a + b).to_string())
+}
+
+#[spacetimedb::reducer]
+pub fn solve_challenge(ctx: &ReducerContext, answer: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let challenge = ctx.db.challenge().identity().find(sender).ok_or("No challenge is pending")?;
+    let expected = ctx.db.challenge_answer().identity().find(sender).ok_or("No challenge is pending")?;
+    if challenge.failed_attempts >= MAX_CHALLENGE_FAILURES {
+        return Err(format!(
+            "Too many wrong answers, wait {} minutes after signing up instead", CHALLENGE_COOLDOWN_SECS / 60
+        ));
+    }
+    if answer.trim() != expected.answer {
+        // returning an error would roll back the new question and the recorded failure, so wrong answers
+        // succeed and the caller sees the failure in its `challenge` row
+        let (question, answer) = random_challenge(ctx);
+        ctx.db.challenge().identity().update(Challenge {
+            question,
+            failed_attempts: challenge.failed_attempts + 1,
+            ..challenge
+        });
+        ctx.db.challenge_answer().identity().update(ChallengeAnswer {
+            answer,
+            ..expected
+        });
+        log::info!("User {} gave a wrong answer to the challenge", sender);
+        return Ok(());
+    }
+    ctx.db.challenge().identity().delete(sender);
+    ctx.db.challenge_answer().identity().delete(sender);
+    Ok(())
+}
+
+// identities without a pending challenge predate it or have solved it
+fn check_challenge_passed(ctx: &ReducerContext) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let cooldown_start = seconds_ago(ctx, CHALLENGE_COOLDOWN_SECS);
+    if ctx.db.challenge().identity().find(sender).is_some_and(|challenge| challenge.issued_at > cooldown_start) {
+        return Err(format!(
+            "Solve the challenge or wait {} minutes after signing up first", CHALLENGE_COOLDOWN_SECS / 60
+        ));
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn create_link_token(ctx: &ReducerContext) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if ctx.db.user().identity().find(sender).is_none() {
+        return Err("Only accounts can create link tokens".to_string());
+    }
+    for token in ctx.db.link_token().account().filter(sender) {
+        ctx.db.link_token().token().delete(&token.token);
+    }
+    ctx.db.link_token().try_insert(LinkToken {
+        token: random_code(ctx, LINK_TOKEN_LEN),
+        account: sender,
+        expires_at: seconds_from_now(ctx, LINK_TOKEN_TTL_SECS)
+    })?;
+    Ok(())
+}
+
+// called from the new device, whose own freshly created user is discarded in favor of the account
+#[spacetimedb::reducer]
+pub fn link_device(ctx: &ReducerContext, link_token: String) -> Result<(), String> {
+    // the device isn't linked yet and still acts as itself
+    check_session(ctx)?;
+    let token = ctx.db.link_token().token().find(&link_token).ok_or("Link token does not exist")?;
+    if token.expires_at <= ctx.timestamp {
+        return Err("Link token has expired".to_string());
+    }
+    if token.account == ctx.sender {
+        return Err("Cannot link a device to itself".to_string());
+    }
+    if ctx.db.linked_device().device().find(ctx.sender).is_some() {
+        return Err("Device is already linked to an account".to_string());
+    }
+    if ctx.db.linked_device().account().filter(ctx.sender).next().is_some() {
+        return Err("Accounts with linked devices cannot be linked to another account".to_string());
+    }
+    let memberships: Vec<GroupChatMembership> = ctx.db.groupchat_membership().identity().filter(ctx.sender).collect();
+    if memberships.iter().any(|membership| membership.role == MembershipRole::Owner) {
+        return Err("Devices owning group chats cannot be linked".to_string());
+    }
+    // `remove_member` already takes the device out of the online counts
+    let online = is_online(ctx, ctx.sender);
+    for membership in memberships {
+        remove_member(ctx, membership);
+    }
+    let own_conversation = ctx.db.direct_conversation().participants().filter((ctx.sender, ctx.sender)).next();
+    if let Some(conversation) = own_conversation {
+        for dm in ctx.db.dm_message().conversation_id().filter(conversation.id) {
+            ctx.db.dm_message().id().delete(dm.id);
+        }
+        ctx.db.direct_conversation().id().delete(conversation.id);
+    }
+    ctx.db.challenge().identity().delete(ctx.sender);
+    ctx.db.challenge_answer().identity().delete(ctx.sender);
+    ctx.db.user_settings().identity().delete(ctx.sender);
+    ctx.db.presence_preference().identity().delete(ctx.sender);
+    ctx.db.username().identity().delete(ctx.sender);
+    ctx.db.user().identity().delete(ctx.sender);
+    ctx.db.link_token().token().delete(&token.token);
+    ctx.db.linked_device().insert(LinkedDevice {
+        device: ctx.sender,
+        account: token.account,
+        linked_at: ctx.timestamp,
+        last_connected_at: ctx.timestamp
+    });
+    for session in ctx.db.session().identity().filter(ctx.sender) {
+        ctx.db.session().connection_id().update(Session {
+            identity: token.account,
+            ..session
+        });
+    }
+    if online {
+        if let Some(user) = ctx.db.user().identity().find(token.account) {
+            let presence = connected_presence(ctx, token.account);
+            update_presence_counts(ctx, token.account, user.presence, presence);
+            ctx.db.user().identity().update(User {
+                presence,
+                last_active: activity_timestamp(ctx, presence, user.last_active),
+                ..user
+            });
+        }
+    }
+    log::info!("User {} linked device {}", token.account, ctx.sender);
+    Ok(())
+}
+
+// the account can revoke any of its devices, a device can unlink itself
+#[spacetimedb::reducer]
+pub fn revoke_device(ctx: &ReducerContext, device: Identity) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let linked = ctx.db.linked_device().device().find(device).ok_or("Device is not linked")?;
+    // a linked device acts as its account, so this also lets a device unlink itself
+    if linked.account != sender {
+        return Err("Only the account or its devices can revoke this device".to_string());
+    }
+    ctx.db.linked_device().device().delete(device);
+    // the device's connections no longer belong to the account, they are terminated like with
+    // `terminate_session` and no longer keep the account online
+    for session in ctx.db.session().identity().filter(linked.account).filter(|session| session.device == device) {
+        ctx.db.session().connection_id().update(Session {
+            identity: device,
+            terminated_at: Some(session.terminated_at.unwrap_or(ctx.timestamp)),
+            ..session
+        });
+    }
+    if ctx.db.session().identity().filter(linked.account).next().is_none() {
+        mark_offline(ctx, linked.account);
+    }
+    log::info!("User {} revoked device {device}", linked.account);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_session_hint(ctx: &ReducerContext, client_hint: String) -> Result<(), String> {
+    let session = check_session(ctx)?;
+    let client_hint = validate_optional_text(Some(client_hint), MAX_CLIENT_HINT_LEN, "Client hint")?;
+    ctx.db.session().connection_id().update(Session {
+        client_hint,
+        ..session
+    });
+    Ok(())
+}
+
+// termination is client-cooperative: the module can't close connections itself, so a terminated
+// connection stays subscribed until its client sees `terminated_at` and disconnects, client reducers
+// reject its calls in the meantime
+#[spacetimedb::reducer]
+pub fn terminate_session(ctx: &ReducerContext, connection_id: ConnectionId) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let session = ctx.db.session().connection_id().find(connection_id).ok_or("Session does not exist")?;
+    if session.identity != sender {
+        return Err("Cannot terminate another user's session".to_string());
+    }
+    if session.terminated_at.is_some() {
+        return Err("Session is already terminated".to_string());
+    }
+    ctx.db.session().connection_id().update(Session {
+        terminated_at: Some(ctx.timestamp),
+        ..session
+    });
+    log::info!("User {} terminated session {connection_id}", sender);
+    Ok(())
+}
+
+// the caller's own session, connections without one or with a terminated one are rejected
+fn check_session(ctx: &ReducerContext) -> Result<Session, String> {
+    let connection_id = ctx.connection_id.ok_or("Reducer was not called over a connection")?;
+    let session = ctx.db.session().connection_id().find(connection_id).ok_or("Session does not exist")?;
+    if session.terminated_at.is_some() {
+        return Err("This session has been terminated".to_string());
+    }
+    Ok(session)
+}
+
+// the identity reducers act as, linked devices act as their account instead of their connection identity.
+// Every client reducer goes through here so calls from connections without a live session are rejected,
+// helpers that can't fail and only run after that use `account_of(ctx, ctx.sender)` directly
+fn acting_identity(ctx: &ReducerContext) -> Result<Identity, String> {
+    check_session(ctx)?;
+    Ok(account_of(ctx, ctx.sender))
+}
+
+// the account a connection identity acts for, identities without a linked device are their own account
+fn account_of(ctx: &ReducerContext, identity: Identity) -> Identity {
+    ctx.db.linked_device().device().find(identity).map_or(identity, |linked| linked.account)
+}
+
+#[spacetimedb::reducer]
+pub fn set_name(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let name = validate_name(ctx, name)?;
+    if let Some(user) = ctx.db.user().identity().find(sender) {
+        claim_name(ctx, sender, &name)?;
+        log::info!("User {} sets name to {name}", sender);
+        ctx.db.user().identity().update(User {
+            name: Some(name),
+            ..user
+        });
+        Ok(())
+    } else {
+        Err("Cannot set name for unknown user".to_string())
+    }
+}
+
+// reserves the normalized form of `name` for `identity`, releasing its previous name
+fn claim_name(ctx: &ReducerContext, identity: Identity, name: &str) -> Result<(), String> {
+    let normalized_name = normalize_name(name);
+    if let Some(taken) = ctx.db.username().normalized_name().find(&normalized_name) {
+        if taken.identity != identity {
+            return Err(format!("The name \"{name}\" is already taken"));
+        }
+    }
+    ctx.db.username().identity().delete(identity);
+    ctx.db.username().insert(Username {
+        normalized_name,
+        identity,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn register_bot(ctx: &ReducerContext, bot: Identity, name: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    require_superadmin(ctx)?;
+    let name = validate_name(ctx, name)?;
+    claim_name(ctx, bot, &name)?;
+    if let Some(user) = ctx.db.user().identity().find(bot) {
+        ctx.db.user().identity().update(User {
+            name: Some(name),
+            is_bot: true,
+            ..user
+        });
+    } else {
+        // bots may be registered before they connect for the first time
+        ctx.db.user().insert(User {
+            identity: bot,
+            name: Some(name),
+            presence: Presence::Offline,
+            last_seen: ctx.timestamp,
+            last_active: ctx.timestamp,
+            bio: None,
+            avatar_url: None,
+            status: None,
+            is_bot: true,
+            verified: false,
+            tos_accepted_version: 0,
+        });
+    }
+    log::info!("User {} registered bot {bot}", sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn update_settings(ctx: &ReducerContext, settings: UserSettings) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let locale = validate_locale(settings.locale)?;
+    if ctx.db.user().identity().find(sender).is_none() {
+        return Err("Cannot update settings for unknown user".to_string());
+    }
+    let settings = UserSettings {
+        identity: sender,
+        locale,
+        ..settings
+    };
+    if ctx.db.user_settings().identity().find(sender).is_some() {
+        ctx.db.user_settings().identity().update(settings);
+    } else {
+        ctx.db.user_settings().insert(settings);
+    }
+    Ok(())
+}
+
+fn validate_locale(locale: String) -> Result<String, String> {
+    let locale = locale.trim().to_string();
+    if locale.is_empty() || locale.len() > MAX_LOCALE_LEN {
+        Err(format!("Locales must be between 1 and {MAX_LOCALE_LEN} characters"))
+    } else if !locale.split('-').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric())) {
+        Err("Locales must be a language tag like \"en\" or \"de-AT\"".to_string())
+    } else {
+        Ok(locale)
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn award_points(ctx: &ReducerContext, target: Identity, amount: u32, reason: Option<String>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let reason = validate_optional_text(reason, MAX_KARMA_REASON_LEN, "Reason")?;
+    if target == sender {
+        return Err("Cannot award points to yourself".to_string());
+    }
+    if amount == 0 || amount > MAX_KARMA_AWARD {
+        return Err(format!("Awards must be between 1 and {MAX_KARMA_AWARD} points"));
+    }
+    if ctx.db.user().identity().find(target).is_none() {
+        return Err("User does not exist".to_string());
+    }
+    let window_start = seconds_ago(ctx, SECONDS_PER_DAY);
+    let recent_awards = ctx.db.karma_transaction().giver().filter(sender)
+        .filter(|transaction| transaction.created_at > window_start)
+        .count();
+    if recent_awards >= MAX_KARMA_AWARDS_PER_DAY {
+        return Err(format!("You can award points at most {MAX_KARMA_AWARDS_PER_DAY} times per day"));
+    }
+    ctx.db.karma_transaction().insert(KarmaTransaction {
+        id: 0,
+        giver: sender,
+        recipient: target,
+        amount,
+        reason,
+        created_at: ctx.timestamp
+    });
+    if let Some(karma) = ctx.db.karma().identity().find(target) {
+        ctx.db.karma().identity().update(Karma {
+            points: karma.points + amount as u64,
+            ..karma
+        });
+    } else {
+        ctx.db.karma().insert(Karma {
+            identity: target,
+            points: amount as u64
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn heartbeat(ctx: &ReducerContext) -> Result<(), String> {
+    let identity = acting_identity(ctx)?;
+    let user = ctx.db.user().identity().find(identity).ok_or("Cannot send heartbeat for unknown user")?;
+    let presence = connected_presence(ctx, identity);
+    update_presence_counts(ctx, identity, user.presence, presence);
+    ctx.db.user().identity().update(User {
+        presence,
+        last_active: activity_timestamp(ctx, presence, user.last_active),
+        ..user
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_presence(ctx: &ReducerContext, presence: Presence, status: Option<String>) -> Result<(), String> {
+    let identity = acting_identity(ctx)?;
+    if presence == Presence::Offline {
+        return Err("Use `Invisible` to appear offline while connected".to_string());
+    }
+    let status = validate_optional_text(status, MAX_STATUS_LEN, "Status")?;
+    let user = ctx.db.user().identity().find(identity).ok_or("Cannot set presence for unknown user")?;
+    let preference = PresencePreference { identity, presence };
+    if ctx.db.presence_preference().identity().find(identity).is_some() {
+        ctx.db.presence_preference().identity().update(preference);
+    } else {
+        ctx.db.presence_preference().insert(preference);
+    }
+    let shown = connected_presence(ctx, identity);
+    update_presence_counts(ctx, identity, user.presence, shown);
+    ctx.db.user().identity().update(User {
+        presence: shown,
+        status,
+        last_active: activity_timestamp(ctx, shown, user.last_active),
+        ..user
+    });
+    Ok(())
+}
+
+// presence shown to others while the user is connected
+fn connected_presence(ctx: &ReducerContext, identity: Identity) -> Presence {
+    match ctx.db.presence_preference().identity().find(identity).map(|preference| preference.presence) {
+        None => Presence::Online,
+        Some(Presence::Invisible) => Presence::Offline,
+        Some(presence) => presence,
+    }
+}
+
+// invisible users are shown as offline, their public timestamps stay frozen so they can't be spotted
+// through `last_active` and `last_seen` changing
+fn activity_timestamp(ctx: &ReducerContext, shown: Presence, previous: Timestamp) -> Timestamp {
+    if shown == Presence::Offline { previous } else { ctx.timestamp }
+}
+
+// away and do not disturb users still count towards `online_count`
+fn update_presence_counts(ctx: &ReducerContext, identity: Identity, old: Presence, new: Presence) {
+    if (old == Presence::Offline) != (new == Presence::Offline) {
+        update_online_counts(ctx, identity, new != Presence::Offline);
+    }
+}
+
+// catches connections that were dropped without a disconnect event
+#[spacetimedb::reducer]
+pub fn sweep_presence(ctx: &ReducerContext, _sweep: PresenceSweep) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `sweep_presence` may only be invoked by the scheduler".to_string());
+    }
+    let cutoff = seconds_ago(ctx, PRESENCE_TIMEOUT_SECS);
+    for user in ctx.db.user().iter().filter(|user| user.presence != Presence::Offline && user.last_active < cutoff) {
+        update_online_counts(ctx, user.identity, false);
+        ctx.db.user().identity().update(User {
+            presence: Presence::Offline,
+            last_seen: user.last_active,
+            ..user
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_verified(ctx: &ReducerContext, target: Identity, verified: bool) -> Result<(), String> {
+    require_superadmin(ctx)?;
+    let user = ctx.db.user().identity().find(target).ok_or("User does not exist")?;
+    audit(ctx, MODULE_AUDIT_GROUPCHAT_ID, AuditAction::SetVerified, Some(target), Some(verified.to_string()));
+    ctx.db.user().identity().update(User {
+        verified,
+        ..user
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn update_profile(ctx: &ReducerContext, bio: Option<String>, avatar_url: Option<String>, status: Option<String>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let bio = validate_optional_text(bio, MAX_BIO_LEN, "Bio")?;
+    let avatar_url = avatar_url.map(validate_url).transpose()?;
+    let status = validate_optional_text(status, MAX_STATUS_LEN, "Status")?;
+    let user = ctx.db.user().identity().find(sender).ok_or("Cannot update profile for unknown user")?;
+    ctx.db.user().identity().update(User {
+        bio,
+        avatar_url,
+        status,
+        ..user
+    });
+    Ok(())
+}
+
+// `groupchat_id` is None for messages outside of group chats, which are only checked against global banned words
+fn validate_message(ctx: &ReducerContext, groupchat_id: Option<&str>, text: String) -> Result<String, String> {
+    let max_message_len = config(ctx).max_message_len;
+    if text.is_empty() {
+        Err("Messages must not be empty".to_string())
+    } else if text.chars().count() > max_message_len as usize {
+        Err(format!("Messages must be at most {max_message_len} characters"))
+    } else if let Some(word) = find_banned_word(ctx, groupchat_id, &text) {
+        Err(format!("Message contains the banned word \"{word}\""))
+    } else {
+        Ok(text)
+    }
+}
+
+fn find_banned_word(ctx: &ReducerContext, groupchat_id: Option<&str>, text: &str) -> Option<String> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect();
+    ctx.db.banned_word().iter()
+        .filter(|banned| banned.groupchat_id.is_none() || banned.groupchat_id.as_deref() == groupchat_id)
+        .find(|banned| words.contains(&banned.word))
+        .map(|banned| banned.word)
+}
+
+fn normalize_banned_word(word: String) -> Result<String, String> {
+    let word = word.trim().to_lowercase();
+    if word.is_empty() {
+        Err("Banned word must not be empty".to_string())
+    } else if word.chars().any(|c| !c.is_alphanumeric()) {
+        Err("Banned words must be a single word".to_string())
+    } else {
+        Ok(word)
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn add_banned_word(ctx: &ReducerContext, groupchat_id: Option<String>, word: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let word = normalize_banned_word(word)?;
+    match &groupchat_id {
+        Some(groupchat_id) => { require_role(ctx, groupchat_id, MembershipRole::Admin)?; }
+        None => require_superadmin(ctx)?,
+    }
+    if ctx.db.banned_word().iter().any(|banned| banned.groupchat_id == groupchat_id && banned.word == word) {
+        return Err("Word is already banned".to_string());
+    }
+    if let Some(groupchat_id) = &groupchat_id {
+        audit(ctx, groupchat_id, AuditAction::AddBannedWord, None, Some(word.clone()));
+    }
+    ctx.db.banned_word().insert(BannedWord {
+        id: 0,
+        groupchat_id,
+        word,
+        added_by: sender
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_banned_word(ctx: &ReducerContext, banned_word_id: u64) -> Result<(), String> {
+    let banned = ctx.db.banned_word().id().find(banned_word_id).ok_or("Banned word does not exist")?;
+    match &banned.groupchat_id {
+        Some(groupchat_id) => {
+            require_role(ctx, groupchat_id, MembershipRole::Admin)?;
+            audit(ctx, groupchat_id, AuditAction::RemoveBannedWord, None, Some(banned.word.clone()));
+        }
+        None => require_superadmin(ctx)?,
+    }
+    ctx.db.banned_word().id().delete(banned_word_id);
+    Ok(())
+}
+
+fn validate_group_name(ctx: &ReducerContext, name: String) -> Result<String, String> {
+    let max_group_name_len = config(ctx).max_group_name_len;
+    if name.is_empty() {
+        Err("Group chat name must not be empty".to_string())
+    } else if name.chars().count() > max_group_name_len as usize {
+        Err(format!("Group chat names must be at most {max_group_name_len} characters"))
+    } else {
+        Ok(name)
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn create_groupchat(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    check_tos_accepted(ctx)?;
+    check_challenge_passed(ctx)?;
+    let name = validate_group_name(ctx, name)?;
+    check_membership_limit(ctx, sender)?;
+    check_group_creation_limit(ctx)?;
+    ctx.db.groupchat().try_insert(new_groupchat(ctx, name.clone()))?;
+    // Add the creator as the owner of the group chat
+    add_member(ctx, sender, name, MembershipRole::Owner);
+    Ok(())
+}
+
+// records the creation when it is allowed, ownership transfers count towards the new owner
+fn check_group_creation_limit(ctx: &ReducerContext) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let Config { max_groups_created_per_hour, max_groups_owned, .. } = config(ctx);
+    let owned = ctx.db.groupchat_membership().identity().filter(sender)
+        .filter(|membership| membership.role == MembershipRole::Owner)
+        .count();
+    if owned >= max_groups_owned as usize {
+        return Err(format!("Cannot own more than {max_groups_owned} group chats"));
+    }
+    let window_start = seconds_ago(ctx, 60 * 60);
+    let mut recent_creations: Vec<Timestamp> = ctx.db.group_creation_limit().identity().find(sender)
+        .map(|limit| limit.recent_creations)
+        .unwrap_or_default();
+    recent_creations.retain(|created_at| *created_at > window_start);
+    if recent_creations.len() >= max_groups_created_per_hour as usize {
+        return Err(format!("At most {max_groups_created_per_hour} group chats can be created per hour"));
+    }
+    recent_creations.push(ctx.timestamp);
+    let limit = GroupCreationLimit { identity: sender, recent_creations };
+    if ctx.db.group_creation_limit().identity().find(sender).is_some() {
+        ctx.db.group_creation_limit().identity().update(limit);
+    } else {
+        ctx.db.group_creation_limit().insert(limit);
+    }
+    Ok(())
+}
+
+fn new_groupchat(ctx: &ReducerContext, name: String) -> GroupChat {
+    GroupChat {
+        id: name.clone(),
+        slug: unique_slug(ctx, &name),
+        name,
+        created_by: account_of(ctx, ctx.sender),
+        visibility: GroupVisibility::Public,
+        next_seq: 1,
+        last_message_at: ctx.timestamp,
+        message_count: 0,
+        retention_days: None,
+        description: None,
+        topic: None,
+        avatar_url: None,
+        slowmode_seconds: 0,
+        post_policy: PostPolicy::Everyone,
+        archived: false,
+        member_count: 0,
+        online_count: 0,
+        discoverable: false,
+        nsfw: false,
+        max_members: None,
+        message_ttl_seconds: None,
+        strike_threshold: 0,
+        strike_escalation: StrikeEscalation::Mute,
+        deleted_account_policy: DeletedAccountPolicy::Anonymize,
+        key_epoch: 0,
+        key_rotation_pending: false,
+        has_live_messages: false
+    }
+}
+
+// lowercase ascii words joined by dashes, a numeric suffix is appended on collision
+fn unique_slug(ctx: &ReducerContext, name: &str) -> String {
+    let mut base = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            base.push(c.to_ascii_lowercase());
+        } else if !base.is_empty() && !base.ends_with('-') {
+            base.push('-');
+        }
+    }
+    let mut base = base.trim_end_matches('-').to_string();
+    if base.is_empty() {
+        base = "group".to_string();
+    }
+    let mut slug = base.clone();
+    let mut suffix = 2;
+    while ctx.db.groupchat().slug().find(&slug).is_some() {
+        slug = format!("{base}-{suffix}");
+        suffix += 1;
+    }
+    slug
+}
+
+#[spacetimedb::reducer]
+pub fn join_by_slug(ctx: &ReducerContext, slug: String) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().slug().find(&slug.trim().to_lowercase()).ok_or("Group chat does not exist")?;
+    join_groupchat(ctx, groupchat.id)
+}
+
+#[spacetimedb::reducer]
+pub fn join_groupchat(ctx: &ReducerContext, groupchat: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if let Some(user) = ctx.db.user().identity().find(sender) {
+        if let Some(chat) = ctx.db.groupchat().id().find(&groupchat) {
+            if chat.visibility == GroupVisibility::Private {
+                return Err("Group chat is private, an invite is required to join".to_string());
+            }
+            check_can_join(ctx, sender, &chat)?;
+            // if membership to this groupchat already exists for this user, error out
+            if ctx.db.groupchat_membership().user_and_groupchat().filter((user.identity, &groupchat)).next().is_none() {
+                add_member(ctx, sender, groupchat.clone(), MembershipRole::Member);
+                let text = format!("{} joined the group chat", display_name(ctx, sender));
+                post_system_message(ctx, groupchat, text);
+            } else {
+                return Err("User is already a member of this group chat".to_string());
+            }
+            Ok(())
+        } else {
+            Err("Group chat does not exist".to_string())
+        }
+    } else {
+        Err("Cannot join group chat for unknown user".to_string())
+    }
+}
+
+// checks that don't depend on how a user joins a group chat
+fn check_can_join(ctx: &ReducerContext, identity: Identity, groupchat: &GroupChat) -> Result<(), String> {
+    if groupchat.archived {
+        return Err("Group chat is archived".to_string());
+    }
+    if is_banned(ctx, &groupchat.id, identity) {
+        return Err("User is banned from this group chat".to_string());
+    }
+    if groupchat.max_members.is_some_and(|max_members| groupchat.member_count >= max_members) {
+        return Err("Group chat is full".to_string());
+    }
+    if groupchat.nsfw && !shows_nsfw(ctx, identity) {
+        return Err("Group chat is marked nsfw, enable nsfw content in your settings to join".to_string());
+    }
+    check_membership_limit(ctx, identity)
+}
+
+fn check_membership_limit(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    let max_memberships_per_user = config(ctx).max_memberships_per_user;
+    if ctx.db.groupchat_membership().identity().filter(identity).count() >= max_memberships_per_user as usize {
+        return Err(format!("User cannot be a member of more than {max_memberships_per_user} group chats"));
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn send_message(ctx: &ReducerContext, groupchat: String, text: String, client_id: Option<u128>, topic_id: Option<u64>, lang: Option<String>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    // a retry of a message that was already posted succeeds without posting it again
+    if client_id.is_some_and(|client_id| is_duplicate_send(ctx, sender, client_id)) {
+        return Ok(());
+    }
+    let text = validate_message(ctx, Some(&groupchat), text)?;
+    if let Some(topic_id) = topic_id {
+        check_topic_open(ctx, &groupchat, topic_id)?;
+    }
+    let lang = lang.map(validate_locale).transpose()?;
+    check_rate_limit(ctx)?;
+    check_can_post(ctx, &groupchat)?;
+    let message = post_message(ctx, Message {
+        client_id,
+        topic_id,
+        lang,
+        ..new_message(ctx, sender, groupchat, text)
+    });
+    if let Some(client_id) = message.client_id {
+        ctx.db.sent_client_id().insert(SentClientId {
+            id: 0,
+            sender,
+            client_id,
+            sent: message.sent
+        });
+    }
+    award_builtin_badge(ctx, sender, BuiltinBadge::FirstMessage);
+    let total_messages: u64 = ctx.db.member_stats().identity().filter(sender).map(|stats| stats.message_count).sum();
+    if total_messages >= HUNDRED_MESSAGES_BADGE_THRESHOLD {
+        award_builtin_badge(ctx, sender, BuiltinBadge::HundredMessages);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn send_encrypted_message(ctx: &ReducerContext, groupchat_id: String, ciphertext: Vec<u8>, nonce: Vec<u8>, key_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if ciphertext.is_empty() || ciphertext.len() > MAX_CIPHERTEXT_LEN {
+        return Err(format!("Ciphertext must be between 1 and {MAX_CIPHERTEXT_LEN} bytes"));
+    }
+    if nonce.is_empty() || nonce.len() > MAX_NONCE_LEN {
+        return Err(format!("Nonce must be between 1 and {MAX_NONCE_LEN} bytes"));
+    }
+    let key_id = validate_key_id(key_id)?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    if groupchat.key_epoch > 0 && key_id != groupchat.key_epoch.to_string() {
+        return Err(format!("Messages must be encrypted with the key of the current epoch {}", groupchat.key_epoch));
+    }
+    check_rate_limit(ctx)?;
+    check_can_post(ctx, &groupchat_id)?;
+    // the module can't read the content, so banned words, mentions and link handling don't apply
+    post_message(ctx, Message {
+        ciphertext: Some(ciphertext),
+        nonce: Some(nonce),
+        key_id: Some(key_id),
+        ..new_message(ctx, sender, groupchat_id, String::new())
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn share_group_key(ctx: &ReducerContext, groupchat_id: String, key_id: String, recipient: Identity, wrapped_key: Vec<u8>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if find_membership(ctx, sender, &groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if find_membership(ctx, recipient, &groupchat_id).is_none() {
+        return Err("Recipient is not a member of this group chat".to_string());
+    }
+    let key_id = validate_key_id(key_id)?;
+    // numeric key ids belong to `publish_group_key` epochs
+    if key_id.parse::<u64>().is_ok() {
+        return Err("Key ids that are epoch numbers are reserved for published group keys".to_string());
+    }
+    if wrapped_key.is_empty() || wrapped_key.len() > MAX_WRAPPED_KEY_LEN {
+        return Err(format!("Wrapped keys must be between 1 and {MAX_WRAPPED_KEY_LEN} bytes"));
+    }
+    if ctx.db.group_key().groupchat_key_and_recipient().filter((&groupchat_id, &key_id, recipient)).next().is_some() {
+        return Err("Recipient already has this key".to_string());
+    }
+    ctx.db.group_key().insert(GroupKey {
+        id: 0,
+        groupchat_id,
+        key_id,
+        recipient,
+        wrapped_key,
+        created_by: sender,
+        created_at: ctx.timestamp
+    });
+    Ok(())
+}
+
+// the key id of an epoch's wrapped keys is the epoch number
+#[spacetimedb::reducer]
+pub fn publish_group_key(ctx: &ReducerContext, groupchat_id: String, epoch: u64, wrapped_keys: Vec<WrappedKey>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    if find_membership(ctx, sender, &groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if epoch != groupchat.key_epoch + 1 {
+        return Err(format!("Next key epoch must be {}", groupchat.key_epoch + 1));
+    }
+    let mut members: Vec<Identity> = ctx.db.groupchat_membership().groupchat_id().filter(&groupchat_id)
+        .map(|membership| membership.identity)
+        .collect();
+    let mut recipients: Vec<Identity> = wrapped_keys.iter().map(|wrapped| wrapped.recipient).collect();
+    members.sort_unstable();
+    recipients.sort_unstable();
+    if recipients != members {
+        return Err("Wrapped keys must be provided for exactly the current members".to_string());
+    }
+    if wrapped_keys.iter().any(|wrapped| wrapped.wrapped_key.is_empty() || wrapped.wrapped_key.len() > MAX_WRAPPED_KEY_LEN) {
+        return Err(format!("Wrapped keys must be between 1 and {MAX_WRAPPED_KEY_LEN} bytes"));
+    }
+    ctx.db.group_key_epoch().insert(GroupKeyEpoch {
+        id: 0,
+        groupchat_id: groupchat_id.clone(),
+        epoch,
+        published_by: sender,
+        published_at: ctx.timestamp
+    });
+    let key_id = epoch.to_string();
+    for wrapped in wrapped_keys {
+        // replaces rows shared under this key id before epoch numbers were reserved
+        for existing in ctx.db.group_key().groupchat_key_and_recipient().filter((&groupchat_id, &key_id, wrapped.recipient)) {
+            ctx.db.group_key().id().delete(existing.id);
+        }
+        ctx.db.group_key().insert(GroupKey {
+            id: 0,
+            groupchat_id: groupchat_id.clone(),
+            key_id: key_id.clone(),
+            recipient: wrapped.recipient,
+            wrapped_key: wrapped.wrapped_key,
+            created_by: sender,
+            created_at: ctx.timestamp
+        });
+    }
+    ctx.db.groupchat().id().update(GroupChat {
+        key_epoch: epoch,
+        key_rotation_pending: false,
+        ..groupchat
+    });
+    Ok(())
+}
+
+fn validate_key_id(key_id: String) -> Result<String, String> {
+    let key_id = key_id.trim().to_string();
+    if key_id.is_empty() || key_id.len() > MAX_KEY_ID_LEN {
+        Err(format!("Key ids must be between 1 and {MAX_KEY_ID_LEN} characters"))
+    } else {
+        Ok(key_id)
+    }
+}
+
+// also prunes the sender's client ids that fell out of the deduplication window
+fn is_duplicate_send(ctx: &ReducerContext, sender: Identity, client_id: u128) -> bool {
+    let window_start = seconds_ago(ctx, CLIENT_ID_WINDOW_SECS);
+    for expired in ctx.db.sent_client_id().sender_and_client_id().filter(sender).filter(|sent| sent.sent <= window_start) {
+        ctx.db.sent_client_id().id().delete(expired.id);
+    }
+    ctx.db.sent_client_id().sender_and_client_id().filter((sender, client_id)).next().is_some()
+}
+
+fn validate_attachment(attachment: AttachmentInput) -> Result<AttachmentInput, String> {
+    if attachment.url.is_none() && attachment.content_hash.is_none() {
+        return Err("Attachments need a url or a content hash".to_string());
+    }
+    let url = attachment.url.map(validate_url).transpose()?;
+    if attachment.size > MAX_ATTACHMENT_SIZE {
+        return Err(format!("Attachments must be at most {MAX_ATTACHMENT_SIZE} bytes"));
+    }
+    let mime_type = attachment.mime_type.trim().to_lowercase();
+    match mime_type.split_once('/') {
+        Some((kind, subtype)) if !kind.is_empty() && !subtype.is_empty() && !mime_type.contains(char::is_whitespace) => {}
+        _ => return Err("Attachments need a valid mime type".to_string()),
+    }
+    Ok(AttachmentInput {
+        url,
+        mime_type,
+        ..attachment
+    })
+}
+
+#[spacetimedb::reducer]
+pub fn send_message_with_attachments(ctx: &ReducerContext, groupchat: String, text: String, attachments: Vec<AttachmentInput>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if attachments.is_empty() {
+        return Err("Use send_message for messages without attachments".to_string());
+    }
+    if attachments.len() > MAX_ATTACHMENTS_PER_MESSAGE {
+        return Err(format!("Messages can have at most {MAX_ATTACHMENTS_PER_MESSAGE} attachments"));
+    }
+    // the text is optional when sending attachments
+    let text = if text.is_empty() { text } else { validate_message(ctx, Some(&groupchat), text)? };
+    let attachments = attachments.into_iter().map(validate_attachment).collect::<Result<Vec<_>, _>>()?;
+    check_rate_limit(ctx)?;
+    check_can_post(ctx, &groupchat)?;
+    let message = post_message(ctx, new_message(ctx, sender, groupchat, text));
+    for attachment in attachments {
+        ctx.db.attachment().insert(Attachment {
+            id: 0,
+            message_id: message.id,
+            kind: attachment.kind,
+            url: attachment.url,
+            content_hash: attachment.content_hash,
+            size: attachment.size,
+            mime_type: attachment.mime_type
+        });
+    }
+    Ok(())
+}
+
+// checks if the caller is currently allowed to post into a group chat
+fn check_can_post(ctx: &ReducerContext, groupchat_id: &str) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    check_tos_accepted(ctx)?;
+    // check if groupchat exists and if membership exists for this user in this groupchat
+    let groupchat = ctx.db.groupchat().id().find(groupchat_id.to_string()).ok_or("Group chat does not exist")?;
+    if groupchat.archived {
+        return Err("Group chat is archived".to_string());
+    }
+    let membership = has_permission(ctx, groupchat_id, GroupAction::Post)?;
+    if let Some(mute) = find_active_mute(ctx, groupchat_id, sender) {
+        return Err(format!("You are muted in this group chat until {}", mute.until));
+    }
+    if groupchat.post_policy == PostPolicy::AdminsOnly && membership.role < MembershipRole::Admin {
+        return Err("Only admins can post in this group chat".to_string());
+    }
+    // admins are exempt from slow mode
+    if groupchat.slowmode_seconds > 0 && membership.role < MembershipRole::Admin {
+        if let Some(last_posted_at) = membership.last_posted_at {
+            let next_allowed = Timestamp::from_micros_since_unix_epoch(
+                last_posted_at.to_micros_since_unix_epoch() + groupchat.slowmode_seconds as i64 * 1_000_000
+            );
+            if ctx.timestamp < next_allowed {
+                return Err(format!("Slow mode is enabled, you can only send one message every {} seconds", groupchat.slowmode_seconds));
+            }
+        }
+    }
+    Ok(())
+}
+
+// history can only be imported into a group chat no member has posted in yet, split over as many batches as needed
+#[spacetimedb::reducer]
+pub fn import_messages(ctx: &ReducerContext, groupchat_id: String, batch: Vec<ImportedMessage>) -> Result<(), String> {
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    if batch.is_empty() || batch.len() > MAX_IMPORT_BATCH {
+        return Err(format!("Imports must contain between 1 and {MAX_IMPORT_BATCH} messages"));
+    }
+    if batch.windows(2).any(|pair| pair[1].sent < pair[0].sent) {
+        return Err("Imported messages must be ordered by their timestamp".to_string());
+    }
+    if batch.iter().any(|imported| imported.sent > ctx.timestamp) {
+        return Err("Imported messages must not be from the future".to_string());
+    }
+    let batch = batch
+        .into_iter()
+        .map(|imported| Ok(ImportedMessage {
+            sender_label: validate_name(ctx, imported.sender_label)?,
+            text: validate_message(ctx, Some(&groupchat_id), imported.text)?,
+            ..imported
+        }))
+        .collect::<Result<Vec<_>, String>>()?;
+    let mut groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    // imported history takes the next `seq` values, so it only stays ahead of live messages while there are none yet
+    if groupchat.has_live_messages {
+        return Err("Messages can only be imported before members start posting in the group chat".to_string());
+    }
+    audit(ctx, &groupchat_id, AuditAction::ImportMessages, None, Some(batch.len().to_string()));
+    // imported history is inserted directly so it doesn't trigger unread counts, mentions or webhooks
+    for imported in batch {
+        ctx.db.message().insert(Message {
+            sent: imported.sent,
+            day: day_of(imported.sent),
+            seq: groupchat.next_seq,
+            imported_sender: Some(imported.sender_label),
+            ..new_message(ctx, ctx.identity(), groupchat_id.clone(), imported.text)
+        });
+        groupchat.next_seq += 1;
+        groupchat.message_count += 1;
+        if imported.sent > groupchat.last_message_at {
+            groupchat.last_message_at = imported.sent;
+        }
+    }
+    ctx.db.groupchat().id().update(groupchat);
+    Ok(())
+}
+
+fn check_topic_open(ctx: &ReducerContext, groupchat_id: &str, topic_id: u64) -> Result<(), String> {
+    let topic = ctx.db.topic().id().find(topic_id).ok_or("Topic does not exist")?;
+    if topic.groupchat_id != groupchat_id {
+        return Err("Topic does not belong to this group chat".to_string());
+    }
+    if topic.archived {
+        return Err("Topic is archived".to_string());
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn create_topic(ctx: &ReducerContext, groupchat_id: String, name: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let name = validate_optional_text(Some(name), MAX_TOPIC_NAME_LEN, "Topic name")?.ok_or("Topic name must not be empty")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    if ctx.db.topic().groupchat_and_name().filter((&groupchat_id, &name)).next().is_some() {
+        return Err("Group chat already has a topic with this name".to_string());
+    }
+    if ctx.db.topic().groupchat_id().filter(&groupchat_id).count() >= MAX_TOPICS_PER_GROUP {
+        return Err(format!("A group chat can have at most {MAX_TOPICS_PER_GROUP} topics"));
+    }
+    audit(ctx, &groupchat_id, AuditAction::CreateTopic, None, Some(name.clone()));
+    ctx.db.topic().insert(Topic {
+        id: 0,
+        groupchat_id,
+        name,
+        created_by: sender,
+        archived: false
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn archive_topic(ctx: &ReducerContext, topic_id: u64) -> Result<(), String> {
+    let topic = ctx.db.topic().id().find(topic_id).ok_or("Topic does not exist")?;
+    require_role(ctx, &topic.groupchat_id, MembershipRole::Admin)?;
+    if topic.archived {
+        return Err("Topic is already archived".to_string());
+    }
+    audit(ctx, &topic.groupchat_id, AuditAction::ArchiveTopic, None, Some(topic.name.clone()));
+    ctx.db.topic().id().update(Topic {
+        archived: true,
+        ..topic
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn reply_to_message(ctx: &ReducerContext, parent_id: u64, text: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let parent = ctx.db.message().id().find(parent_id).ok_or("Parent message does not exist")?;
+    let text = validate_message(ctx, Some(&parent.groupchat_id), text)?;
+    check_rate_limit(ctx)?;
+    if parent.deleted {
+        return Err("Cannot reply to a deleted message".to_string());
+    }
+    check_can_post(ctx, &parent.groupchat_id)?;
+    if let Some(topic_id) = parent.topic_id {
+        check_topic_open(ctx, &parent.groupchat_id, topic_id)?;
+    }
+    // threads are flat, replying to a reply continues the thread of its root message
+    let root_message_id = parent.reply_to.unwrap_or(parent.id);
+    post_message(ctx, Message {
+        reply_to: Some(root_message_id),
+        topic_id: parent.topic_id,
+        ..new_message(ctx, sender, parent.groupchat_id, text)
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn forward_message(ctx: &ReducerContext, message_id: u64, target_groupchat_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if message.deleted {
+        return Err("Cannot forward a deleted message".to_string());
+    }
+    if find_membership(ctx, sender, &message.groupchat_id).is_none() {
+        return Err("User is not a member of the source group chat".to_string());
+    }
+    copy_message(ctx, &message, target_groupchat_id)
+}
+
+#[spacetimedb::reducer]
+pub fn crosspost_message(ctx: &ReducerContext, message_id: u64, target_groups: Vec<String>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if message.sender != sender {
+        return Err("Only the sender can crosspost this message".to_string());
+    }
+    if message.deleted {
+        return Err("Cannot crosspost a deleted message".to_string());
+    }
+    let mut target_groups = target_groups;
+    target_groups.sort();
+    target_groups.dedup();
+    if target_groups.is_empty() || target_groups.len() > MAX_CROSSPOST_TARGETS {
+        return Err(format!("Messages can be crossposted into between 1 and {MAX_CROSSPOST_TARGETS} group chats"));
+    }
+    if target_groups.contains(&message.groupchat_id) {
+        return Err("Cannot crosspost a message into its own group chat".to_string());
+    }
+    // every copy counts against the rate limit, a failing target aborts the whole crosspost
+    for target_groupchat_id in target_groups {
+        copy_message(ctx, &message, target_groupchat_id)?;
+    }
+    Ok(())
+}
+
+// posts a copy of `message` by the caller, keeping a reference to where it came from
+fn copy_message(ctx: &ReducerContext, message: &Message, target_groupchat_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let text = validate_message(ctx, Some(&target_groupchat_id), message.text.clone())?;
+    check_rate_limit(ctx)?;
+    check_can_post(ctx, &target_groupchat_id)?;
+    let copy = post_message(ctx, Message {
+        forwarded_from: Some(message.id),
+        // copying a copied message keeps pointing at the original author
+        original_sender: Some(message.original_sender.unwrap_or(message.sender)),
+        ..new_message(ctx, sender, target_groupchat_id, text)
+    });
+    let attachments: Vec<Attachment> = ctx.db.attachment().message_id().filter(message.id).collect();
+    for attachment in attachments {
+        ctx.db.attachment().insert(Attachment {
+            id: 0,
+            message_id: copy.id,
+            ..attachment
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn search_messages(ctx: &ReducerContext, groupchat_id: String, query: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Err("Search query must not be empty".to_string());
+    }
+    if find_membership(ctx, sender, &groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    // every search replaces the caller's previous results
+    for result in ctx.db.search_result().identity().filter(sender) {
+        ctx.db.search_result().id().delete(result.id);
+    }
+    let mut matches: Vec<u64> = ctx.db.message().groupchat_id().filter(&groupchat_id)
+        .filter(|message| !message.deleted && message.text.to_lowercase().contains(&query))
+        .map(|message| message.id)
+        .collect();
+    // newest matches first
+    matches.sort_unstable_by(|a, b| b.cmp(a));
+    for message_id in matches.into_iter().take(MAX_SEARCH_RESULTS) {
+        ctx.db.search_result().insert(SearchResult {
+            id: 0,
+            identity: sender,
+            message_id,
+            groupchat_id: groupchat_id.clone()
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn search_users(ctx: &ReducerContext, prefix: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let prefix = normalize_name(&prefix);
+    if prefix.is_empty() {
+        return Err("Search prefix must not be empty".to_string());
+    }
+    // every search replaces the caller's previous results
+    for result in ctx.db.user_search_result().identity().filter(sender) {
+        ctx.db.user_search_result().id().delete(result.id);
+    }
+    let mut matches: Vec<Username> = ctx.db.username().iter()
+        .filter(|username| username.normalized_name.starts_with(&prefix))
+        .filter(|username| username.identity != sender && !is_blocked(ctx, username.identity, sender))
+        .collect();
+    // shortest names first so exact matches come out on top
+    matches.sort_unstable_by(|a, b| a.normalized_name.len().cmp(&b.normalized_name.len()).then_with(|| a.normalized_name.cmp(&b.normalized_name)));
+    for username in matches.into_iter().take(MAX_USER_SEARCH_RESULTS) {
+        ctx.db.user_search_result().insert(UserSearchResult {
+            id: 0,
+            identity: sender,
+            user_identity: username.identity
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn edit_message(ctx: &ReducerContext, message_id: u64, new_text: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    let new_text = validate_message(ctx, Some(&message.groupchat_id), new_text)?;
+    // only the original sender may edit a message
+    if message.sender != sender {
+        return Err("Only the sender can edit this message".to_string());
+    }
+    if message.deleted {
+        return Err("Cannot edit a deleted message".to_string());
+    }
+    if message.ciphertext.is_some() {
+        return Err("Cannot edit an encrypted message".to_string());
+    }
+    ctx.db.message_edit().insert(MessageEdit {
+        id: 0,
+        message_id,
+        editor: sender,
+        old_text: message.text.clone(),
+        edited_at: ctx.timestamp
+    });
+    clear_translations(ctx, message_id);
+    ctx.db.message().id().update(Message {
+        text: new_text,
+        edited_at: Some(ctx.timestamp),
+        ..message
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn store_translation(ctx: &ReducerContext, message_id: u64, target_lang: String, text: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if find_membership(ctx, sender, &message.groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if message.deleted {
+        return Err("Cannot translate a deleted message".to_string());
+    }
+    let target_lang = validate_locale(target_lang)?;
+    if message.lang.as_ref() == Some(&target_lang) {
+        return Err("Message is already in this language".to_string());
+    }
+    let text = validate_message(ctx, Some(&message.groupchat_id), text)?;
+    // the first translation stored for a language is kept, clients fetch their own only when none exists yet
+    if ctx.db.translation_cache().message_and_lang().filter((message_id, &target_lang)).next().is_some() {
+        return Err("A translation into this language already exists".to_string());
+    }
+    ctx.db.translation_cache().insert(TranslationCache {
+        id: 0,
+        message_id,
+        target_lang,
+        text,
+        translated_by: sender,
+        created_at: ctx.timestamp
+    });
+    Ok(())
+}
+
+fn clear_translations(ctx: &ReducerContext, message_id: u64) {
+    for translation in ctx.db.translation_cache().message_id().filter(message_id) {
+        ctx.db.translation_cache().id().delete(translation.id);
+    }
+}
+
+// unlike `delete_message` this removes the message entirely instead of leaving a placeholder
+#[spacetimedb::reducer]
+pub fn unsend_message(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if message.sender != sender {
+        return Err("Only the sender can unsend this message".to_string());
+    }
+    let unsend_window_secs = config(ctx).unsend_window_secs;
+    if unsend_window_secs == 0 {
+        return Err("Unsending messages is disabled".to_string());
+    }
+    if message.sent < seconds_ago(ctx, unsend_window_secs as u64) {
+        return Err(format!("Messages can only be unsent within {unsend_window_secs} seconds of sending them"));
+    }
+    purge_message(ctx, message_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn delete_message(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    // senders can delete their own messages, admins can delete anyone's
+    if message.sender != sender {
+        require_role(ctx, &message.groupchat_id, MembershipRole::Admin)
+            .map_err(|_| "Only the sender or an admin can delete this message".to_string())?;
+        audit(ctx, &message.groupchat_id, AuditAction::DeleteMessage, Some(message.sender), Some(message.text.clone()));
+    }
+    if message.deleted {
+        return Err("Message is already deleted".to_string());
+    }
+    // soft-delete: keep the row so clients can render a placeholder, but drop the content
+    for attachment in ctx.db.attachment().message_id().filter(message_id) {
+        ctx.db.attachment().id().delete(attachment.id);
+    }
+    clear_translations(ctx, message_id);
+    ctx.db.message().id().update(Message {
+        text: String::new(),
+        ciphertext: None,
+        nonce: None,
+        deleted: true,
+        ..message
+    });
+    Ok(())
+}
+
+fn validate_emoji(emoji: String) -> Result<String, String> {
+    if emoji.is_empty() {
+        Err("Emoji must not be empty".to_string())
+    } else if emoji.chars().count() > MAX_EMOJI_LEN {
+        Err("Emoji is too long".to_string())
+    } else {
+        Ok(emoji)
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn add_reaction(ctx: &ReducerContext, message_id: u64, emoji: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let emoji = validate_emoji(emoji)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if message.deleted {
+        return Err("Cannot react to a deleted message".to_string());
+    }
+    if let Some(shortcode) = emoji.strip_prefix(':').and_then(|emoji| emoji.strip_suffix(':')) {
+        if ctx.db.custom_emoji().groupchat_and_shortcode().filter((&message.groupchat_id, shortcode)).next().is_none() {
+            return Err("Custom emoji does not exist in this group chat".to_string());
+        }
+    }
+    if find_membership(ctx, sender, &message.groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if ctx.db.reaction().message_user_emoji().filter((message_id, sender, &emoji)).next().is_some() {
+        return Err("User already reacted with this emoji".to_string());
+    }
+    if let Some(reaction_count) = ctx.db.reaction_count().message_and_emoji().filter((message_id, &emoji)).next() {
+        ctx.db.reaction_count().id().update(ReactionCount {
+            count: reaction_count.count + 1,
+            ..reaction_count
+        });
+    } else {
+        ctx.db.reaction_count().insert(ReactionCount {
+            id: 0,
+            message_id,
+            emoji: emoji.clone(),
+            count: 1
+        });
+    }
+    ctx.db.reaction().insert(Reaction {
+        id: 0,
+        message_id,
+        identity: sender,
+        emoji
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_reaction(ctx: &ReducerContext, message_id: u64, emoji: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if find_membership(ctx, sender, &message.groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    let reaction = ctx.db.reaction().message_user_emoji().filter((message_id, sender, &emoji)).next()
+        .ok_or("User has not reacted with this emoji")?;
+    ctx.db.reaction().id().delete(reaction.id);
+    if let Some(reaction_count) = ctx.db.reaction_count().message_and_emoji().filter((message_id, &emoji)).next() {
+        if reaction_count.count <= 1 {
+            ctx.db.reaction_count().id().delete(reaction_count.id);
+        } else {
+            ctx.db.reaction_count().id().update(ReactionCount {
+                count: reaction_count.count - 1,
+                ..reaction_count
+            });
+        }
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn report_message(ctx: &ReducerContext, message_id: u64, reason: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let reason = validate_optional_text(Some(reason), MAX_REPORT_REASON_LEN, "Report reason")?
+        .ok_or("Report reason must not be empty")?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if find_membership(ctx, sender, &message.groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if message.sender == sender {
+        return Err("Cannot report your own message".to_string());
+    }
+    if message.deleted {
+        return Err("Cannot report a deleted message".to_string());
+    }
+    if ctx.db.report().message_and_reporter().filter((message_id, sender)).any(|report| report.status == ReportStatus::Open) {
+        return Err("You already reported this message".to_string());
+    }
+    ctx.db.report().insert(Report {
+        id: 0,
+        message_id,
+        groupchat_id: message.groupchat_id,
+        reporter: sender,
+        reason,
+        status: ReportStatus::Open,
+        created_at: ctx.timestamp,
+        handled_by: None
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn resolve_report(ctx: &ReducerContext, report_id: u64) -> Result<(), String> {
+    close_report(ctx, report_id, ReportStatus::Resolved)
+}
+
+#[spacetimedb::reducer]
+pub fn dismiss_report(ctx: &ReducerContext, report_id: u64) -> Result<(), String> {
+    close_report(ctx, report_id, ReportStatus::Dismissed)
+}
+
+fn close_report(ctx: &ReducerContext, report_id: u64, status: ReportStatus) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let report = ctx.db.report().id().find(report_id).ok_or("Report does not exist")?;
+    require_role(ctx, &report.groupchat_id, MembershipRole::Admin)?;
+    if report.status != ReportStatus::Open {
+        return Err("Report has already been handled".to_string());
+    }
+    let action = if status == ReportStatus::Resolved { AuditAction::ResolveReport } else { AuditAction::DismissReport };
+    audit(ctx, &report.groupchat_id, action, Some(report.reporter), Some(report.message_id.to_string()));
+    ctx.db.report().id().update(Report {
+        status,
+        handled_by: Some(sender),
+        ..report
+    });
+    Ok(())
+}
+
+fn validate_shortcode(shortcode: String) -> Result<String, String> {
+    let shortcode = shortcode.trim().trim_matches(':').to_lowercase();
+    if shortcode.len() < MIN_SHORTCODE_LEN || shortcode.len() > MAX_SHORTCODE_LEN {
+        Err(format!("Shortcodes must be between {MIN_SHORTCODE_LEN} and {MAX_SHORTCODE_LEN} characters"))
+    } else if !shortcode.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Err("Shortcodes may only contain letters, digits and underscores".to_string())
+    } else {
+        Ok(shortcode)
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn add_custom_emoji(ctx: &ReducerContext, groupchat_id: String, shortcode: String, image_url: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let shortcode = validate_shortcode(shortcode)?;
+    let image_url = validate_url(image_url)?;
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    if ctx.db.custom_emoji().groupchat_and_shortcode().filter((&groupchat_id, &shortcode)).next().is_some() {
+        return Err(format!("The shortcode :{shortcode}: is already taken in this group chat"));
+    }
+    if ctx.db.custom_emoji().groupchat_id().filter(&groupchat_id).count() >= MAX_CUSTOM_EMOJIS_PER_GROUP {
+        return Err(format!("A group chat can have at most {MAX_CUSTOM_EMOJIS_PER_GROUP} custom emojis"));
+    }
+    audit(ctx, &groupchat_id, AuditAction::AddCustomEmoji, None, Some(shortcode.clone()));
+    ctx.db.custom_emoji().insert(CustomEmoji {
+        id: 0,
+        groupchat_id,
+        shortcode,
+        image_url,
+        uploaded_by: sender
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_custom_emoji(ctx: &ReducerContext, custom_emoji_id: u64) -> Result<(), String> {
+    let custom_emoji = ctx.db.custom_emoji().id().find(custom_emoji_id).ok_or("Custom emoji does not exist")?;
+    require_role(ctx, &custom_emoji.groupchat_id, MembershipRole::Admin)?;
+    audit(ctx, &custom_emoji.groupchat_id, AuditAction::RemoveCustomEmoji, None, Some(custom_emoji.shortcode.clone()));
+    ctx.db.custom_emoji().id().delete(custom_emoji_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn create_badge(ctx: &ReducerContext, groupchat_id: String, name: String, description: Option<String>, icon_url: Option<String>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let name = validate_optional_text(Some(name), MAX_BADGE_NAME_LEN, "Badge name")?.ok_or("Badge name must not be empty")?;
+    let description = validate_optional_text(description, MAX_DESCRIPTION_LEN, "Badge description")?;
+    let icon_url = icon_url.map(validate_url).transpose()?;
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    if ctx.db.badge().iter().filter(|badge| badge.groupchat_id.as_ref() == Some(&groupchat_id)).count() >= MAX_BADGES_PER_GROUP {
+        return Err(format!("A group chat can have at most {MAX_BADGES_PER_GROUP} badges"));
+    }
+    audit(ctx, &groupchat_id, AuditAction::CreateBadge, None, Some(name.clone()));
+    ctx.db.badge().insert(Badge {
+        id: 0,
+        groupchat_id: Some(groupchat_id),
+        builtin: None,
+        name,
+        description,
+        icon_url,
+        created_by: sender
+    });
+    Ok(())
+}
+
+// only custom badges can be awarded by hand, built-in ones are granted automatically
+#[spacetimedb::reducer]
+pub fn award_badge(ctx: &ReducerContext, badge_id: u64, target: Identity) -> Result<(), String> {
+    let badge = ctx.db.badge().id().find(badge_id).ok_or("Badge does not exist")?;
+    let groupchat_id = badge.groupchat_id.ok_or("Built-in badges cannot be awarded manually")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    if find_membership(ctx, target, &groupchat_id).is_none() {
+        return Err("Target is not a member of this group chat".to_string());
+    }
+    if ctx.db.awarded_badge().badge_and_user().filter((badge_id, target)).next().is_some() {
+        return Err("User already has this badge".to_string());
+    }
+    audit(ctx, &groupchat_id, AuditAction::AwardBadge, Some(target), Some(badge.name));
+    insert_awarded_badge(ctx, badge_id, target);
+    Ok(())
+}
+
+fn award_builtin_badge(ctx: &ReducerContext, identity: Identity, builtin: BuiltinBadge) {
+    let badge = match ctx.db.badge().iter().find(|badge| badge.builtin == Some(builtin)) {
+        Some(badge) => badge,
+        None => ctx.db.badge().insert(Badge {
+            id: 0,
+            groupchat_id: None,
+            builtin: Some(builtin),
+            name: builtin_badge_name(builtin).to_string(),
+            description: None,
+            icon_url: None,
+            created_by: ctx.identity()
+        }),
+    };
+    if ctx.db.awarded_badge().badge_and_user().filter((badge.id, identity)).next().is_none() {
+        insert_awarded_badge(ctx, badge.id, identity);
+    }
+}
+
+fn builtin_badge_name(builtin: BuiltinBadge) -> &'static str {
+    match builtin {
+        BuiltinBadge::FirstMessage => "First Message",
+        BuiltinBadge::HundredMessages => "100 Messages",
+        BuiltinBadge::Founder => "Founder",
+    }
+}
+
+fn insert_awarded_badge(ctx: &ReducerContext, badge_id: u64, identity: Identity) {
+    ctx.db.awarded_badge().insert(AwardedBadge {
+        id: 0,
+        badge_id,
+        identity,
+        awarded_by: account_of(ctx, ctx.sender),
+        awarded_at: ctx.timestamp
+    });
+}
+
+fn delete_badge(ctx: &ReducerContext, badge_id: u64) {
+    for awarded in ctx.db.awarded_badge().badge_id().filter(badge_id) {
+        ctx.db.awarded_badge().id().delete(awarded.id);
+    }
+    ctx.db.badge().id().delete(badge_id);
+}
+
+#[spacetimedb::reducer]
+pub fn pin_message(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    has_permission(ctx, &message.groupchat_id, GroupAction::Pin)?;
+    if message.deleted {
+        return Err("Cannot pin a deleted message".to_string());
+    }
+    if ctx.db.pinned_message().message_id().find(message_id).is_some() {
+        return Err("Message is already pinned".to_string());
+    }
+    let max_pins_per_group = config(ctx).max_pins_per_group;
+    if ctx.db.pinned_message().groupchat_id().filter(&message.groupchat_id).count() >= max_pins_per_group as usize {
+        return Err(format!("A group chat can have at most {max_pins_per_group} pinned messages"));
+    }
+    audit(ctx, &message.groupchat_id, AuditAction::PinMessage, Some(message.sender), Some(message_id.to_string()));
+    ctx.db.pinned_message().insert(PinnedMessage {
+        id: 0,
+        groupchat_id: message.groupchat_id,
+        message_id,
+        pinned_by: sender,
+        pinned_at: ctx.timestamp
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unpin_message(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+    let pin = ctx.db.pinned_message().message_id().find(message_id).ok_or("Message is not pinned")?;
+    has_permission(ctx, &pin.groupchat_id, GroupAction::Pin)?;
+    audit(ctx, &pin.groupchat_id, AuditAction::UnpinMessage, None, Some(message_id.to_string()));
+    ctx.db.pinned_message().id().delete(pin.id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn start_typing(ctx: &ReducerContext, groupchat_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if find_membership(ctx, sender, &groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if let Some(indicator) = ctx.db.typing_indicator().groupchat_and_user().filter((&groupchat_id, sender)).next() {
+        ctx.db.typing_indicator().id().update(TypingIndicator {
+            started_at: ctx.timestamp,
+            ..indicator
+        });
+    } else {
+        ctx.db.typing_indicator().insert(TypingIndicator {
+            id: 0,
+            groupchat_id,
+            identity: sender,
+            started_at: ctx.timestamp
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn purge_typing_indicators(ctx: &ReducerContext, _cleanup: TypingIndicatorCleanup) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `purge_typing_indicators` may only be invoked by the scheduler".to_string());
+    }
+    let cutoff = seconds_ago(ctx, TYPING_INDICATOR_TTL_SECS);
+    for indicator in ctx.db.typing_indicator().iter().filter(|indicator| indicator.started_at < cutoff) {
+        ctx.db.typing_indicator().id().delete(indicator.id);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn mark_read(ctx: &ReducerContext, groupchat_id: String, up_to: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if find_membership(ctx, sender, &groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    let message = ctx.db.message().id().find(up_to).ok_or("Message does not exist")?;
+    if message.groupchat_id != groupchat_id {
+        return Err("Message does not belong to this group chat".to_string());
+    }
+    let read_state = read_state_for(ctx, sender, &groupchat_id);
+    // read state only ever moves forward
+    if read_state.last_read_message_id >= up_to {
+        return Ok(());
+    }
+    if receipts_enabled(ctx, &groupchat_id) {
+        let newly_read: Vec<u64> = ctx.db.message().groupchat_id().filter(&groupchat_id)
+            .filter(|message| message.id > read_state.last_read_message_id && message.id <= up_to && message.sender != sender)
+            .map(|message| message.id)
+            .collect();
+        for message_id in newly_read {
+            set_receipt(ctx, sender, message_id, ReceiptState::Read);
+        }
+    }
+    let unread_count = ctx.db.message().groupchat_id().filter(&groupchat_id)
+        .filter(|message| message.id > up_to && message.sender != sender)
+        .count() as u32;
+    ctx.db.read_state().id().update(ReadState {
+        last_read_message_id: up_to,
+        last_read_at: ctx.timestamp,
+        unread_count,
+        ..read_state
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn ack_delivery(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if find_membership(ctx, sender, &message.groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if message.sender == sender || !receipts_enabled(ctx, &message.groupchat_id) {
+        return Ok(());
+    }
+    set_receipt(ctx, sender, message_id, ReceiptState::Delivered);
+    Ok(())
+}
+
+fn receipts_enabled(ctx: &ReducerContext, groupchat_id: &str) -> bool {
+    ctx.db.groupchat().id().find(groupchat_id.to_string())
+        .is_some_and(|groupchat| groupchat.member_count <= MAX_RECEIPT_GROUP_SIZE)
+}
+
+fn set_receipt(ctx: &ReducerContext, identity: Identity, message_id: u64, state: ReceiptState) {
+    match ctx.db.message_receipt().message_and_user().filter((message_id, identity)).next() {
+        Some(receipt) if receipt.state >= state => {}
+        Some(receipt) => {
+            ctx.db.message_receipt().id().update(MessageReceipt {
+                state,
+                updated_at: ctx.timestamp,
+                ..receipt
+            });
+        }
+        None => {
+            ctx.db.message_receipt().insert(MessageReceipt {
+                id: 0,
+                message_id,
+                identity,
+                state,
+                updated_at: ctx.timestamp
+            });
+        }
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn create_folder(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let name = validate_optional_text(Some(name), MAX_FOLDER_NAME_LEN, "Folder name")?.ok_or("Folder name must not be empty")?;
+    let folder_count = ctx.db.chat_folder().identity().filter(sender).count();
+    if folder_count >= MAX_FOLDERS_PER_USER {
+        return Err(format!("You can have at most {MAX_FOLDERS_PER_USER} folders"));
+    }
+    // new folders are appended at the end
+    let sort_order = ctx.db.chat_folder().identity().filter(sender)
+        .map(|folder| folder.sort_order + 1)
+        .max()
+        .unwrap_or(0);
+    ctx.db.chat_folder().insert(ChatFolder {
+        id: 0,
+        identity: sender,
+        name,
+        sort_order
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn delete_folder(ctx: &ReducerContext, folder_id: u64) -> Result<(), String> {
+    let folder = find_own_folder(ctx, folder_id)?;
+    for entry in ctx.db.folder_membership().folder_id().filter(folder.id) {
+        ctx.db.folder_membership().id().delete(entry.id);
+    }
+    ctx.db.chat_folder().id().delete(folder.id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn add_chat_to_folder(ctx: &ReducerContext, folder_id: u64, groupchat_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let folder = find_own_folder(ctx, folder_id)?;
+    if find_membership(ctx, sender, &groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if ctx.db.folder_membership().folder_and_groupchat().filter((folder.id, &groupchat_id)).next().is_some() {
+        return Err("Group chat is already in this folder".to_string());
+    }
+    ctx.db.folder_membership().insert(FolderMembership {
+        id: 0,
+        folder_id: folder.id,
+        identity: sender,
+        groupchat_id
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_chat_from_folder(ctx: &ReducerContext, folder_id: u64, groupchat_id: String) -> Result<(), String> {
+    let folder = find_own_folder(ctx, folder_id)?;
+    let entry = ctx.db.folder_membership().folder_and_groupchat().filter((folder.id, &groupchat_id)).next()
+        .ok_or("Group chat is not in this folder")?;
+    ctx.db.folder_membership().id().delete(entry.id);
+    Ok(())
+}
+
+// `folder_ids` must list all of the caller's folders in their new order
+#[spacetimedb::reducer]
+pub fn reorder_folders(ctx: &ReducerContext, folder_ids: Vec<u64>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let mut own_ids: Vec<u64> = ctx.db.chat_folder().identity().filter(sender).map(|folder| folder.id).collect();
+    let mut requested_ids = folder_ids.clone();
+    own_ids.sort_unstable();
+    requested_ids.sort_unstable();
+    if own_ids != requested_ids {
+        return Err("Folder order must contain each of your folders exactly once".to_string());
+    }
+    for (sort_order, folder_id) in folder_ids.into_iter().enumerate() {
+        if let Some(folder) = ctx.db.chat_folder().id().find(folder_id) {
+            ctx.db.chat_folder().id().update(ChatFolder {
+                sort_order: sort_order as u32,
+                ..folder
+            });
+        }
+    }
+    Ok(())
+}
+
+fn find_own_folder(ctx: &ReducerContext, folder_id: u64) -> Result<ChatFolder, String> {
+    let sender = acting_identity(ctx)?;
+    let folder = ctx.db.chat_folder().id().find(folder_id).ok_or("Folder does not exist")?;
+    if folder.identity != sender {
+        return Err("Folder does not exist".to_string());
+    }
+    Ok(folder)
+}
+
+#[spacetimedb::reducer]
+pub fn save_draft(ctx: &ReducerContext, groupchat_id: String, text: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    // saving an empty draft is the same as clearing it
+    if text.trim().is_empty() {
+        return clear_draft(ctx, groupchat_id);
+    }
+    let max_message_len = config(ctx).max_message_len;
+    if text.chars().count() > max_message_len as usize {
+        return Err(format!("Drafts must be at most {max_message_len} characters"));
+    }
+    if find_membership(ctx, sender, &groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if let Some(draft) = ctx.db.draft().user_and_groupchat().filter((sender, &groupchat_id)).next() {
+        ctx.db.draft().id().update(Draft {
+            text,
+            updated_at: ctx.timestamp,
+            ..draft
+        });
+    } else {
+        ctx.db.draft().insert(Draft {
+            id: 0,
+            identity: sender,
+            groupchat_id,
+            text,
+            updated_at: ctx.timestamp
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn clear_draft(ctx: &ReducerContext, groupchat_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    for draft in ctx.db.draft().user_and_groupchat().filter((sender, &groupchat_id)) {
+        ctx.db.draft().id().delete(draft.id);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn bookmark_message(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if find_membership(ctx, sender, &message.groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if message.deleted {
+        return Err("Cannot bookmark a deleted message".to_string());
+    }
+    if ctx.db.bookmark().user_and_message().filter((sender, message_id)).next().is_some() {
+        return Err("Message is already bookmarked".to_string());
+    }
+    ctx.db.bookmark().insert(Bookmark {
+        id: 0,
+        identity: sender,
+        message_id,
+        created_at: ctx.timestamp
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_bookmark(ctx: &ReducerContext, message_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let bookmark = ctx.db.bookmark().user_and_message().filter((sender, message_id)).next()
+        .ok_or("Message is not bookmarked")?;
+    ctx.db.bookmark().id().delete(bookmark.id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn ack_mention(ctx: &ReducerContext, mention_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let mention = ctx.db.mention().id().find(mention_id).ok_or("Mention does not exist")?;
+    if mention.mentioned_identity != sender {
+        return Err("Cannot acknowledge a mention of another user".to_string());
+    }
+    ctx.db.mention().id().update(Mention {
+        seen: true,
+        ..mention
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn mark_notification_read(ctx: &ReducerContext, notification_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let notification = ctx.db.notification().id().find(notification_id).ok_or("Notification does not exist")?;
+    if notification.recipient != sender {
+        return Err("Cannot mark a notification of another user as read".to_string());
+    }
+    ctx.db.notification().id().update(Notification {
+        read: true,
+        ..notification
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn clear_notifications(ctx: &ReducerContext) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    for notification in ctx.db.notification().recipient().filter(sender) {
+        ctx.db.notification().id().delete(notification.id);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remind_me(ctx: &ReducerContext, groupchat_id: String, message_id: u64, after_secs: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if after_secs == 0 || after_secs > MAX_REMINDER_SECS {
+        return Err(format!("Reminders must fire between 1 and {MAX_REMINDER_SECS} seconds from now"));
+    }
+    if find_membership(ctx, sender, &groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    let message = ctx.db.message().id().find(message_id).ok_or("Message does not exist")?;
+    if message.groupchat_id != groupchat_id {
+        return Err("Message does not belong to this group chat".to_string());
+    }
+    if ctx.db.reminder().identity().filter(sender).count() >= MAX_REMINDERS_PER_USER {
+        return Err(format!("You can have at most {MAX_REMINDERS_PER_USER} pending reminders"));
+    }
+    ctx.db.reminder().insert(Reminder {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(seconds_from_now(ctx, after_secs)),
+        identity: sender,
+        groupchat_id,
+        message_id
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn fire_reminder(ctx: &ReducerContext, reminder: Reminder) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `fire_reminder` may only be invoked by the scheduler".to_string());
+    }
+    // the reminder was set by the recipient, so they are also the actor of the notification
+    ctx.db.notification().insert(Notification {
+        id: 0,
+        recipient: reminder.identity,
+        kind: NotificationKind::Reminder,
+        actor: reminder.identity,
+        groupchat_id: Some(reminder.groupchat_id),
+        message_id: Some(reminder.message_id),
+        created_at: ctx.timestamp,
+        read: false
+    });
+    Ok(())
+}
+
+fn notify(ctx: &ReducerContext, recipient: Identity, kind: NotificationKind, groupchat_id: Option<String>, message_id: Option<u64>) {
+    ctx.db.notification().insert(Notification {
+        id: 0,
+        recipient,
+        kind,
+        actor: account_of(ctx, ctx.sender),
+        groupchat_id,
+        message_id,
+        created_at: ctx.timestamp,
+        read: false
+    });
+}
+
+#[spacetimedb::reducer]
+pub fn set_group_nickname(ctx: &ReducerContext, groupchat_id: String, nickname: Option<String>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let nickname = validate_optional_text(nickname, MAX_NICKNAME_LEN, "Nickname")?;
+    let membership = find_membership(ctx, sender, &groupchat_id).ok_or("User is not a member of this group chat")?;
+    ctx.db.groupchat_membership().id().update(GroupChatMembership {
+        nickname,
+        ..membership
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_chat_pin(ctx: &ReducerContext, groupchat_id: String, pinned: bool) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let membership = find_membership(ctx, sender, &groupchat_id).ok_or("User is not a member of this group chat")?;
+    ctx.db.groupchat_membership().id().update(GroupChatMembership {
+        pinned,
+        ..membership
+    });
+    Ok(())
+}
+
+// `groupchat_ids` must list all of the caller's group chats in their new order
+#[spacetimedb::reducer]
+pub fn reorder_chats(ctx: &ReducerContext, groupchat_ids: Vec<String>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let mut own_ids: Vec<String> = ctx.db.groupchat_membership().identity().filter(sender)
+        .map(|membership| membership.groupchat_id)
+        .collect();
+    let mut requested_ids = groupchat_ids.clone();
+    own_ids.sort_unstable();
+    requested_ids.sort_unstable();
+    if own_ids != requested_ids {
+        return Err("Chat order must contain each of your group chats exactly once".to_string());
+    }
+    for (sort_order, groupchat_id) in groupchat_ids.into_iter().enumerate() {
+        if let Some(membership) = find_membership(ctx, sender, &groupchat_id) {
+            ctx.db.groupchat_membership().id().update(GroupChatMembership {
+                sort_order: sort_order as u32,
+                ..membership
+            });
+        }
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_notification_pref(ctx: &ReducerContext, groupchat_id: String, mode: NotificationMode, mute_for_secs: Option<u64>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if find_membership(ctx, sender, &groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    let muted_until = mute_for_secs.map(|secs| seconds_from_now(ctx, secs));
+    if let Some(pref) = ctx.db.notification_pref().user_and_groupchat().filter((sender, &groupchat_id)).next() {
+        ctx.db.notification_pref().id().update(NotificationPref {
+            mode,
+            muted_until,
+            ..pref
+        });
+    } else {
+        ctx.db.notification_pref().insert(NotificationPref {
+            id: 0,
+            identity: sender,
+            groupchat_id,
+            mode,
+            muted_until
+        });
+    }
+    Ok(())
+}
+
+// the notification mode currently in effect for a member, taking temporary mutes into account
+fn notification_mode(ctx: &ReducerContext, identity: Identity, groupchat_id: &str) -> NotificationMode {
+    match ctx.db.notification_pref().user_and_groupchat().filter((identity, groupchat_id)).next() {
+        Some(pref) if pref.muted_until.is_some_and(|muted_until| muted_until > ctx.timestamp) => NotificationMode::None,
+        Some(pref) => pref.mode,
+        None => NotificationMode::All,
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn create_poll(ctx: &ReducerContext, groupchat_id: String, question: String, options: Vec<String>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let question = validate_message(ctx, Some(&groupchat_id), question.trim().to_string())?;
+    if options.len() < 2 || options.len() > MAX_POLL_OPTIONS {
+        return Err(format!("Polls must have between 2 and {MAX_POLL_OPTIONS} options"));
+    }
+    let options = options
+        .into_iter()
+        .map(|option| validate_message(ctx, Some(&groupchat_id), option.trim().to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    check_can_post(ctx, &groupchat_id)?;
+    let poll = ctx.db.poll().insert(Poll {
+        id: 0,
+        groupchat_id: groupchat_id.clone(),
+        created_by: sender,
+        question,
+        options,
+        created_at: ctx.timestamp,
+        closed: false
+    });
+    let text = format!("{} created a poll: {}", display_name(ctx, sender), poll.question);
+    post_message(ctx, Message {
+        poll_id: Some(poll.id),
+        kind: MessageKind::System,
+        ..new_message(ctx, ctx.identity(), groupchat_id, text)
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn vote(ctx: &ReducerContext, poll_id: u64, option_index: u32) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let poll = ctx.db.poll().id().find(poll_id).ok_or("Poll does not exist")?;
+    if find_membership(ctx, sender, &poll.groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if poll.closed {
+        return Err("Poll is closed".to_string());
+    }
+    if option_index as usize >= poll.options.len() {
+        return Err("Poll option does not exist".to_string());
+    }
+    if ctx.db.poll_vote().poll_and_user().filter((poll_id, sender)).next().is_some() {
+        return Err("User has already voted in this poll".to_string());
+    }
+    ctx.db.poll_vote().insert(PollVote {
+        id: 0,
+        poll_id,
+        identity: sender,
+        option_index
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn close_poll(ctx: &ReducerContext, poll_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let poll = ctx.db.poll().id().find(poll_id).ok_or("Poll does not exist")?;
+    // the creator of a poll can always close it, otherwise admins of the group can
+    if poll.created_by != sender {
+        require_role(ctx, &poll.groupchat_id, MembershipRole::Admin)?;
+    }
+    if poll.closed {
+        return Err("Poll is already closed".to_string());
+    }
+    ctx.db.poll().id().update(Poll {
+        closed: true,
+        ..poll
+    });
+    Ok(())
+}
+
+fn delete_poll(ctx: &ReducerContext, poll_id: u64) {
+    for vote in ctx.db.poll_vote().poll_id().filter(poll_id) {
+        ctx.db.poll_vote().id().delete(vote.id);
+    }
+    ctx.db.poll().id().delete(poll_id);
+}
+
+#[spacetimedb::reducer]
+pub fn create_event(ctx: &ReducerContext, groupchat_id: String, title: String, starts_at: Timestamp) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let title = validate_optional_text(Some(title), MAX_EVENT_TITLE_LEN, "Event title")?
+        .ok_or("Event title must not be empty")?;
+    if starts_at <= ctx.timestamp {
+        return Err("Events must start in the future".to_string());
+    }
+    check_can_post(ctx, &groupchat_id)?;
+    let event = ctx.db.group_event().insert(GroupEvent {
+        id: 0,
+        groupchat_id: groupchat_id.clone(),
+        title,
+        starts_at,
+        created_by: sender
+    });
+    // events starting within the lead time are reminded about right away
+    let reminder_at = seconds_before(starts_at, EVENT_REMINDER_LEAD_SECS).max(ctx.timestamp);
+    ctx.db.event_reminder().insert(EventReminder {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(reminder_at),
+        event_id: event.id
+    });
+    let text = format!("{} scheduled an event: {}", display_name(ctx, sender), event.title);
+    post_system_message(ctx, groupchat_id, text);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn cancel_event(ctx: &ReducerContext, event_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let event = ctx.db.group_event().id().find(event_id).ok_or("Event does not exist")?;
+    // the creator of an event can always cancel it, otherwise admins of the group can
+    if event.created_by != sender {
+        require_role(ctx, &event.groupchat_id, MembershipRole::Admin)?;
+    }
+    delete_event(ctx, event_id);
+    let text = format!("{} cancelled the event {}", display_name(ctx, sender), event.title);
+    post_system_message(ctx, event.groupchat_id, text);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn rsvp(ctx: &ReducerContext, event_id: u64, status: RsvpStatus) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let event = ctx.db.group_event().id().find(event_id).ok_or("Event does not exist")?;
+    if find_membership(ctx, sender, &event.groupchat_id).is_none() {
+        return Err("User is not a member of this group chat".to_string());
+    }
+    if let Some(rsvp) = ctx.db.event_rsvp().event_and_user().filter((event_id, sender)).next() {
+        ctx.db.event_rsvp().id().update(EventRsvp {
+            status,
+            ..rsvp
+        });
+    } else {
+        ctx.db.event_rsvp().insert(EventRsvp {
+            id: 0,
+            event_id,
+            identity: sender,
+            status
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn send_event_reminder(ctx: &ReducerContext, reminder: EventReminder) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `send_event_reminder` may only be invoked by the scheduler".to_string());
+    }
+    let Some(event) = ctx.db.group_event().id().find(reminder.event_id) else {
+        return Ok(());
+    };
+    let going = ctx.db.event_rsvp().event_id().filter(event.id).filter(|rsvp| rsvp.status == RsvpStatus::Going).count();
+    let text = format!("Reminder: {} starts at {} ({going} going)", event.title, event.starts_at);
+    post_system_message(ctx, event.groupchat_id, text);
+    Ok(())
+}
+
+fn delete_event(ctx: &ReducerContext, event_id: u64) {
+    for rsvp in ctx.db.event_rsvp().event_id().filter(event_id) {
+        ctx.db.event_rsvp().id().delete(rsvp.id);
+    }
+    for reminder in ctx.db.event_reminder().event_id().filter(event_id) {
+        ctx.db.event_reminder().scheduled_id().delete(reminder.scheduled_id);
+    }
+    ctx.db.group_event().id().delete(event_id);
+}
+
+#[spacetimedb::reducer]
+pub fn add_webhook(ctx: &ReducerContext, groupchat_id: String, url: String, secret: String, event_mask: u32) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let url = validate_url(url)?;
+    if !url.starts_with("https://") {
+        return Err("Webhooks must use https".to_string());
+    }
+    if secret.len() < MIN_WEBHOOK_SECRET_LEN {
+        return Err(format!("Webhook secrets must be at least {MIN_WEBHOOK_SECRET_LEN} characters"));
+    }
+    if event_mask == 0 || event_mask & !WEBHOOK_EVENT_ALL != 0 {
+        return Err("Webhook event mask is invalid".to_string());
+    }
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    if ctx.db.webhook().groupchat_id().filter(&groupchat_id).count() >= MAX_WEBHOOKS_PER_GROUP {
+        return Err(format!("A group chat can have at most {MAX_WEBHOOKS_PER_GROUP} webhooks"));
+    }
+    audit(ctx, &groupchat_id, AuditAction::AddWebhook, None, Some(url.clone()));
+    ctx.db.webhook().insert(Webhook {
+        id: 0,
+        groupchat_id,
+        url,
+        secret,
+        event_mask,
+        created_by: sender,
+        created_at: ctx.timestamp
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_webhook(ctx: &ReducerContext, webhook_id: u64) -> Result<(), String> {
+    let webhook = ctx.db.webhook().id().find(webhook_id).ok_or("Webhook does not exist")?;
+    require_role(ctx, &webhook.groupchat_id, MembershipRole::Admin)?;
+    audit(ctx, &webhook.groupchat_id, AuditAction::RemoveWebhook, None, Some(webhook.url.clone()));
+    delete_webhook(ctx, webhook_id);
+    Ok(())
+}
+
+fn delete_webhook(ctx: &ReducerContext, webhook_id: u64) {
+    for delivery in ctx.db.webhook_outbox().webhook_id().filter(webhook_id) {
+        ctx.db.webhook_outbox().id().delete(delivery.id);
+    }
+    ctx.db.webhook().id().delete(webhook_id);
+}
+
+fn enqueue_webhooks(ctx: &ReducerContext, groupchat_id: &str, event: u32, message_id: u64) {
+    for webhook in ctx.db.webhook().groupchat_id().filter(groupchat_id) {
+        if webhook.event_mask & event == 0 {
+            continue;
+        }
+        ctx.db.webhook_outbox().insert(WebhookOutbox {
+            id: 0,
+            webhook_id: webhook.id,
+            event,
+            groupchat_id: groupchat_id.to_string(),
+            message_id,
+            created_at: ctx.timestamp
+        });
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn request_data_export(ctx: &ReducerContext) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let user = ctx.db.user().identity().find(sender).ok_or("Cannot export data for unknown user")?;
+    let mut records = vec![format!(
+        "{{\"type\":\"user\",\"identity\":{},\"name\":{},\"bio\":{},\"avatar_url\":{},\"status\":{},\"last_seen\":{}}}",
+        json_string(&user.identity.to_hex().to_string()),
+        json_optional_string(user.name.as_deref()),
+        json_optional_string(user.bio.as_deref()),
+        json_optional_string(user.avatar_url.as_deref()),
+        json_optional_string(user.status.as_deref()),
+        user.last_seen.to_micros_since_unix_epoch()
+    )];
+    for membership in ctx.db.groupchat_membership().identity().filter(sender) {
+        records.push(format!(
+            "{{\"type\":\"membership\",\"groupchat_id\":{},\"role\":{},\"nickname\":{}}}",
+            json_string(&membership.groupchat_id),
+            json_string(&format!("{:?}", membership.role)),
+            json_optional_string(membership.nickname.as_deref())
+        ));
+    }
+    for message in ctx.db.message().iter().filter(|message| message.sender == sender && !message.deleted) {
+        records.push(format!(
+            "{{\"type\":\"message\",\"id\":{},\"groupchat_id\":{},\"sent\":{},\"text\":{}}}",
+            message.id,
+            json_string(&message.groupchat_id),
+            message.sent.to_micros_since_unix_epoch(),
+            json_string(&message.text)
+        ));
+    }
+    for message in ctx.db.dm_message().iter().filter(|message| message.sender == sender) {
+        records.push(format!(
+            "{{\"type\":\"dm_message\",\"id\":{},\"conversation_id\":{},\"sent\":{},\"text\":{}}}",
+            message.id,
+            message.conversation_id,
+            message.sent.to_micros_since_unix_epoch(),
+            json_string(&message.text)
+        ));
+    }
+    for reaction in ctx.db.reaction().iter().filter(|reaction| reaction.identity == sender) {
+        records.push(format!(
+            "{{\"type\":\"reaction\",\"message_id\":{},\"emoji\":{}}}",
+            reaction.message_id,
+            json_string(&reaction.emoji)
+        ));
+    }
+    // every export replaces the caller's previous one
+    for chunk in ctx.db.data_export().identity().filter(sender) {
+        ctx.db.data_export().id().delete(chunk.id);
+    }
+    // records are never split across chunks
+    let mut chunks: Vec<String> = Vec::new();
+    for record in records {
+        match chunks.last_mut() {
+            Some(chunk) if chunk.len() + record.len() < MAX_EXPORT_CHUNK_LEN => chunk.push_str(&record),
+            _ => chunks.push(record),
+        }
+        chunks.last_mut().expect("a chunk was just written").push('\n');
+    }
+    for (chunk_index, data) in chunks.into_iter().enumerate() {
+        ctx.db.data_export().insert(DataExport {
+            id: 0,
+            identity: sender,
+            chunk_index: chunk_index as u32,
+            data,
+            created_at: ctx.timestamp
+        });
+    }
+    log::info!("User {} exported their data", sender);
+    Ok(())
+}
+
+// removes everything personal about the caller, their group messages stay behind attributed to a tombstone user
+#[spacetimedb::reducer]
+pub fn delete_account(ctx: &ReducerContext) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if ctx.db.user().identity().find(sender).is_none() {
+        return Err("User does not exist".to_string());
+    }
+    let memberships: Vec<GroupChatMembership> = ctx.db.groupchat_membership().identity().filter(sender).collect();
+    if memberships.iter().any(|membership| membership.role == MembershipRole::Owner) {
+        return Err("Transfer or delete the group chats you own before deleting your account".to_string());
+    }
+    // `remove_member` also takes the user out of the online counts
+    for membership in memberships {
+        remove_member(ctx, membership);
+    }
+    for reaction in ctx.db.reaction().iter().filter(|reaction| reaction.identity == sender) {
+        ctx.db.reaction().id().delete(reaction.id);
+        if let Some(reaction_count) = ctx.db.reaction_count().message_and_emoji().filter((reaction.message_id, &reaction.emoji)).next() {
+            if reaction_count.count <= 1 {
+                ctx.db.reaction_count().id().delete(reaction_count.id);
+            } else {
+                ctx.db.reaction_count().id().update(ReactionCount {
+                    count: reaction_count.count - 1,
+                    ..reaction_count
+                });
+            }
+        }
+    }
+    let tombstone = deleted_user(ctx);
+    for message in ctx.db.message().iter().filter(|message| message.sender == sender || message.original_sender == Some(sender)) {
+        let scrub = message.sender == sender && ctx.db.groupchat().id().find(&message.groupchat_id)
+            .is_some_and(|groupchat| groupchat.deleted_account_policy == DeletedAccountPolicy::Scrub);
+        if scrub {
+            for attachment in ctx.db.attachment().message_id().filter(message.id) {
+                ctx.db.attachment().id().delete(attachment.id);
+            }
+            // earlier versions of the text would survive in the edit history
+            for edit in ctx.db.message_edit().message_id().filter(message.id) {
+                ctx.db.message_edit().id().delete(edit.id);
+            }
+            clear_translations(ctx, message.id);
+        } else {
+            for edit in ctx.db.message_edit().message_id().filter(message.id).filter(|edit| edit.editor == sender) {
+                ctx.db.message_edit().id().update(MessageEdit {
+                    editor: tombstone,
+                    ..edit
+                });
+            }
+        }
+        ctx.db.message().id().update(Message {
+            sender: if message.sender == sender { tombstone } else { message.sender },
+            original_sender: message.original_sender.map(|original| if original == sender { tombstone } else { original }),
+            text: if scrub { String::new() } else { message.text },
+            ciphertext: if scrub { None } else { message.ciphertext },
+            nonce: if scrub { None } else { message.nonce },
+            deleted: message.deleted || scrub,
+            ..message
+        });
+    }
+    // audit entries of deleted messages keep the removed text in `details`
+    for entry in ctx.db.audit_log().iter().filter(|entry| entry.action == AuditAction::DeleteMessage && entry.target == Some(sender)) {
+        let scrub = ctx.db.groupchat().id().find(&entry.groupchat_id)
+            .is_some_and(|groupchat| groupchat.deleted_account_policy == DeletedAccountPolicy::Scrub);
+        ctx.db.audit_log().id().update(AuditLog {
+            target: Some(tombstone),
+            details: if scrub { None } else { entry.details },
+            ..entry
+        });
+    }
+    // direct conversations, notes to self included, go away entirely,
+    // a reconnect would otherwise find them again under the same identity
+    let conversations = ctx.db.direct_conversation().participant_a().filter(sender)
+        .chain(ctx.db.direct_conversation().participant_b().filter(sender).filter(|conversation| conversation.participant_a != sender));
+    for conversation in conversations {
+        for dm in ctx.db.dm_message().conversation_id().filter(conversation.id) {
+            ctx.db.dm_message().id().delete(dm.id);
+        }
+        ctx.db.direct_conversation().id().delete(conversation.id);
+    }
+    ctx.db.user_settings().identity().delete(sender);
+    ctx.db.presence_preference().identity().delete(sender);
+    ctx.db.challenge().identity().delete(sender);
+    ctx.db.challenge_answer().identity().delete(sender);
+    ctx.db.username().identity().delete(sender);
+    for linked in ctx.db.linked_device().account().filter(sender) {
+        ctx.db.linked_device().device().delete(linked.device);
+    }
+    for token in ctx.db.link_token().account().filter(sender) {
+        ctx.db.link_token().token().delete(&token.token);
+    }
+    for session in ctx.db.session().identity().filter(sender) {
+        ctx.db.session().connection_id().delete(session.connection_id);
+    }
+    ctx.db.karma().identity().delete(sender);
+    for stats in ctx.db.member_stats().identity().filter(sender) {
+        ctx.db.member_stats().id().delete(stats.id);
+    }
+    for streak in ctx.db.streak().identity().filter(sender) {
+        ctx.db.streak().id().delete(streak.id);
+    }
+    for awarded in ctx.db.awarded_badge().identity().filter(sender) {
+        ctx.db.awarded_badge().id().delete(awarded.id);
+    }
+    for bookmark in ctx.db.bookmark().identity().filter(sender) {
+        ctx.db.bookmark().id().delete(bookmark.id);
+    }
+    for notification in ctx.db.notification().recipient().filter(sender) {
+        ctx.db.notification().id().delete(notification.id);
+    }
+    for mention in ctx.db.mention().mentioned_identity().filter(sender) {
+        ctx.db.mention().id().delete(mention.id);
+    }
+    for reminder in ctx.db.reminder().identity().filter(sender) {
+        ctx.db.reminder().scheduled_id().delete(reminder.scheduled_id);
+    }
+    for folder in ctx.db.chat_folder().identity().filter(sender) {
+        ctx.db.chat_folder().id().delete(folder.id);
+    }
+    for result in ctx.db.search_result().identity().filter(sender) {
+        ctx.db.search_result().id().delete(result.id);
+    }
+    for result in ctx.db.user_search_result().identity().filter(sender) {
+        ctx.db.user_search_result().id().delete(result.id);
+    }
+    for export in ctx.db.data_export().identity().filter(sender) {
+        ctx.db.data_export().id().delete(export.id);
+    }
+    for block in ctx.db.block().blocker().filter(sender).chain(ctx.db.block().blocked().filter(sender)) {
+        ctx.db.block().id().delete(block.id);
+    }
+    for request in ctx.db.friend_request().sender().filter(sender).chain(ctx.db.friend_request().recipient().filter(sender)) {
+        ctx.db.friend_request().id().delete(request.id);
+    }
+    for friendship in ctx.db.friendship().identity_a().filter(sender).chain(ctx.db.friendship().identity_b().filter(sender)) {
+        ctx.db.friendship().id().delete(friendship.id);
+    }
+    for transaction in ctx.db.karma_transaction().giver().filter(sender).chain(ctx.db.karma_transaction().recipient().filter(sender)) {
+        ctx.db.karma_transaction().id().delete(transaction.id);
+    }
+    for report in ctx.db.report().iter().filter(|report| report.reporter == sender) {
+        ctx.db.report().id().delete(report.id);
+    }
+    for vote in ctx.db.poll_vote().iter().filter(|vote| vote.identity == sender) {
+        ctx.db.poll_vote().id().delete(vote.id);
+    }
+    for rsvp in ctx.db.event_rsvp().iter().filter(|rsvp| rsvp.identity == sender) {
+        ctx.db.event_rsvp().id().delete(rsvp.id);
+    }
+    for receipt in ctx.db.message_receipt().iter().filter(|receipt| receipt.identity == sender) {
+        ctx.db.message_receipt().id().delete(receipt.id);
+    }
+    for indicator in ctx.db.typing_indicator().iter().filter(|indicator| indicator.identity == sender) {
+        ctx.db.typing_indicator().id().delete(indicator.id);
+    }
+    for request in ctx.db.join_request().iter().filter(|request| request.identity == sender) {
+        ctx.db.join_request().id().delete(request.id);
+    }
+    for sent in ctx.db.sent_client_id().iter().filter(|sent| sent.sender == sender) {
+        ctx.db.sent_client_id().id().delete(sent.id);
+    }
+    ctx.db.rate_limit().identity().delete(sender);
+    ctx.db.group_creation_limit().identity().delete(sender);
+    ctx.db.join_code_attempt().identity().delete(sender);
+    ctx.db.user().identity().delete(sender);
+    log::info!("User {} deleted their account", sender);
+    Ok(())
+}
+
+// the user deleted accounts' messages are attributed to, created on first use
+fn deleted_user(ctx: &ReducerContext) -> Identity {
+    if ctx.db.user().identity().find(DELETED_USER_IDENTITY).is_none() {
+        ctx.db.user().insert(User {
+            identity: DELETED_USER_IDENTITY,
+            name: Some(DELETED_USER_NAME.to_string()),
+            presence: Presence::Offline,
+            last_seen: ctx.timestamp,
+            last_active: ctx.timestamp,
+            bio: None,
+            avatar_url: None,
+            status: None,
+            is_bot: false,
+            verified: false,
+            tos_accepted_version: 0,
+        });
+    }
+    DELETED_USER_IDENTITY
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_optional_string(value: Option<&str>) -> String {
+    value.map(json_string).unwrap_or_else(|| "null".to_string())
+}
+
+#[spacetimedb::reducer]
+pub fn leave_groupchat(ctx: &ReducerContext, groupchat_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let membership = ctx.db.groupchat_membership().user_and_groupchat().filter((sender, &groupchat_id)).next()
+        .ok_or("User is not a member of this group chat")?;
+    if membership.role == MembershipRole::Owner {
+        return Err("The owner cannot leave the group chat, transfer ownership or delete it instead".to_string());
+    }
+    remove_member(ctx, membership);
+    let text = format!("{} left the group chat", display_name(ctx, sender));
+    post_system_message(ctx, groupchat_id, text);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_group_chat_name(ctx: &ReducerContext, groupchat_id: String, name: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let name = validate_group_name(ctx, name)?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    has_permission(ctx, &groupchat_id, GroupAction::Rename)?;
+    audit(ctx, &groupchat_id, AuditAction::RenameGroupChat, None, Some(format!("{} -> {name}", groupchat.name)));
+    let text = format!("{} renamed the group chat to {name}", display_name(ctx, sender));
+    ctx.db.groupchat().id().update(GroupChat {
+        name,
+        ..groupchat
+    });
+    post_system_message(ctx, groupchat_id, text);
+    Ok(())
+}
+
+// trims optional free-text fields, treating blank values as unset
+fn validate_optional_text(value: Option<String>, max_len: usize, field: &str) -> Result<Option<String>, String> {
+    let Some(value) = value.map(|value| value.trim().to_string()).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    if value.chars().count() > max_len {
+        return Err(format!("{field} must be at most {max_len} characters"));
+    }
+    Ok(Some(value))
+}
+
+#[spacetimedb::reducer]
+pub fn set_group_description(ctx: &ReducerContext, groupchat_id: String, description: Option<String>) -> Result<(), String> {
+    let description = validate_optional_text(description, MAX_DESCRIPTION_LEN, "Description")?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    audit(ctx, &groupchat_id, AuditAction::SetDescription, None, description.clone());
+    ctx.db.groupchat().id().update(GroupChat {
+        description,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_group_topic(ctx: &ReducerContext, groupchat_id: String, topic: Option<String>) -> Result<(), String> {
+    let topic = validate_optional_text(topic, MAX_TOPIC_LEN, "Topic")?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    audit(ctx, &groupchat_id, AuditAction::SetTopic, None, topic.clone());
+    ctx.db.groupchat().id().update(GroupChat {
+        topic,
+        ..groupchat
+    });
+    Ok(())
+}
+
+fn validate_url(url: String) -> Result<String, String> {
+    let url = url.trim().to_string();
+    if !(url.starts_with("https://") || url.starts_with("http://")) {
+        Err("URLs must start with http:// or https://".to_string())
+    } else if url.len() > MAX_URL_LEN {
+        Err(format!("URLs must be at most {MAX_URL_LEN} characters"))
+    } else if url.chars().any(char::is_whitespace) {
+        Err("URLs must not contain whitespace".to_string())
+    } else {
+        Ok(url)
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn set_group_avatar(ctx: &ReducerContext, groupchat_id: String, avatar_url: Option<String>) -> Result<(), String> {
+    let avatar_url = avatar_url.map(validate_url).transpose()?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    audit(ctx, &groupchat_id, AuditAction::SetAvatar, None, avatar_url.clone());
+    ctx.db.groupchat().id().update(GroupChat {
+        avatar_url,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_slowmode(ctx: &ReducerContext, groupchat_id: String, slowmode_seconds: u32) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    audit(ctx, &groupchat_id, AuditAction::SetSlowmode, None, Some(slowmode_seconds.to_string()));
+    ctx.db.groupchat().id().update(GroupChat {
+        slowmode_seconds,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_post_policy(ctx: &ReducerContext, groupchat_id: String, post_policy: PostPolicy) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    audit(ctx, &groupchat_id, AuditAction::SetPostPolicy, None, Some(format!("{post_policy:?}")));
+    ctx.db.groupchat().id().update(GroupChat {
+        post_policy,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn archive_groupchat(ctx: &ReducerContext, groupchat_id: String) -> Result<(), String> {
+    set_archived(ctx, groupchat_id, true)
+}
+
+#[spacetimedb::reducer]
+pub fn unarchive_groupchat(ctx: &ReducerContext, groupchat_id: String) -> Result<(), String> {
+    set_archived(ctx, groupchat_id, false)
+}
+
+fn set_archived(ctx: &ReducerContext, groupchat_id: String, archived: bool) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    if groupchat.archived == archived {
+        return Err(if archived { "Group chat is already archived" } else { "Group chat is not archived" }.to_string());
+    }
+    let action = if archived { AuditAction::ArchiveGroupChat } else { AuditAction::UnarchiveGroupChat };
+    audit(ctx, &groupchat_id, action, None, None);
+    ctx.db.groupchat().id().update(GroupChat {
+        archived,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_discoverable(ctx: &ReducerContext, groupchat_id: String, discoverable: bool) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    if discoverable && groupchat.visibility == GroupVisibility::Private {
+        return Err("Private group chats cannot be listed for discovery".to_string());
+    }
+    audit(ctx, &groupchat_id, AuditAction::SetDiscoverable, None, Some(discoverable.to_string()));
+    ctx.db.groupchat().id().update(GroupChat {
+        discoverable,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_nsfw(ctx: &ReducerContext, groupchat_id: String, nsfw: bool) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    audit(ctx, &groupchat_id, AuditAction::SetNsfw, None, Some(nsfw.to_string()));
+    ctx.db.groupchat().id().update(GroupChat {
+        nsfw,
+        ..groupchat
+    });
+    Ok(())
+}
+
+fn shows_nsfw(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db.user_settings().identity().find(identity).is_some_and(|settings| settings.show_nsfw)
+}
+
+fn validate_tag(tag: String) -> Result<String, String> {
+    let tag = tag.trim().to_lowercase();
+    if tag.is_empty() || tag.len() > MAX_TAG_LEN {
+        Err(format!("Tags must be between 1 and {MAX_TAG_LEN} characters"))
+    } else if !tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        Err("Tags may only contain letters, digits and dashes".to_string())
+    } else {
+        Ok(tag)
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn add_group_tag(ctx: &ReducerContext, groupchat_id: String, tag: String) -> Result<(), String> {
+    let tag = validate_tag(tag)?;
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    if ctx.db.group_tag().groupchat_and_tag().filter((&groupchat_id, &tag)).next().is_some() {
+        return Err("Group chat already has this tag".to_string());
+    }
+    if ctx.db.group_tag().groupchat_id().filter(&groupchat_id).count() >= MAX_TAGS_PER_GROUP {
+        return Err(format!("A group chat can have at most {MAX_TAGS_PER_GROUP} tags"));
+    }
+    ctx.db.group_tag().insert(GroupTag {
+        id: 0,
+        groupchat_id,
+        tag
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_group_tag(ctx: &ReducerContext, groupchat_id: String, tag: String) -> Result<(), String> {
+    let tag = validate_tag(tag)?;
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    let group_tag = ctx.db.group_tag().groupchat_and_tag().filter((&groupchat_id, &tag)).next()
+        .ok_or("Group chat does not have this tag")?;
+    ctx.db.group_tag().id().delete(group_tag.id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_member_limit(ctx: &ReducerContext, groupchat_id: String, max_members: Option<u32>) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    if max_members == Some(0) {
+        return Err("Member limit must be at least one".to_string());
+    }
+    audit(ctx, &groupchat_id, AuditAction::SetMemberLimit, None, Some(format!("{max_members:?}")));
+    ctx.db.groupchat().id().update(GroupChat {
+        max_members,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_group_visibility(ctx: &ReducerContext, groupchat_id: String, visibility: GroupVisibility) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    audit(ctx, &groupchat_id, AuditAction::SetVisibility, None, Some(format!("{visibility:?}")));
+    ctx.db.groupchat().id().update(GroupChat {
+        visibility,
+        // private group chats are never listed for discovery
+        discoverable: groupchat.discoverable && visibility == GroupVisibility::Public,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_retention_days(ctx: &ReducerContext, groupchat_id: String, retention_days: Option<u32>) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    if retention_days == Some(0) {
+        return Err("Retention must be at least one day".to_string());
+    }
+    audit(ctx, &groupchat_id, AuditAction::SetRetention, None, Some(format!("{retention_days:?}")));
+    ctx.db.groupchat().id().update(GroupChat {
+        retention_days,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn apply_retention_policies(ctx: &ReducerContext, _cleanup: RetentionCleanup) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `apply_retention_policies` may only be invoked by the scheduler".to_string());
+    }
+    for groupchat in ctx.db.groupchat().iter() {
+        let Some(retention_days) = groupchat.retention_days else {
+            continue;
+        };
+        let cutoff = seconds_ago(ctx, retention_days as u64 * SECONDS_PER_DAY);
+        for message in ctx.db.message().groupchat_id().filter(&groupchat.id).filter(|message| message.sent < cutoff) {
+            purge_message(ctx, message.id);
+        }
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_message_ttl(ctx: &ReducerContext, groupchat_id: String, message_ttl_seconds: Option<u64>) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    if message_ttl_seconds.is_some_and(|ttl| ttl < MIN_MESSAGE_TTL_SECS) {
+        return Err(format!("Disappearing messages must live at least {MIN_MESSAGE_TTL_SECS} seconds"));
+    }
+    audit(ctx, &groupchat_id, AuditAction::SetMessageTtl, None, Some(format!("{message_ttl_seconds:?}")));
+    ctx.db.groupchat().id().update(GroupChat {
+        message_ttl_seconds,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn expire_disappearing_messages(ctx: &ReducerContext, _sweep: MessageExpirySweep) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `expire_disappearing_messages` may only be invoked by the scheduler".to_string());
+    }
+    for groupchat in ctx.db.groupchat().iter() {
+        let Some(message_ttl_seconds) = groupchat.message_ttl_seconds else {
+            continue;
+        };
+        let cutoff = seconds_ago(ctx, message_ttl_seconds);
+        for message in ctx.db.message().groupchat_id().filter(&groupchat.id).filter(|message| message.sent < cutoff) {
+            purge_message(ctx, message.id);
+        }
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn generate_daily_digests(ctx: &ReducerContext, _schedule: DailyDigestSchedule) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `generate_daily_digests` may only be invoked by the scheduler".to_string());
+    }
+    let period_start = seconds_ago(ctx, SECONDS_PER_DAY);
+    let first_day = day_of(period_start);
+    for groupchat in ctx.db.groupchat().iter() {
+        let mut posts_per_member: HashMap<Identity, u32> = HashMap::new();
+        for message in ctx.db.message().groupchat_and_day().filter((&groupchat.id, first_day..=day_of(ctx.timestamp))) {
+            if message.sent >= period_start && message.kind == MessageKind::UserText && !message.deleted {
+                *posts_per_member.entry(message.sender).or_default() += 1;
+            }
+        }
+        let new_members = ctx.db.groupchat_membership().groupchat_id().filter(&groupchat.id)
+            .filter(|membership| membership.joined_at >= period_start)
+            .count() as u32;
+        if posts_per_member.is_empty() && new_members == 0 {
+            continue;
+        }
+        let message_count = posts_per_member.values().sum();
+        let mut most_active: Vec<(Identity, u32)> = posts_per_member.into_iter().collect();
+        most_active.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        ctx.db.daily_digest().insert(DailyDigest {
+            id: 0,
+            groupchat_id: groupchat.id,
+            period_start,
+            period_end: ctx.timestamp,
+            message_count,
+            most_active: most_active.into_iter().take(DIGEST_MOST_ACTIVE_LEN).map(|(identity, _)| identity).collect(),
+            new_members
+        });
+    }
+    let cutoff = seconds_ago(ctx, DIGEST_RETENTION_DAYS * SECONDS_PER_DAY);
+    for digest in ctx.db.daily_digest().iter().filter(|digest| digest.period_end < cutoff) {
+        ctx.db.daily_digest().id().delete(digest.id);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn roll_up_group_stats(ctx: &ReducerContext, _rollup: GroupStatsRollup) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `roll_up_group_stats` may only be invoked by the scheduler".to_string());
+    }
+    let day = day_of(ctx.timestamp);
+    for groupchat in ctx.db.groupchat().iter() {
+        let mut posters: Vec<Identity> = Vec::new();
+        let mut message_count = 0;
+        for message in ctx.db.message().groupchat_and_day().filter((&groupchat.id, day)) {
+            if message.kind == MessageKind::UserText && !message.deleted {
+                message_count += 1;
+                posters.push(message.sender);
+            }
+        }
+        posters.sort();
+        posters.dedup();
+        let unique_posters = posters.len() as u32;
+        if let Some(stats) = ctx.db.group_stats().groupchat_and_day().filter((&groupchat.id, day)).next() {
+            ctx.db.group_stats().id().update(GroupStats {
+                message_count,
+                unique_posters,
+                peak_online: stats.peak_online.max(groupchat.online_count),
+                ..stats
+            });
+        } else {
+            ctx.db.group_stats().insert(GroupStats {
+                id: 0,
+                groupchat_id: groupchat.id,
+                day,
+                message_count,
+                unique_posters,
+                peak_online: groupchat.online_count
+            });
+        }
+    }
+    let oldest_day = day.saturating_sub(GROUP_STATS_RETENTION_DAYS);
+    for stats in ctx.db.group_stats().iter().filter(|stats| stats.day < oldest_day) {
+        ctx.db.group_stats().id().delete(stats.id);
+    }
+    Ok(())
+}
+
+fn day_of(timestamp: Timestamp) -> u32 {
+    (timestamp.to_micros_since_unix_epoch() / (SECONDS_PER_DAY as i64 * 1_000_000)) as u32
+}
+
+fn increment_member_stats(ctx: &ReducerContext, message: &Message) {
+    if message.kind != MessageKind::UserText {
+        return;
+    }
+    if let Some(stats) = ctx.db.member_stats().groupchat_and_user().filter((&message.groupchat_id, message.sender)).next() {
+        ctx.db.member_stats().id().update(MemberStats {
+            message_count: stats.message_count + 1,
+            ..stats
+        });
+    } else {
+        ctx.db.member_stats().insert(MemberStats {
+            id: 0,
+            groupchat_id: message.groupchat_id.clone(),
+            identity: message.sender,
+            message_count: 1
+        });
+    }
+}
+
+// only the first message of each day extends the streak
+fn update_streak(ctx: &ReducerContext, message: &Message) {
+    if message.kind != MessageKind::UserText {
+        return;
+    }
+    let today = day_of(message.sent);
+    if let Some(streak) = ctx.db.streak().groupchat_and_user().filter((&message.groupchat_id, message.sender)).next() {
+        if streak.last_day == today {
+            return;
+        }
+        let current = if streak.current > 0 && streak.last_day + 1 == today { streak.current + 1 } else { 1 };
+        ctx.db.streak().id().update(Streak {
+            current,
+            longest: streak.longest.max(current),
+            last_day: today,
+            ..streak
+        });
+    } else {
+        ctx.db.streak().insert(Streak {
+            id: 0,
+            groupchat_id: message.groupchat_id.clone(),
+            identity: message.sender,
+            current: 1,
+            longest: 1,
+            last_day: today
+        });
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn reset_broken_streaks(ctx: &ReducerContext, _sweep: StreakResetSweep) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `reset_broken_streaks` may only be invoked by the scheduler".to_string());
+    }
+    let today = day_of(ctx.timestamp);
+    for streak in ctx.db.streak().iter().filter(|streak| streak.current > 0 && streak.last_day + 1 < today) {
+        ctx.db.streak().id().update(Streak {
+            current: 0,
+            ..streak
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn reset_leaderboard(ctx: &ReducerContext, groupchat_id: String) -> Result<(), String> {
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    for stats in ctx.db.member_stats().groupchat_id().filter(&groupchat_id) {
+        ctx.db.member_stats().id().delete(stats.id);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn promote_member(ctx: &ReducerContext, groupchat_id: String, target: Identity) -> Result<(), String> {
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    let membership = find_membership(ctx, target, &groupchat_id).ok_or("Target is not a member of this group chat")?;
+    if membership.role != MembershipRole::Member {
+        return Err("Only regular members can be promoted".to_string());
+    }
+    audit(ctx, &groupchat_id, AuditAction::PromoteMember, Some(target), None);
+    ctx.db.groupchat_membership().id().update(GroupChatMembership {
+        role: MembershipRole::Admin,
+        moderator: true,
+        ..membership
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn demote_member(ctx: &ReducerContext, groupchat_id: String, target: Identity) -> Result<(), String> {
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    let membership = find_membership(ctx, target, &groupchat_id).ok_or("Target is not a member of this group chat")?;
+    if membership.role != MembershipRole::Admin {
+        return Err("Only admins can be demoted".to_string());
+    }
+    audit(ctx, &groupchat_id, AuditAction::DemoteMember, Some(target), None);
+    ctx.db.groupchat_membership().id().update(GroupChatMembership {
+        role: MembershipRole::Member,
+        moderator: false,
+        ..membership
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn transfer_ownership(ctx: &ReducerContext, groupchat_id: String, new_owner: Identity) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    if groupchat.created_by != sender {
+        return Err("Only the owner can transfer ownership of this group chat".to_string());
+    }
+    if new_owner == sender {
+        return Err("User already owns this group chat".to_string());
+    }
+    let target = find_membership(ctx, new_owner, &groupchat_id).ok_or("Target is not a member of this group chat")?;
+    let caller = find_membership(ctx, sender, &groupchat_id).ok_or("User is not a member of this group chat")?;
+    // the previous owner stays on as an admin
+    ctx.db.groupchat_membership().id().update(GroupChatMembership {
+        role: MembershipRole::Admin,
+        moderator: true,
+        ..caller
+    });
+    ctx.db.groupchat_membership().id().update(GroupChatMembership {
+        role: MembershipRole::Owner,
+        moderator: true,
+        ..target
+    });
+    ctx.db.groupchat().id().update(GroupChat {
+        created_by: new_owner,
+        ..groupchat
+    });
+    audit(ctx, &groupchat_id, AuditAction::TransferOwnership, Some(new_owner), None);
+    let text = format!("{} transferred ownership to {}", display_name(ctx, sender), display_name(ctx, new_owner));
+    post_system_message(ctx, groupchat_id, text);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn kick_member(ctx: &ReducerContext, groupchat_id: String, target: Identity) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let caller = has_permission(ctx, &groupchat_id, GroupAction::Kick)?;
+    let membership = find_membership(ctx, target, &groupchat_id).ok_or("Target is not a member of this group chat")?;
+    check_outranks(&caller, &membership)?;
+    remove_member(ctx, membership);
+    audit(ctx, &groupchat_id, AuditAction::KickMember, Some(target), None);
+    notify(ctx, target, NotificationKind::Kicked, Some(groupchat_id.clone()), None);
+    let text = format!("{} removed {} from the group chat", display_name(ctx, sender), display_name(ctx, target));
+    post_system_message(ctx, groupchat_id.clone(), text);
+    log::info!("User {} kicked {target} from group chat {groupchat_id}", sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn ban_member(ctx: &ReducerContext, groupchat_id: String, target: Identity, reason: Option<String>) -> Result<(), String> {
+    let caller = require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    if is_banned(ctx, &groupchat_id, target) {
+        return Err("User is already banned from this group chat".to_string());
+    }
+    // banning also kicks the target if they are currently a member
+    if let Some(membership) = find_membership(ctx, target, &groupchat_id) {
+        check_outranks(&caller, &membership)?;
+    }
+    audit(ctx, &groupchat_id, AuditAction::BanMember, Some(target), reason.clone());
+    apply_ban(ctx, groupchat_id, target, reason);
+    Ok(())
+}
+
+fn apply_ban(ctx: &ReducerContext, groupchat_id: String, target: Identity, reason: Option<String>) {
+    if let Some(membership) = find_membership(ctx, target, &groupchat_id) {
+        remove_member(ctx, membership);
+    }
+    ctx.db.groupchat_ban().insert(GroupChatBan {
+        id: 0,
+        groupchat_id,
+        identity: target,
+        banned_by: account_of(ctx, ctx.sender),
+        reason,
+        banned_at: ctx.timestamp
+    });
+}
+
+#[spacetimedb::reducer]
+pub fn unban_member(ctx: &ReducerContext, groupchat_id: String, target: Identity) -> Result<(), String> {
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    let ban = ctx.db.groupchat_ban().groupchat_and_user().filter((&groupchat_id, target)).next()
+        .ok_or("User is not banned from this group chat")?;
+    ctx.db.groupchat_ban().id().delete(ban.id);
+    audit(ctx, &groupchat_id, AuditAction::UnbanMember, Some(target), None);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn mute_member(ctx: &ReducerContext, groupchat_id: String, target: Identity, duration_secs: u64) -> Result<(), String> {
+    let caller = require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    let membership = find_membership(ctx, target, &groupchat_id).ok_or("Target is not a member of this group chat")?;
+    check_outranks(&caller, &membership)?;
+    if duration_secs == 0 || duration_secs > MAX_MUTE_SECS {
+        return Err(format!("Mutes must last between 1 and {MAX_MUTE_SECS} seconds"));
+    }
+    audit(ctx, &groupchat_id, AuditAction::MuteMember, Some(target), Some(format!("{duration_secs}s")));
+    apply_mute(ctx, groupchat_id, target, duration_secs);
+    Ok(())
+}
+
+// muting an already muted member replaces the previous mute
+fn apply_mute(ctx: &ReducerContext, groupchat_id: String, target: Identity, duration_secs: u64) {
+    let until = seconds_from_now(ctx, duration_secs);
+    if let Some(mute) = ctx.db.mute().groupchat_and_user().filter((&groupchat_id, target)).next() {
+        ctx.db.mute().id().update(Mute {
+            muted_by: account_of(ctx, ctx.sender),
+            until,
+            ..mute
+        });
+    } else {
+        ctx.db.mute().insert(Mute {
+            id: 0,
+            groupchat_id,
+            identity: target,
+            muted_by: account_of(ctx, ctx.sender),
+            until
+        });
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn warn_member(ctx: &ReducerContext, groupchat_id: String, target: Identity, reason: Option<String>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let reason = validate_optional_text(reason, MAX_WARNING_REASON_LEN, "Warning reason")?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    let caller = require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    let membership = find_membership(ctx, target, &groupchat_id).ok_or("Target is not a member of this group chat")?;
+    check_outranks(&caller, &membership)?;
+    audit(ctx, &groupchat_id, AuditAction::WarnMember, Some(target), reason.clone());
+    ctx.db.warning().insert(Warning {
+        id: 0,
+        groupchat_id: groupchat_id.clone(),
+        identity: target,
+        warned_by: sender,
+        reason,
+        warned_at: ctx.timestamp
+    });
+    let strikes = ctx.db.warning().groupchat_and_user().filter((&groupchat_id, target)).count() as u32;
+    if groupchat.strike_threshold == 0 || strikes % groupchat.strike_threshold != 0 {
+        return Ok(());
+    }
+    let details = format!("{strikes} warnings");
+    match groupchat.strike_escalation {
+        StrikeEscalation::Mute => {
+            audit(ctx, &groupchat_id, AuditAction::MuteMember, Some(target), Some(details));
+            apply_mute(ctx, groupchat_id, target, STRIKE_MUTE_SECS);
+        }
+        StrikeEscalation::Ban => {
+            audit(ctx, &groupchat_id, AuditAction::BanMember, Some(target), Some(details.clone()));
+            apply_ban(ctx, groupchat_id, target, Some(details));
+        }
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_strike_policy(ctx: &ReducerContext, groupchat_id: String, strike_threshold: u32, strike_escalation: StrikeEscalation) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    audit(ctx, &groupchat_id, AuditAction::SetStrikePolicy, None, Some(format!("{strike_threshold} -> {strike_escalation:?}")));
+    ctx.db.groupchat().id().update(GroupChat {
+        strike_threshold,
+        strike_escalation,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_deleted_account_policy(ctx: &ReducerContext, groupchat_id: String, deleted_account_policy: DeletedAccountPolicy) -> Result<(), String> {
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    audit(ctx, &groupchat_id, AuditAction::SetDeletedAccountPolicy, None, Some(format!("{deleted_account_policy:?}")));
+    ctx.db.groupchat().id().update(GroupChat {
+        deleted_account_policy,
+        ..groupchat
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unmute_member(ctx: &ReducerContext, groupchat_id: String, target: Identity) -> Result<(), String> {
+    require_role(ctx, &groupchat_id, MembershipRole::Admin)?;
+    let mute = find_active_mute(ctx, &groupchat_id, target).ok_or("User is not muted in this group chat")?;
+    ctx.db.mute().id().delete(mute.id);
+    audit(ctx, &groupchat_id, AuditAction::UnmuteMember, Some(target), None);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn expire_mutes(ctx: &ReducerContext, _sweep: MuteExpirySweep) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer `expire_mutes` may only be invoked by the scheduler".to_string());
+    }
+    for mute in ctx.db.mute().iter().filter(|mute| mute.until <= ctx.timestamp) {
+        ctx.db.mute().id().delete(mute.id);
+    }
+    Ok(())
+}
+
+// mutes are checked against their expiry so they end on time even before the sweep removes them
+fn find_active_mute(ctx: &ReducerContext, groupchat_id: &str, identity: Identity) -> Option<Mute> {
+    ctx.db.mute().groupchat_and_user().filter((groupchat_id, identity)).find(|mute| mute.until > ctx.timestamp)
+}
+
+#[spacetimedb::reducer]
+pub fn create_invite(ctx: &ReducerContext, groupchat_id: String, expires_in_secs: Option<u64>, max_uses: Option<u32>) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    has_permission(ctx, &groupchat_id, GroupAction::Invite)?;
+    check_challenge_passed(ctx)?;
+    if max_uses == Some(0) {
+        return Err("Invite must allow at least one use".to_string());
+    }
+    ctx.db.groupchat_invite().try_insert(GroupChatInvite {
+        code: random_code(ctx, INVITE_CODE_LEN),
+        groupchat_id,
+        created_by: sender,
+        created_at: ctx.timestamp,
+        expires_at: expires_in_secs.map(|secs| seconds_from_now(ctx, secs)),
+        max_uses,
+        uses: 0
+    })?;
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn revoke_invite(ctx: &ReducerContext, code: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let invite = ctx.db.groupchat_invite().code().find(&code).ok_or("Invite does not exist")?;
+    // the creator of an invite can always revoke it, otherwise members allowed to invite can
+    if invite.created_by != sender {
+        has_permission(ctx, &invite.groupchat_id, GroupAction::Invite)?;
+    }
+    ctx.db.groupchat_invite().code().delete(&code);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn join_via_invite(ctx: &ReducerContext, code: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let invite = ctx.db.groupchat_invite().code().find(&code).ok_or("Invite does not exist")?;
+    if invite.expires_at.is_some_and(|expires_at| expires_at <= ctx.timestamp) {
+        return Err("Invite has expired".to_string());
+    }
+    if invite.max_uses.is_some_and(|max_uses| invite.uses >= max_uses) {
+        return Err("Invite has reached its usage limit".to_string());
+    }
+    if ctx.db.user().identity().find(sender).is_none() {
+        return Err("Cannot join group chat for unknown user".to_string());
+    }
+    let groupchat = ctx.db.groupchat().id().find(&invite.groupchat_id).ok_or("Group chat does not exist")?;
+    check_can_join(ctx, sender, &groupchat)?;
+    if find_membership(ctx, sender, &invite.groupchat_id).is_some() {
+        return Err("User is already a member of this group chat".to_string());
+    }
+    add_member(ctx, sender, invite.groupchat_id.clone(), MembershipRole::Member);
+    let text = format!("{} joined the group chat", display_name(ctx, sender));
+    post_system_message(ctx, invite.groupchat_id.clone(), text);
+    notify(ctx, invite.created_by, NotificationKind::InviteUsed, Some(invite.groupchat_id.clone()), None);
+    ctx.db.groupchat_invite().code().update(GroupChatInvite {
+        uses: invite.uses + 1,
+        ..invite
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn regenerate_join_code(ctx: &ReducerContext, groupchat_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    has_permission(ctx, &groupchat_id, GroupAction::Invite)?;
+    let mut join_code = random_digits(ctx, JOIN_CODE_LEN);
+    while ctx.db.group_join_code().join_code().find(&join_code).is_some() {
+        join_code = random_digits(ctx, JOIN_CODE_LEN);
+    }
+    // the previous code stops working immediately
+    ctx.db.group_join_code().groupchat_id().delete(&groupchat_id);
+    ctx.db.group_join_code().insert(GroupJoinCode {
+        groupchat_id,
+        join_code,
+        created_by: sender,
+        created_at: ctx.timestamp
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn join_by_code(ctx: &ReducerContext, join_code: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let window_start = seconds_ago(ctx, JOIN_CODE_ATTEMPT_WINDOW_SECS);
+    let failed_attempts: Vec<Timestamp> = ctx.db.join_code_attempt().identity().find(sender)
+        .map(|attempt| attempt.failed_attempts)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|failed_at| *failed_at > window_start)
+        .collect();
+    if failed_attempts.len() >= MAX_JOIN_CODE_FAILURES {
+        return Err(format!(
+            "Too many wrong join codes, try again in {} minutes", JOIN_CODE_ATTEMPT_WINDOW_SECS / 60
+        ));
+    }
+    let Some(code) = ctx.db.group_join_code().join_code().find(&join_code.trim().to_string()) else {
+        // returning an error would roll back the recorded attempt, so wrong codes succeed without
+        // joining and the caller sees the failure in its `join_code_attempt` row
+        let mut failed_attempts = failed_attempts;
+        failed_attempts.push(ctx.timestamp);
+        let attempt = JoinCodeAttempt { identity: sender, failed_attempts };
+        if ctx.db.join_code_attempt().identity().find(sender).is_some() {
+            ctx.db.join_code_attempt().identity().update(attempt);
+        } else {
+            ctx.db.join_code_attempt().insert(attempt);
+        }
+        log::info!("User {} entered a wrong join code", sender);
+        return Ok(());
+    };
+    if ctx.db.user().identity().find(sender).is_none() {
+        return Err("Cannot join group chat for unknown user".to_string());
+    }
+    let groupchat = ctx.db.groupchat().id().find(&code.groupchat_id).ok_or("Group chat does not exist")?;
+    check_can_join(ctx, sender, &groupchat)?;
+    if find_membership(ctx, sender, &code.groupchat_id).is_some() {
+        return Err("User is already a member of this group chat".to_string());
+    }
+    add_member(ctx, sender, code.groupchat_id.clone(), MembershipRole::Member);
+    let text = format!("{} joined the group chat", display_name(ctx, sender));
+    post_system_message(ctx, code.groupchat_id, text);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn request_to_join(ctx: &ReducerContext, groupchat_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    if groupchat.visibility == GroupVisibility::Public {
+        return Err("Group chat is public, join it directly instead".to_string());
+    }
+    if groupchat.archived {
+        return Err("Group chat is archived".to_string());
+    }
+    if ctx.db.user().identity().find(sender).is_none() {
+        return Err("Cannot request to join group chat for unknown user".to_string());
+    }
+    if is_banned(ctx, &groupchat_id, sender) {
+        return Err("User is banned from this group chat".to_string());
+    }
+    if find_membership(ctx, sender, &groupchat_id).is_some() {
+        return Err("User is already a member of this group chat".to_string());
+    }
+    if ctx.db.join_request().groupchat_and_user().filter((&groupchat_id, sender)).next().is_some() {
+        return Err("A join request for this group chat is already pending".to_string());
+    }
+    ctx.db.join_request().insert(JoinRequest {
+        id: 0,
+        groupchat_id,
+        identity: sender,
+        requested_at: ctx.timestamp
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn approve_join_request(ctx: &ReducerContext, request_id: u64) -> Result<(), String> {
+    let request = ctx.db.join_request().id().find(request_id).ok_or("Join request does not exist")?;
+    require_role(ctx, &request.groupchat_id, MembershipRole::Admin)?;
+    ctx.db.join_request().id().delete(request_id);
+    let groupchat = ctx.db.groupchat().id().find(&request.groupchat_id).ok_or("Group chat does not exist")?;
+    check_can_join(ctx, request.identity, &groupchat)?;
+    audit(ctx, &request.groupchat_id, AuditAction::ApproveJoinRequest, Some(request.identity), None);
+    if find_membership(ctx, request.identity, &request.groupchat_id).is_none() {
+        add_member(ctx, request.identity, request.groupchat_id.clone(), MembershipRole::Member);
+        let text = format!("{} joined the group chat", display_name(ctx, request.identity));
+        post_system_message(ctx, request.groupchat_id.clone(), text);
+        notify(ctx, request.identity, NotificationKind::JoinApproved, Some(request.groupchat_id), None);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn reject_join_request(ctx: &ReducerContext, request_id: u64) -> Result<(), String> {
+    let request = ctx.db.join_request().id().find(request_id).ok_or("Join request does not exist")?;
+    require_role(ctx, &request.groupchat_id, MembershipRole::Admin)?;
+    ctx.db.join_request().id().delete(request_id);
+    audit(ctx, &request.groupchat_id, AuditAction::RejectJoinRequest, Some(request.identity), None);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn delete_groupchat(ctx: &ReducerContext, groupchat_id: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let groupchat = ctx.db.groupchat().id().find(&groupchat_id).ok_or("Group chat does not exist")?;
+    if groupchat.created_by != sender {
+        return Err("Only the creator can delete this group chat".to_string());
+    }
+    // remove everything that references the group chat so no orphaned rows are left behind
+    for membership in ctx.db.groupchat_membership().groupchat_id().filter(&groupchat_id) {
+        ctx.db.groupchat_membership().id().delete(membership.id);
+    }
+    for ban in ctx.db.groupchat_ban().groupchat_id().filter(&groupchat_id) {
+        ctx.db.groupchat_ban().id().delete(ban.id);
+    }
+    for warning in ctx.db.warning().groupchat_id().filter(&groupchat_id) {
+        ctx.db.warning().id().delete(warning.id);
+    }
+    for mute in ctx.db.mute().groupchat_id().filter(&groupchat_id) {
+        ctx.db.mute().id().delete(mute.id);
+    }
+    for invite in ctx.db.groupchat_invite().groupchat_id().filter(&groupchat_id) {
+        ctx.db.groupchat_invite().code().delete(&invite.code);
+    }
+    ctx.db.group_join_code().groupchat_id().delete(&groupchat_id);
+    for request in ctx.db.join_request().groupchat_id().filter(&groupchat_id) {
+        ctx.db.join_request().id().delete(request.id);
+    }
+    for topic in ctx.db.topic().groupchat_id().filter(&groupchat_id) {
+        ctx.db.topic().id().delete(topic.id);
+    }
+    for permission in ctx.db.group_permission().groupchat_id().filter(&groupchat_id) {
+        ctx.db.group_permission().id().delete(permission.id);
+    }
+    for key in ctx.db.group_key().groupchat_id().filter(&groupchat_id) {
+        ctx.db.group_key().id().delete(key.id);
+    }
+    for key_epoch in ctx.db.group_key_epoch().groupchat_id().filter(&groupchat_id) {
+        ctx.db.group_key_epoch().id().delete(key_epoch.id);
+    }
+    for custom_emoji in ctx.db.custom_emoji().groupchat_id().filter(&groupchat_id) {
+        ctx.db.custom_emoji().id().delete(custom_emoji.id);
+    }
+    for pin in ctx.db.pinned_message().groupchat_id().filter(&groupchat_id) {
+        ctx.db.pinned_message().id().delete(pin.id);
+    }
+    for indicator in ctx.db.typing_indicator().groupchat_id().filter(&groupchat_id) {
+        ctx.db.typing_indicator().id().delete(indicator.id);
+    }
+    for read_state in ctx.db.read_state().groupchat_id().filter(&groupchat_id) {
+        ctx.db.read_state().id().delete(read_state.id);
+    }
+    for mention in ctx.db.mention().groupchat_id().filter(&groupchat_id) {
+        ctx.db.mention().id().delete(mention.id);
+    }
+    for pref in ctx.db.notification_pref().groupchat_id().filter(&groupchat_id) {
+        ctx.db.notification_pref().id().delete(pref.id);
+    }
+    for draft in ctx.db.draft().groupchat_id().filter(&groupchat_id) {
+        ctx.db.draft().id().delete(draft.id);
+    }
+    for entry in ctx.db.folder_membership().iter().filter(|entry| entry.groupchat_id == groupchat_id) {
+        ctx.db.folder_membership().id().delete(entry.id);
+    }
+    for reminder in ctx.db.reminder().iter().filter(|reminder| reminder.groupchat_id == groupchat_id) {
+        ctx.db.reminder().scheduled_id().delete(reminder.scheduled_id);
+    }
+    for result in ctx.db.search_result().iter().filter(|result| result.groupchat_id == groupchat_id) {
+        ctx.db.search_result().id().delete(result.id);
+    }
+    for banned in ctx.db.banned_word().iter().filter(|banned| banned.groupchat_id.as_ref() == Some(&groupchat_id)) {
+        ctx.db.banned_word().id().delete(banned.id);
+    }
+    for entry in ctx.db.audit_log().groupchat_id().filter(&groupchat_id) {
+        ctx.db.audit_log().id().delete(entry.id);
+    }
+    for group_tag in ctx.db.group_tag().groupchat_id().filter(&groupchat_id) {
+        ctx.db.group_tag().id().delete(group_tag.id);
+    }
+    for report in ctx.db.report().groupchat_id().filter(&groupchat_id) {
+        ctx.db.report().id().delete(report.id);
+    }
+    for poll in ctx.db.poll().groupchat_id().filter(&groupchat_id) {
+        delete_poll(ctx, poll.id);
+    }
+    for stats in ctx.db.member_stats().groupchat_id().filter(&groupchat_id) {
+        ctx.db.member_stats().id().delete(stats.id);
+    }
+    for streak in ctx.db.streak().groupchat_id().filter(&groupchat_id) {
+        ctx.db.streak().id().delete(streak.id);
+    }
+    for badge in ctx.db.badge().iter().filter(|badge| badge.groupchat_id.as_ref() == Some(&groupchat_id)) {
+        delete_badge(ctx, badge.id);
+    }
+    for stats in ctx.db.group_stats().groupchat_id().filter(&groupchat_id) {
+        ctx.db.group_stats().id().delete(stats.id);
+    }
+    for digest in ctx.db.daily_digest().groupchat_id().filter(&groupchat_id) {
+        ctx.db.daily_digest().id().delete(digest.id);
+    }
+    for event in ctx.db.group_event().groupchat_id().filter(&groupchat_id) {
+        delete_event(ctx, event.id);
+    }
+    for webhook in ctx.db.webhook().groupchat_id().filter(&groupchat_id) {
+        delete_webhook(ctx, webhook.id);
+    }
+    for message in ctx.db.message().groupchat_id().filter(&groupchat_id) {
+        purge_message(ctx, message.id);
+    }
+    ctx.db.groupchat().id().delete(&groupchat_id);
+    log::info!("User {} deleted group chat {groupchat_id}", sender);
+    Ok(())
 }
 
-
-#[spacetimedb::table(name = groupchat_membership,
-    index(name = user_and_groupchat, btree(columns = [identity, groupchat_id])),
-    public)]
-pub struct GroupChatMembership {
-    #[primary_key]
-    #[auto_inc]
-    id: u32,
-    #[index(btree)]
-    identity: Identity,
-    #[index(btree)]
-    groupchat_id: String
+#[spacetimedb::reducer]
+pub fn block_user(ctx: &ReducerContext, target: Identity) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if target == sender {
+        return Err("Cannot block yourself".to_string());
+    }
+    if ctx.db.user().identity().find(target).is_none() {
+        return Err("User does not exist".to_string());
+    }
+    if is_blocked(ctx, sender, target) {
+        return Err("User is already blocked".to_string());
+    }
+    // blocking someone ends the friendship and drops pending requests in both directions
+    if let Some(friendship) = find_friendship(ctx, sender, target) {
+        ctx.db.friendship().id().delete(friendship.id);
+    }
+    for request in [find_friend_request(ctx, sender, target), find_friend_request(ctx, target, sender)].into_iter().flatten() {
+        ctx.db.friend_request().id().delete(request.id);
+    }
+    ctx.db.block().insert(Block {
+        id: 0,
+        blocker: sender,
+        blocked: target,
+        created_at: ctx.timestamp
+    });
+    Ok(())
 }
 
-#[spacetimedb::table(name = message, public)]
-pub struct Message {
-    sender: Identity,
-    sent: Timestamp,
-    text: String,
-    #[index(btree)]
-    groupchat_id: String
+#[spacetimedb::reducer]
+pub fn unblock_user(ctx: &ReducerContext, target: Identity) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let block = ctx.db.block().blocker_and_blocked().filter((sender, target)).next()
+        .ok_or("User is not blocked")?;
+    ctx.db.block().id().delete(block.id);
+    Ok(())
 }
 
-#[spacetimedb::table(name = groupchat, public)]
-pub struct GroupChat {
-    #[primary_key]
-    id: String
+fn is_blocked(ctx: &ReducerContext, blocker: Identity, blocked: Identity) -> bool {
+    ctx.db.block().blocker_and_blocked().filter((blocker, blocked)).next().is_some()
 }
 
+#[spacetimedb::reducer]
+pub fn send_friend_request(ctx: &ReducerContext, target: Identity) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if target == sender {
+        return Err("Cannot send a friend request to yourself".to_string());
+    }
+    if ctx.db.user().identity().find(target).is_none() {
+        return Err("User does not exist".to_string());
+    }
+    if is_blocked(ctx, target, sender) || is_blocked(ctx, sender, target) {
+        return Err("Cannot send a friend request to this user".to_string());
+    }
+    if find_friendship(ctx, sender, target).is_some() {
+        return Err("You are already friends with this user".to_string());
+    }
+    if find_friend_request(ctx, sender, target).is_some() {
+        return Err("A friend request to this user is already pending".to_string());
+    }
+    if find_friend_request(ctx, target, sender).is_some() {
+        return Err("This user already sent you a friend request, accept it instead".to_string());
+    }
+    ctx.db.friend_request().insert(FriendRequest {
+        id: 0,
+        sender,
+        recipient: target,
+        created_at: ctx.timestamp
+    });
+    Ok(())
+}
 
-fn validate_name(name: String) -> Result<String, String> {
-    if name.is_empty() {
-        Err("Names must not be empty".to_string())
+#[spacetimedb::reducer]
+pub fn accept_friend_request(ctx: &ReducerContext, request_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let request = ctx.db.friend_request().id().find(request_id).ok_or("Friend request does not exist")?;
+    if request.recipient != sender {
+        return Err("Only the recipient can accept this friend request".to_string());
+    }
+    ctx.db.friend_request().id().delete(request_id);
+    let (identity_a, identity_b) = if request.sender < request.recipient {
+        (request.sender, request.recipient)
     } else {
-        Ok(name)
+        (request.recipient, request.sender)
+    };
+    ctx.db.friendship().insert(Friendship {
+        id: 0,
+        identity_a,
+        identity_b,
+        since: ctx.timestamp
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn decline_friend_request(ctx: &ReducerContext, request_id: u64) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let request = ctx.db.friend_request().id().find(request_id).ok_or("Friend request does not exist")?;
+    // the recipient declines a request, the sender can withdraw it the same way
+    if request.recipient != sender && request.sender != sender {
+        return Err("Cannot decline a friend request of other users".to_string());
     }
+    ctx.db.friend_request().id().delete(request_id);
+    Ok(())
 }
 
 #[spacetimedb::reducer]
-pub fn set_name(ctx: &ReducerContext, name: String) -> Result<(), String> {
-    let name = validate_name(name)?;
-    if let Some(user) = ctx.db.user().identity().find(ctx.sender) {
-        log::info!("User {} sets name to {name}", ctx.sender);
-        ctx.db.user().identity().update(User {
-            name: Some(name),
-            ..user
+pub fn remove_friend(ctx: &ReducerContext, friend: Identity) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let friendship = find_friendship(ctx, sender, friend).ok_or("You are not friends with this user")?;
+    ctx.db.friendship().id().delete(friendship.id);
+    Ok(())
+}
+
+fn find_friend_request(ctx: &ReducerContext, sender: Identity, recipient: Identity) -> Option<FriendRequest> {
+    ctx.db.friend_request().sender_and_recipient().filter((sender, recipient)).next()
+}
+
+fn find_friendship(ctx: &ReducerContext, a: Identity, b: Identity) -> Option<Friendship> {
+    let (identity_a, identity_b) = if a < b { (a, b) } else { (b, a) };
+    ctx.db.friendship().friends().filter((identity_a, identity_b)).next()
+}
+
+#[spacetimedb::reducer]
+pub fn send_dm(ctx: &ReducerContext, recipient: Identity, text: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    check_tos_accepted(ctx)?;
+    let text = validate_message(ctx, None, text)?;
+    check_rate_limit(ctx)?;
+    if recipient == sender {
+        return Err("Cannot send a direct message to yourself".to_string());
+    }
+    if ctx.db.user().identity().find(recipient).is_none() {
+        return Err("Recipient does not exist".to_string());
+    }
+    if is_blocked(ctx, recipient, sender) {
+        return Err("Recipient is not accepting direct messages from you".to_string());
+    }
+    if is_blocked(ctx, sender, recipient) {
+        return Err("Cannot send a direct message to a user you have blocked".to_string());
+    }
+    let conversation = find_or_create_conversation(ctx, sender, recipient);
+    let dm_message = ctx.db.dm_message().insert(DmMessage {
+        id: 0,
+        conversation_id: conversation.id,
+        sender,
+        sent: ctx.timestamp,
+        text
+    });
+    notify(ctx, recipient, NotificationKind::DirectMessage, None, Some(dm_message.id));
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn send_note_to_self(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let text = validate_message(ctx, None, text)?;
+    // users who signed up before notes to self existed get their conversation on first use
+    let conversation = find_or_create_conversation(ctx, sender, sender);
+    ctx.db.dm_message().insert(DmMessage {
+        id: 0,
+        conversation_id: conversation.id,
+        sender,
+        sent: ctx.timestamp,
+        text
+    });
+    Ok(())
+}
+
+fn find_or_create_conversation(ctx: &ReducerContext, a: Identity, b: Identity) -> DirectConversation {
+    let (participant_a, participant_b) = if a < b { (a, b) } else { (b, a) };
+    if let Some(conversation) = ctx.db.direct_conversation().participants().filter((participant_a, participant_b)).next() {
+        return conversation;
+    }
+    ctx.db.direct_conversation().insert(DirectConversation {
+        id: 0,
+        participant_a,
+        participant_b,
+        created_at: ctx.timestamp
+    })
+}
+
+fn find_membership(ctx: &ReducerContext, identity: Identity, groupchat_id: &str) -> Option<GroupChatMembership> {
+    ctx.db.groupchat_membership().user_and_groupchat().filter((identity, groupchat_id)).next()
+}
+
+// returns the caller's membership if their role is at least `role`
+fn require_role(ctx: &ReducerContext, groupchat_id: &str, role: MembershipRole) -> Result<GroupChatMembership, String> {
+    let sender = acting_identity(ctx)?;
+    let membership = find_membership(ctx, sender, groupchat_id).ok_or("User is not a member of this group chat")?;
+    if membership.role < role {
+        return Err("Insufficient permissions in this group chat".to_string());
+    }
+    Ok(membership)
+}
+
+// returns the caller's membership if their role may perform `action` in the group chat
+fn has_permission(ctx: &ReducerContext, groupchat_id: &str, action: GroupAction) -> Result<GroupChatMembership, String> {
+    let min_role = ctx.db.group_permission().groupchat_id().filter(groupchat_id)
+        .find(|permission| permission.action == action)
+        .map(|permission| permission.min_role)
+        .unwrap_or_else(|| default_min_role(action));
+    require_role(ctx, groupchat_id, min_role)
+}
+
+fn default_min_role(action: GroupAction) -> MembershipRole {
+    match action {
+        GroupAction::Post => MembershipRole::Member,
+        GroupAction::Invite | GroupAction::Pin | GroupAction::Rename | GroupAction::Kick => MembershipRole::Admin,
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn set_permission(ctx: &ReducerContext, groupchat_id: String, action: GroupAction, min_role: MembershipRole) -> Result<(), String> {
+    require_role(ctx, &groupchat_id, MembershipRole::Owner)?;
+    audit(ctx, &groupchat_id, AuditAction::SetPermission, None, Some(format!("{action:?} -> {min_role:?}")));
+    if let Some(permission) = ctx.db.group_permission().groupchat_id().filter(&groupchat_id).find(|permission| permission.action == action) {
+        ctx.db.group_permission().id().update(GroupPermission {
+            min_role,
+            ..permission
         });
-        Ok(())
     } else {
-        Err("Cannot set name for unknown user".to_string())
+        ctx.db.group_permission().insert(GroupPermission {
+            id: 0,
+            groupchat_id,
+            action,
+            min_role
+        });
     }
+    Ok(())
 }
 
-fn validate_message(text: String) -> Result<String, String> {
-    if text.is_empty() {
-        Err("Messages must not be empty".to_string())
-    } else {
-        Ok(text)
+// admins can only act on regular members, the owner can never be targeted
+fn check_outranks(caller: &GroupChatMembership, target: &GroupChatMembership) -> Result<(), String> {
+    if target.role >= caller.role {
+        return Err("Cannot act on a member with an equal or higher role".to_string());
     }
+    Ok(())
 }
 
-#[spacetimedb::reducer]
-pub fn create_groupchat(ctx: &ReducerContext, name: String) -> Result<(), String> {
-    if name.is_empty() {
-        return Err("Group chat name must not be empty".to_string());
+fn is_banned(ctx: &ReducerContext, groupchat_id: &str, identity: Identity) -> bool {
+    ctx.db.groupchat_ban().groupchat_and_user().filter((groupchat_id, identity)).next().is_some()
+}
+
+fn add_member(ctx: &ReducerContext, identity: Identity, groupchat_id: String, role: MembershipRole) -> GroupChatMembership {
+    read_state_for(ctx, identity, &groupchat_id);
+    let online = is_online(ctx, identity);
+    if let Some(groupchat) = ctx.db.groupchat().id().find(&groupchat_id) {
+        // awarded here so every way of joining counts, including invites, join codes, approved requests and the lobby
+        if groupchat.member_count < FOUNDER_MEMBER_LIMIT {
+            award_builtin_badge(ctx, identity, BuiltinBadge::Founder);
+        }
+        ctx.db.groupchat().id().update(GroupChat {
+            member_count: groupchat.member_count + 1,
+            online_count: groupchat.online_count + online as u32,
+            key_rotation_pending: groupchat.key_epoch > 0,
+            ..groupchat
+        });
     }
-    ctx.db.groupchat().try_insert(GroupChat { id: name.clone() })?;
-    // Add the creator as a member of the group chat
+    // newly joined chats are appended to the end of the member's sidebar
+    let sort_order = ctx.db.groupchat_membership().identity().filter(identity)
+        .map(|membership| membership.sort_order + 1)
+        .max()
+        .unwrap_or(0);
     ctx.db.groupchat_membership().insert(GroupChatMembership {
         id: 0,
-        identity: ctx.sender,
-        groupchat_id: name
-    });
-    Ok(())
+        identity,
+        groupchat_id,
+        role,
+        moderator: role >= MembershipRole::Admin,
+        nickname: None,
+        last_posted_at: None,
+        joined_at: ctx.timestamp,
+        pinned: false,
+        sort_order
+    })
 }
 
-#[spacetimedb::reducer]
-pub fn join_groupchat(ctx: &ReducerContext, groupchat: String) -> Result<(), String> {
-    if let Some(user) = ctx.db.user().identity().find(ctx.sender) {
-        if ctx.db.groupchat().id().find(&groupchat).is_some() {
-            // if membership to this groupchat already exists for this user, error out
-            if ctx.db.groupchat_membership().user_and_groupchat().filter((user.identity, &groupchat)).next().is_none() {
-                ctx.db.groupchat_membership().insert(GroupChatMembership {
-                    id: 0,
-                    identity: ctx.sender,
-                    groupchat_id: groupchat
-                });
-            } else {
-                return Err("User is already a member of this group chat".to_string());
-            }
-            Ok(())
-        } else {
-            Err("Group chat does not exist".to_string())
+fn is_online(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db.user().identity().find(identity).is_some_and(|user| user.presence != Presence::Offline)
+}
+
+// must be called whenever a user's presence switches between `Offline` and anything else
+fn update_online_counts(ctx: &ReducerContext, identity: Identity, online: bool) {
+    for membership in ctx.db.groupchat_membership().identity().filter(identity) {
+        if let Some(groupchat) = ctx.db.groupchat().id().find(&membership.groupchat_id) {
+            let online_count = if online { groupchat.online_count + 1 } else { groupchat.online_count.saturating_sub(1) };
+            ctx.db.groupchat().id().update(GroupChat {
+                online_count,
+                ..groupchat
+            });
         }
+    }
+}
+
+// finds the read state of a member, creating an empty one if it doesn't exist yet
+fn read_state_for(ctx: &ReducerContext, identity: Identity, groupchat_id: &str) -> ReadState {
+    if let Some(read_state) = ctx.db.read_state().user_and_groupchat().filter((identity, groupchat_id)).next() {
+        return read_state;
+    }
+    ctx.db.read_state().insert(ReadState {
+        id: 0,
+        identity,
+        groupchat_id: groupchat_id.to_string(),
+        last_read_message_id: 0,
+        last_read_at: ctx.timestamp,
+        unread_count: 0
+    })
+}
+
+const LOBBY_GROUPCHAT_ID: &str = "Lobby";
+const DELETED_USER_IDENTITY: Identity = Identity::ZERO;
+const DELETED_USER_NAME: &str = "Deleted User";
+// audit log entries of module-wide superadmin actions, no group chat can have an empty id
+const MODULE_AUDIT_GROUPCHAT_ID: &str = "";
+const INVITE_CODE_LEN: usize = 10;
+const JOIN_CODE_LEN: usize = 8;
+const LINK_TOKEN_LEN: usize = 16;
+const LINK_TOKEN_TTL_SECS: u64 = 10 * 60;
+const MAX_CLIENT_HINT_LEN: usize = 100;
+const CHALLENGE_MAX_OPERAND: u32 = 20;
+const CHALLENGE_COOLDOWN_SECS: u64 = 10 * 60;
+const MAX_CHALLENGE_FAILURES: u32 = 3;
+const MAX_JOIN_CODE_FAILURES: usize = 5;
+const JOIN_CODE_ATTEMPT_WINDOW_SECS: u64 = 15 * 60;
+const MAX_EMOJI_LEN: usize = 32;
+const MIN_SHORTCODE_LEN: usize = 2;
+const MAX_SHORTCODE_LEN: usize = 30;
+const MAX_CUSTOM_EMOJIS_PER_GROUP: usize = 50;
+const TYPING_INDICATOR_TTL_SECS: u64 = 5;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const MIN_MESSAGE_TTL_SECS: u64 = 10;
+const MAX_POLL_OPTIONS: usize = 10;
+const DIGEST_MOST_ACTIVE_LEN: usize = 3;
+const DIGEST_RETENTION_DAYS: u64 = 30;
+const GROUP_STATS_ROLLUP_SECS: u64 = 10 * 60;
+const GROUP_STATS_RETENTION_DAYS: u32 = 90;
+const STREAK_RESET_SWEEP_SECS: u64 = 60 * 60;
+const FOUNDER_MEMBER_LIMIT: u32 = 10;
+const HUNDRED_MESSAGES_BADGE_THRESHOLD: u64 = 100;
+const MAX_BADGE_NAME_LEN: usize = 50;
+const MAX_BADGES_PER_GROUP: usize = 50;
+const MAX_EVENT_TITLE_LEN: usize = 200;
+const EVENT_REMINDER_LEAD_SECS: u64 = 15 * 60;
+const MAX_ATTACHMENTS_PER_MESSAGE: usize = 10;
+const MAX_ATTACHMENT_SIZE: u64 = 25 * 1024 * 1024;
+const MAX_SEARCH_RESULTS: usize = 50;
+const MAX_USER_SEARCH_RESULTS: usize = 20;
+const MAX_IMPORT_BATCH: usize = 500;
+const MAX_CROSSPOST_TARGETS: usize = 5;
+const CLIENT_ID_WINDOW_SECS: u64 = 10 * 60;
+const MAX_REPORT_REASON_LEN: usize = 500;
+const MAX_MUTE_SECS: u64 = 28 * SECONDS_PER_DAY;
+const MUTE_EXPIRY_SWEEP_SECS: u64 = 30;
+const MAX_REMINDER_SECS: u64 = 365 * SECONDS_PER_DAY;
+const MAX_REMINDERS_PER_USER: usize = 25;
+const MAX_RECEIPT_GROUP_SIZE: u32 = 32;
+const PRESENCE_TIMEOUT_SECS: u64 = 60;
+const PRESENCE_SWEEP_SECS: u64 = 15;
+const MAX_WARNING_REASON_LEN: usize = 500;
+const STRIKE_MUTE_SECS: u64 = SECONDS_PER_DAY;
+const MAX_WEBHOOKS_PER_GROUP: usize = 10;
+const MIN_WEBHOOK_SECRET_LEN: usize = 16;
+const WEBHOOK_EVENT_MESSAGE_SENT: u32 = 1 << 0;
+const WEBHOOK_EVENT_ALL: u32 = WEBHOOK_EVENT_MESSAGE_SENT;
+const MAX_EXPORT_CHUNK_LEN: usize = 64 * 1024;
+const MAX_CIPHERTEXT_LEN: usize = 64 * 1024;
+const MAX_NONCE_LEN: usize = 64;
+const MAX_KEY_ID_LEN: usize = 64;
+const MAX_WRAPPED_KEY_LEN: usize = 1024;
+const MAX_DESCRIPTION_LEN: usize = 1000;
+const MAX_TOPIC_LEN: usize = 200;
+const MAX_TOPIC_NAME_LEN: usize = 64;
+const MAX_TOPICS_PER_GROUP: usize = 50;
+const MAX_URL_LEN: usize = 2048;
+const MAX_BIO_LEN: usize = 500;
+const MAX_STATUS_LEN: usize = 100;
+const MAX_LOCALE_LEN: usize = 35;
+const MAX_FOLDER_NAME_LEN: usize = 32;
+const MAX_FOLDERS_PER_USER: usize = 20;
+const MAX_KARMA_AWARD: u32 = 10;
+const MAX_KARMA_AWARDS_PER_DAY: usize = 20;
+const MAX_KARMA_REASON_LEN: usize = 200;
+const MAX_NICKNAME_LEN: usize = 32;
+const MAX_TAG_LEN: usize = 24;
+const MAX_TAGS_PER_GROUP: usize = 10;
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz23456789";
+
+fn random_code(ctx: &ReducerContext, len: usize) -> String {
+    (0..len)
+        .map(|_| CODE_ALPHABET[ctx.random::<u32>() as usize % CODE_ALPHABET.len()] as char)
+        .collect()
+}
+
+fn random_digits(ctx: &ReducerContext, len: usize) -> String {
+    (0..len)
+        .map(|_| char::from(b'0' + (ctx.random::<u32>() % 10) as u8))
+        .collect()
+}
+
+fn seconds_ago(ctx: &ReducerContext, secs: u64) -> Timestamp {
+    Timestamp::from_micros_since_unix_epoch(ctx.timestamp.to_micros_since_unix_epoch() - secs as i64 * 1_000_000)
+}
+
+fn seconds_before(timestamp: Timestamp, secs: u64) -> Timestamp {
+    Timestamp::from_micros_since_unix_epoch(timestamp.to_micros_since_unix_epoch() - secs as i64 * 1_000_000)
+}
+
+fn seconds_from_now(ctx: &ReducerContext, secs: u64) -> Timestamp {
+    Timestamp::from_micros_since_unix_epoch(ctx.timestamp.to_micros_since_unix_epoch() + secs as i64 * 1_000_000)
+}
+
+// removes a membership together with the member's per-group state
+fn remove_member(ctx: &ReducerContext, membership: GroupChatMembership) {
+    for read_state in ctx.db.read_state().user_and_groupchat().filter((membership.identity, &membership.groupchat_id)) {
+        ctx.db.read_state().id().delete(read_state.id);
+    }
+    for pref in ctx.db.notification_pref().user_and_groupchat().filter((membership.identity, &membership.groupchat_id)) {
+        ctx.db.notification_pref().id().delete(pref.id);
+    }
+    for draft in ctx.db.draft().user_and_groupchat().filter((membership.identity, &membership.groupchat_id)) {
+        ctx.db.draft().id().delete(draft.id);
+    }
+    for entry in ctx.db.folder_membership().identity().filter(membership.identity).filter(|entry| entry.groupchat_id == membership.groupchat_id) {
+        ctx.db.folder_membership().id().delete(entry.id);
+    }
+    for key in ctx.db.group_key().recipient().filter(membership.identity).filter(|key| key.groupchat_id == membership.groupchat_id) {
+        ctx.db.group_key().id().delete(key.id);
+    }
+    let online = is_online(ctx, membership.identity);
+    if let Some(groupchat) = ctx.db.groupchat().id().find(&membership.groupchat_id) {
+        ctx.db.groupchat().id().update(GroupChat {
+            member_count: groupchat.member_count.saturating_sub(1),
+            online_count: groupchat.online_count.saturating_sub(online as u32),
+            key_rotation_pending: groupchat.key_epoch > 0,
+            ..groupchat
+        });
+    }
+    ctx.db.groupchat_membership().id().delete(membership.id);
+}
+
+// sliding window limit of `rate_limit_max_messages` per `rate_limit_window_secs` for each identity
+fn check_rate_limit(ctx: &ReducerContext) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    let Config { rate_limit_max_messages, rate_limit_window_secs, bot_rate_limit_max_messages, .. } = config(ctx);
+    let is_bot = ctx.db.user().identity().find(sender).is_some_and(|user| user.is_bot);
+    let rate_limit_max_messages = if is_bot { bot_rate_limit_max_messages } else { rate_limit_max_messages };
+    let window_start = seconds_ago(ctx, rate_limit_window_secs as u64);
+    let mut recent_sends: Vec<Timestamp> = ctx.db.rate_limit().identity().find(sender)
+        .map(|rate_limit| rate_limit.recent_sends)
+        .unwrap_or_default();
+    recent_sends.retain(|sent| *sent > window_start);
+    if recent_sends.len() >= rate_limit_max_messages as usize {
+        return Err(format!(
+            "Rate limit exceeded, at most {rate_limit_max_messages} messages per {rate_limit_window_secs} seconds are allowed"
+        ));
+    }
+    recent_sends.push(ctx.timestamp);
+    let rate_limit = RateLimit {
+        identity: sender,
+        recent_sends
+    };
+    if ctx.db.rate_limit().identity().find(sender).is_some() {
+        ctx.db.rate_limit().identity().update(rate_limit);
     } else {
-        Err("Cannot join group chat for unknown user".to_string())
+        ctx.db.rate_limit().insert(rate_limit);
     }
+    Ok(())
+}
+
+fn audit(ctx: &ReducerContext, groupchat_id: &str, action: AuditAction, target: Option<Identity>, details: Option<String>) {
+    ctx.db.audit_log().insert(AuditLog {
+        id: 0,
+        actor: account_of(ctx, ctx.sender),
+        action,
+        target,
+        groupchat_id: groupchat_id.to_string(),
+        timestamp: ctx.timestamp,
+        details
+    });
+}
+
+fn default_config() -> Config {
+    Config {
+        id: 0,
+        max_message_len: 2000,
+        max_name_len: 32,
+        max_group_name_len: 64,
+        max_pins_per_group: 50,
+        rate_limit_max_messages: 10,
+        rate_limit_window_secs: 10,
+        bot_rate_limit_max_messages: 50,
+        unsend_window_secs: 120,
+        max_groups_created_per_hour: 5,
+        max_groups_owned: 50,
+        max_memberships_per_user: 500,
+        tos_version: 0
+    }
+}
+
+fn config(ctx: &ReducerContext) -> Config {
+    ctx.db.config().id().find(0).unwrap_or_else(default_config)
 }
 
 #[spacetimedb::reducer]
-pub fn send_message(ctx: &ReducerContext, groupchat: String, text: String) -> Result<(), String> {
-    let text = validate_message(text)?;
-    // check if groupchat exists and if membership exists for this user in this groupchat
-    if ctx.db.groupchat().id().find(&groupchat).is_none() {
-        return Err("Group chat does not exist".to_string());
+pub fn update_config(ctx: &ReducerContext, config: Config) -> Result<(), String> {
+    require_superadmin(ctx)?;
+    if config.max_message_len == 0
+        || config.max_name_len == 0
+        || config.max_group_name_len == 0
+        || config.rate_limit_max_messages == 0
+        || config.rate_limit_window_secs == 0
+        || config.bot_rate_limit_max_messages == 0
+        || config.max_groups_created_per_hour == 0
+        || config.max_groups_owned == 0
+        || config.max_memberships_per_user == 0
+    {
+        return Err("Config limits must be greater than zero".to_string());
     }
-    if ctx.db.groupchat_membership().user_and_groupchat().filter((ctx.sender, &groupchat)).next().is_none() {
-        return Err("User is not a member of this group chat".to_string());
+    let config = Config { id: 0, ..config };
+    if ctx.db.config().id().find(0).is_some() {
+        ctx.db.config().id().update(config);
+    } else {
+        ctx.db.config().insert(config);
     }
-    ctx.db.message().insert(Message {
-        sender: ctx.sender,
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn broadcast(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    require_superadmin(ctx)?;
+    let text = validate_message(ctx, None, text)?;
+    audit(ctx, MODULE_AUDIT_GROUPCHAT_ID, AuditAction::Broadcast, None, Some(text.clone()));
+    ctx.db.announcement().insert(Announcement {
+        id: 0,
         text,
-        sent: ctx.timestamp,
-        groupchat_id: groupchat
+        created_by: sender,
+        created_at: ctx.timestamp
     });
     Ok(())
 }
 
+fn require_superadmin(ctx: &ReducerContext) -> Result<(), String> {
+    let sender = acting_identity(ctx)?;
+    if ctx.db.superadmin().identity().find(sender).is_none() {
+        return Err("Only superadmins can do this".to_string());
+    }
+    Ok(())
+}
+
+fn display_name(ctx: &ReducerContext, identity: Identity) -> String {
+    ctx.db.user().identity().find(identity)
+        .and_then(|user| user.name)
+        .unwrap_or_else(|| identity.to_abbreviated_hex().to_string())
+}
+
+// system messages are sent by the module itself so clients can tell them apart from user messages
+fn post_system_message(ctx: &ReducerContext, groupchat_id: String, text: String) {
+    post_message(ctx, Message {
+        kind: MessageKind::System,
+        ..new_message(ctx, ctx.identity(), groupchat_id, text)
+    });
+}
+
+// hard-deletes a message together with everything that references it
+fn purge_message(ctx: &ReducerContext, message_id: u64) {
+    let Some(message) = ctx.db.message().id().find(message_id) else {
+        return;
+    };
+    if let Some(poll_id) = message.poll_id {
+        delete_poll(ctx, poll_id);
+    }
+    if let Some(groupchat) = ctx.db.groupchat().id().find(&message.groupchat_id) {
+        ctx.db.groupchat().id().update(GroupChat {
+            message_count: groupchat.message_count.saturating_sub(1),
+            ..groupchat
+        });
+    }
+    for reaction in ctx.db.reaction().message_id().filter(message_id) {
+        ctx.db.reaction().id().delete(reaction.id);
+    }
+    for reaction_count in ctx.db.reaction_count().message_id().filter(message_id) {
+        ctx.db.reaction_count().id().delete(reaction_count.id);
+    }
+    for mention in ctx.db.mention().message_id().filter(message_id) {
+        ctx.db.mention().id().delete(mention.id);
+    }
+    for attachment in ctx.db.attachment().message_id().filter(message_id) {
+        ctx.db.attachment().id().delete(attachment.id);
+    }
+    for edit in ctx.db.message_edit().message_id().filter(message_id) {
+        ctx.db.message_edit().id().delete(edit.id);
+    }
+    clear_translations(ctx, message_id);
+    for report in ctx.db.report().message_id().filter(message_id) {
+        ctx.db.report().id().delete(report.id);
+    }
+    for receipt in ctx.db.message_receipt().message_id().filter(message_id) {
+        ctx.db.message_receipt().id().delete(receipt.id);
+    }
+    for bookmark in ctx.db.bookmark().message_id().filter(message_id) {
+        ctx.db.bookmark().id().delete(bookmark.id);
+    }
+    ctx.db.pinned_message().message_id().delete(message_id);
+    ctx.db.thread_meta().root_message_id().delete(message_id);
+    ctx.db.message().id().delete(message_id);
+}
+
+fn new_message(ctx: &ReducerContext, sender: Identity, groupchat_id: String, text: String) -> Message {
+    Message {
+        id: 0,
+        sender,
+        text,
+        sent: ctx.timestamp,
+        groupchat_id,
+        edited_at: None,
+        deleted: false,
+        reply_to: None,
+        seq: 0,
+        poll_id: None,
+        forwarded_from: None,
+        original_sender: None,
+        imported_sender: None,
+        kind: MessageKind::UserText,
+        client_id: None,
+        topic_id: None,
+        lang: None,
+        ciphertext: None,
+        nonce: None,
+        key_id: None,
+        day: day_of(ctx.timestamp)
+    }
+}
+
+// inserts a message and keeps all derived per-message state in sync
+fn post_message(ctx: &ReducerContext, mut message: Message) -> Message {
+    if let Some(groupchat) = ctx.db.groupchat().id().find(&message.groupchat_id) {
+        message.seq = groupchat.next_seq;
+        ctx.db.groupchat().id().update(GroupChat {
+            next_seq: groupchat.next_seq + 1,
+            last_message_at: message.sent,
+            message_count: groupchat.message_count + 1,
+            has_live_messages: groupchat.has_live_messages || message.kind == MessageKind::UserText,
+            ..groupchat
+        });
+    }
+    let message = ctx.db.message().insert(message);
+    if let Some(membership) = find_membership(ctx, message.sender, &message.groupchat_id) {
+        ctx.db.groupchat_membership().id().update(GroupChatMembership {
+            last_posted_at: Some(message.sent),
+            ..membership
+        });
+        increment_member_stats(ctx, &message);
+        update_streak(ctx, &message);
+    }
+    // sending a message ends the sender's typing indicator
+    if let Some(indicator) = ctx.db.typing_indicator().groupchat_and_user().filter((&message.groupchat_id, message.sender)).next() {
+        ctx.db.typing_indicator().id().delete(indicator.id);
+    }
+    for membership in ctx.db.groupchat_membership().groupchat_id().filter(&message.groupchat_id) {
+        if membership.identity == message.sender {
+            continue;
+        }
+        let read_state = read_state_for(ctx, membership.identity, &message.groupchat_id);
+        ctx.db.read_state().id().update(ReadState {
+            unread_count: read_state.unread_count + 1,
+            ..read_state
+        });
+    }
+    insert_mentions(ctx, &message);
+    enqueue_webhooks(ctx, &message.groupchat_id, WEBHOOK_EVENT_MESSAGE_SENT, message.id);
+    if let Some(root_message_id) = message.reply_to {
+        if let Some(thread) = ctx.db.thread_meta().root_message_id().find(root_message_id) {
+            ctx.db.thread_meta().root_message_id().update(ThreadMeta {
+                reply_count: thread.reply_count + 1,
+                last_reply_at: message.sent,
+                ..thread
+            });
+        } else {
+            ctx.db.thread_meta().insert(ThreadMeta {
+                root_message_id,
+                reply_count: 1,
+                last_reply_at: message.sent
+            });
+        }
+    }
+    message
+}
+
+// extracts the names referenced by `@name` tokens in a message text
+fn parse_mentions(text: &str) -> Vec<&str> {
+    text.split_whitespace()
+        .filter_map(|token| token.strip_prefix('@'))
+        .map(|name| name.trim_end_matches(|c: char| c.is_ascii_punctuation() && c != '_' && c != '-'))
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+// resolves mentioned names to users who are members of the message's group chat
+fn insert_mentions(ctx: &ReducerContext, message: &Message) {
+    let mut mentioned: Vec<Identity> = parse_mentions(&message.text)
+        .into_iter()
+        .filter_map(|name| ctx.db.username().normalized_name().find(normalize_name(name)))
+        .map(|username| username.identity)
+        .collect();
+    mentioned.sort();
+    mentioned.dedup();
+    for identity in mentioned {
+        if identity == message.sender || find_membership(ctx, identity, &message.groupchat_id).is_none() {
+            continue;
+        }
+        // users don't get notified about mentions from people they blocked or in chats they muted
+        if is_blocked(ctx, identity, message.sender) || notification_mode(ctx, identity, &message.groupchat_id) == NotificationMode::None {
+            continue;
+        }
+        ctx.db.mention().insert(Mention {
+            id: 0,
+            mentioned_identity: identity,
+            message_id: message.id,
+            groupchat_id: message.groupchat_id.clone(),
+            seen: false
+        });
+        notify(ctx, identity, NotificationKind::Mention, Some(message.groupchat_id.clone()), Some(message.id));
+    }
+}
+
 #[spacetimedb::reducer(init)]
 // Called when the module is initially published
-pub fn init(_ctx: &ReducerContext) {}
+pub fn init(ctx: &ReducerContext) {
+    // the identity publishing the module becomes the first superadmin
+    ctx.db.superadmin().insert(Superadmin { identity: ctx.sender });
+    ctx.db.config().insert(default_config());
+    // new users are added to the lobby on their first connection, the publisher owns it
+    ctx.db.groupchat().insert(GroupChat {
+        discoverable: true,
+        ..new_groupchat(ctx, LOBBY_GROUPCHAT_ID.to_string())
+    });
+    add_member(ctx, ctx.sender, LOBBY_GROUPCHAT_ID.to_string(), MembershipRole::Owner);
+    ctx.db.typing_indicator_cleanup().insert(TypingIndicatorCleanup {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(1).into())
+    });
+    ctx.db.retention_cleanup().insert(RetentionCleanup {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(60 * 60).into())
+    });
+    ctx.db.message_expiry_sweep().insert(MessageExpirySweep {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(MIN_MESSAGE_TTL_SECS).into())
+    });
+    ctx.db.daily_digest_schedule().insert(DailyDigestSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(SECONDS_PER_DAY).into())
+    });
+    ctx.db.group_stats_rollup().insert(GroupStatsRollup {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(GROUP_STATS_ROLLUP_SECS).into())
+    });
+    ctx.db.streak_reset_sweep().insert(StreakResetSweep {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(STREAK_RESET_SWEEP_SECS).into())
+    });
+    ctx.db.presence_sweep().insert(PresenceSweep {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(PRESENCE_SWEEP_SECS).into())
+    });
+    ctx.db.mute_expiry_sweep().insert(MuteExpirySweep {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(MUTE_EXPIRY_SWEEP_SECS).into())
+    });
+}
 
 #[spacetimedb::reducer(client_connected)]
 pub fn identity_connected(ctx: &ReducerContext) {
-    if ctx.db.user().identity().find(ctx.sender).is_none() {
+    if let Some(device) = ctx.db.linked_device().device().find(ctx.sender) {
+        ctx.db.linked_device().device().update(LinkedDevice {
+            last_connected_at: ctx.timestamp,
+            ..device
+        });
+    }
+    let identity = account_of(ctx, ctx.sender);
+    if let Some(connection_id) = ctx.connection_id {
+        ctx.db.session().insert(Session {
+            connection_id,
+            identity,
+            device: ctx.sender,
+            connected_at: ctx.timestamp,
+            client_hint: None,
+            terminated_at: None
+        });
+    }
+    if let Some(user) = ctx.db.user().identity().find(identity) {
+        // If this is a returning user, mark them as online again.
+        let presence = connected_presence(ctx, identity);
+        update_presence_counts(ctx, identity, user.presence, presence);
+        ctx.db.user().identity().update(User {
+            presence,
+            last_seen: activity_timestamp(ctx, presence, user.last_seen),
+            last_active: activity_timestamp(ctx, presence, user.last_active),
+            ..user
+        });
+    } else {
         // If this is a new user, create a `User` row for the `Identity`,
         // which is online, but hasn't set a name.
         ctx.db.user().insert(User {
             name: None,
             identity: ctx.sender,
+            presence: Presence::Online,
+            last_seen: ctx.timestamp,
+            last_active: ctx.timestamp,
+            bio: None,
+            avatar_url: None,
+            status: None,
+            is_bot: false,
+            verified: false,
+            tos_accepted_version: 0,
         });
+        issue_challenge(ctx);
+        // the publisher already owns the lobby from `init` by the time they first connect
+        if let Some(lobby) = ctx.db.groupchat().id().find(LOBBY_GROUPCHAT_ID.to_string()) {
+            if find_membership(ctx, ctx.sender, &lobby.id).is_none() && check_can_join(ctx, ctx.sender, &lobby).is_ok() {
+                add_member(ctx, ctx.sender, lobby.id, MembershipRole::Member);
+            }
+        }
+        find_or_create_conversation(ctx, ctx.sender, ctx.sender);
     }
 }
 
 #[spacetimedb::reducer(client_disconnected)]
 pub fn identity_disconnected(ctx: &ReducerContext) {
-    // remove the user and all their group chat memberships when they disconnect
-    for membership in ctx.db.groupchat_membership().identity().filter(ctx.sender) {
-        ctx.db.groupchat_membership().id().delete(membership.id);
+    // keep the user and their group chat memberships around, only mark them as offline
+    if let Some(connection_id) = ctx.connection_id {
+        ctx.db.session().connection_id().delete(connection_id);
+    }
+    let identity = account_of(ctx, ctx.sender);
+    // the user stays online while another device or tab is still connected, including terminated
+    // sessions whose client hasn't disconnected yet
+    if ctx.db.session().identity().filter(identity).next().is_some() {
+        return;
+    }
+    if !mark_offline(ctx, identity) {
+        log::warn!("Disconnect event for unknown user with identity {:?}", ctx.sender);
     }
-    if let Some(user) = ctx.db.user().identity().find(ctx.sender) {
-        ctx.db.user().identity().delete(user.identity);
+}
+
+// shows a user whose last session is gone as offline, returns false if the user doesn't exist
+fn mark_offline(ctx: &ReducerContext, identity: Identity) -> bool {
+    let Some(user) = ctx.db.user().identity().find(identity) else {
+        return false;
+    };
+    if user.presence != Presence::Offline {
+        update_online_counts(ctx, identity, false);
     }
-}
\ No newline at end of file
+    let last_seen = activity_timestamp(ctx, connected_presence(ctx, identity), user.last_seen);
+    ctx.db.user().identity().update(User {
+        presence: Presence::Offline,
+        last_seen,
+        ..user
+    });
+    true
+}
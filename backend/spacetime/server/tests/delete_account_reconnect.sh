@@ -0,0 +1,23 @@
+#!/bin/sh
+# deletes the publisher's account and reconnects with the same identity,
+# nothing the account had before the deletion may come back
+# run from backend/spacetime/server against a local spacetime instance
+set -e
+DB=groupchat-delete-account-test
+NOTE="note written before the account was deleted"
+
+spacetime build
+cd target/wasm32-unknown-unknown/release/ && spacetime publish --delete-data -y -s local --bin-path spacetime_module.wasm $DB && cd -
+
+spacetime call -s local $DB send_note_to_self "\"$NOTE\""
+# the publisher owns the lobby and owners can't delete their account
+spacetime call -s local $DB delete_groupchat '"Lobby"'
+spacetime call -s local $DB delete_account
+# connecting again signs the same identity up as a fresh user
+spacetime call -s local $DB heartbeat
+
+if spacetime sql -s local $DB "SELECT * FROM dm_message" | grep -q "$NOTE"; then
+    echo "notes to self survived the account deletion"
+    exit 1
+fi
+echo "ok"
@@ -27,11 +27,21 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
+import { MembershipRole } from "./membership_role_type";
+// Mark import as potentially unused
+declare type __keep_MembershipRole = MembershipRole;
 
 export type GroupChatMembership = {
   id: number,
   identity: __Identity,
   groupchatId: string,
+  role: MembershipRole,
+  moderator: boolean,
+  nickname: string | undefined,
+  lastPostedAt: __Timestamp | undefined,
+  joinedAt: __Timestamp,
+  pinned: boolean,
+  sortOrder: number,
 };
 let _cached_GroupChatMembership_type_value: __AlgebraicTypeType | null = null;
 
@@ -50,6 +60,13 @@ export const GroupChatMembership = {
       { name: "id", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "identity", algebraicType: __AlgebraicTypeValue.createIdentityType() },
       { name: "groupchatId", algebraicType: __AlgebraicTypeValue.String },
+      { name: "role", algebraicType: MembershipRole.getTypeScriptAlgebraicType() },
+      { name: "moderator", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "nickname", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "lastPostedAt", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.createTimestampType()) },
+      { name: "joinedAt", algebraicType: __AlgebraicTypeValue.createTimestampType() },
+      { name: "pinned", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "sortOrder", algebraicType: __AlgebraicTypeValue.U32 },
     );
     return _cached_GroupChatMembership_type_value;
   },
@@ -27,9 +27,47 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
+import { DeletedAccountPolicy } from "./deleted_account_policy_type";
+// Mark import as potentially unused
+declare type __keep_DeletedAccountPolicy = DeletedAccountPolicy;
+import { GroupVisibility } from "./group_visibility_type";
+// Mark import as potentially unused
+declare type __keep_GroupVisibility = GroupVisibility;
+import { PostPolicy } from "./post_policy_type";
+// Mark import as potentially unused
+declare type __keep_PostPolicy = PostPolicy;
+import { StrikeEscalation } from "./strike_escalation_type";
+// Mark import as potentially unused
+declare type __keep_StrikeEscalation = StrikeEscalation;
 
 export type GroupChat = {
   id: string,
+  name: string,
+  slug: string,
+  createdBy: __Identity,
+  visibility: GroupVisibility,
+  nextSeq: bigint,
+  lastMessageAt: __Timestamp,
+  messageCount: bigint,
+  retentionDays: number | undefined,
+  description: string | undefined,
+  topic: string | undefined,
+  avatarUrl: string | undefined,
+  slowmodeSeconds: number,
+  postPolicy: PostPolicy,
+  archived: boolean,
+  memberCount: number,
+  onlineCount: number,
+  discoverable: boolean,
+  nsfw: boolean,
+  maxMembers: number | undefined,
+  messageTtlSeconds: bigint | undefined,
+  strikeThreshold: number,
+  strikeEscalation: StrikeEscalation,
+  deletedAccountPolicy: DeletedAccountPolicy,
+  keyEpoch: bigint,
+  keyRotationPending: boolean,
+  hasLiveMessages: boolean,
 };
 let _cached_GroupChat_type_value: __AlgebraicTypeType | null = null;
 
@@ -46,6 +84,32 @@ export const GroupChat = {
     _cached_GroupChat_type_value = __AlgebraicTypeValue.Product({ elements: [] });
     _cached_GroupChat_type_value.value.elements.push(
       { name: "id", algebraicType: __AlgebraicTypeValue.String },
+      { name: "name", algebraicType: __AlgebraicTypeValue.String },
+      { name: "slug", algebraicType: __AlgebraicTypeValue.String },
+      { name: "createdBy", algebraicType: __AlgebraicTypeValue.createIdentityType() },
+      { name: "visibility", algebraicType: GroupVisibility.getTypeScriptAlgebraicType() },
+      { name: "nextSeq", algebraicType: __AlgebraicTypeValue.U64 },
+      { name: "lastMessageAt", algebraicType: __AlgebraicTypeValue.createTimestampType() },
+      { name: "messageCount", algebraicType: __AlgebraicTypeValue.U64 },
+      { name: "retentionDays", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32) },
+      { name: "description", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "topic", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "avatarUrl", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "slowmodeSeconds", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "postPolicy", algebraicType: PostPolicy.getTypeScriptAlgebraicType() },
+      { name: "archived", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "memberCount", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "onlineCount", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "discoverable", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "nsfw", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "maxMembers", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32) },
+      { name: "messageTtlSeconds", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U64) },
+      { name: "strikeThreshold", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "strikeEscalation", algebraicType: StrikeEscalation.getTypeScriptAlgebraicType() },
+      { name: "deletedAccountPolicy", algebraicType: DeletedAccountPolicy.getTypeScriptAlgebraicType() },
+      { name: "keyEpoch", algebraicType: __AlgebraicTypeValue.U64 },
+      { name: "keyRotationPending", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "hasLiveMessages", algebraicType: __AlgebraicTypeValue.Bool },
     );
     return _cached_GroupChat_type_value;
   },
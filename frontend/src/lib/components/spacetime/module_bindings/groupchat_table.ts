@@ -79,6 +79,28 @@ export class GroupchatTableHandle<TableName extends string> implements __TableHa
       }
     },
   };
+  /**
+   * Access to the `slug` unique index on the table `groupchat`,
+   * which allows point queries on the field of the same name
+   * via the [`GroupchatSlugUnique.find`] method.
+   *
+   * Users are encouraged not to explicitly reference this type,
+   * but to directly chain method calls,
+   * like `ctx.db.groupchat.slug().find(...)`.
+   *
+   * Get a handle on the `slug` unique index on the table `groupchat`.
+   */
+  slug = {
+    // Find the subscribed row whose `slug` column value is equal to `col_val`,
+    // if such a row is present in the client cache.
+    find: (col_val: string): GroupChat | undefined => {
+      for (let row of this.tableCache.iter()) {
+        if (__deepEqual(row.slug, col_val)) {
+          return row;
+        }
+      }
+    },
+  };
 
   onInsert = (cb: (ctx: EventContext, row: GroupChat) => void) => {
     return this.tableCache.onInsert(cb);
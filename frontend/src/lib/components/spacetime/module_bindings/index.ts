@@ -31,41 +31,960 @@ import {
 } from "spacetimedb";
 
 // Import and reexport all reducer arg types
+import { AcceptFriendRequest } from "./accept_friend_request_reducer.ts";
+export { AcceptFriendRequest };
+import { AcceptTos } from "./accept_tos_reducer.ts";
+export { AcceptTos };
+import { AckDelivery } from "./ack_delivery_reducer.ts";
+export { AckDelivery };
+import { AckMention } from "./ack_mention_reducer.ts";
+export { AckMention };
+import { AddBannedWord } from "./add_banned_word_reducer.ts";
+export { AddBannedWord };
+import { AddChatToFolder } from "./add_chat_to_folder_reducer.ts";
+export { AddChatToFolder };
+import { AddCustomEmoji } from "./add_custom_emoji_reducer.ts";
+export { AddCustomEmoji };
+import { AddGroupTag } from "./add_group_tag_reducer.ts";
+export { AddGroupTag };
+import { AddReaction } from "./add_reaction_reducer.ts";
+export { AddReaction };
+import { AddWebhook } from "./add_webhook_reducer.ts";
+export { AddWebhook };
+import { ApplyRetentionPolicies } from "./apply_retention_policies_reducer.ts";
+export { ApplyRetentionPolicies };
+import { ApproveJoinRequest } from "./approve_join_request_reducer.ts";
+export { ApproveJoinRequest };
+import { ArchiveGroupchat } from "./archive_groupchat_reducer.ts";
+export { ArchiveGroupchat };
+import { ArchiveTopic } from "./archive_topic_reducer.ts";
+export { ArchiveTopic };
+import { AwardBadge } from "./award_badge_reducer.ts";
+export { AwardBadge };
+import { AwardPoints } from "./award_points_reducer.ts";
+export { AwardPoints };
+import { BanMember } from "./ban_member_reducer.ts";
+export { BanMember };
+import { BlockUser } from "./block_user_reducer.ts";
+export { BlockUser };
+import { BookmarkMessage } from "./bookmark_message_reducer.ts";
+export { BookmarkMessage };
+import { Broadcast } from "./broadcast_reducer.ts";
+export { Broadcast };
+import { CancelEvent } from "./cancel_event_reducer.ts";
+export { CancelEvent };
+import { ClearDraft } from "./clear_draft_reducer.ts";
+export { ClearDraft };
+import { ClearNotifications } from "./clear_notifications_reducer.ts";
+export { ClearNotifications };
+import { ClosePoll } from "./close_poll_reducer.ts";
+export { ClosePoll };
+import { CreateBadge } from "./create_badge_reducer.ts";
+export { CreateBadge };
+import { CreateEvent } from "./create_event_reducer.ts";
+export { CreateEvent };
+import { CreateFolder } from "./create_folder_reducer.ts";
+export { CreateFolder };
 import { CreateGroupchat } from "./create_groupchat_reducer.ts";
 export { CreateGroupchat };
+import { CreateInvite } from "./create_invite_reducer.ts";
+export { CreateInvite };
+import { CreateLinkToken } from "./create_link_token_reducer.ts";
+export { CreateLinkToken };
+import { CreatePoll } from "./create_poll_reducer.ts";
+export { CreatePoll };
+import { CreateTopic } from "./create_topic_reducer.ts";
+export { CreateTopic };
+import { CrosspostMessage } from "./crosspost_message_reducer.ts";
+export { CrosspostMessage };
+import { DeclineFriendRequest } from "./decline_friend_request_reducer.ts";
+export { DeclineFriendRequest };
+import { DeleteAccount } from "./delete_account_reducer.ts";
+export { DeleteAccount };
+import { DeleteFolder } from "./delete_folder_reducer.ts";
+export { DeleteFolder };
+import { DeleteGroupchat } from "./delete_groupchat_reducer.ts";
+export { DeleteGroupchat };
+import { DeleteMessage } from "./delete_message_reducer.ts";
+export { DeleteMessage };
+import { DemoteMember } from "./demote_member_reducer.ts";
+export { DemoteMember };
+import { DismissReport } from "./dismiss_report_reducer.ts";
+export { DismissReport };
+import { EditMessage } from "./edit_message_reducer.ts";
+export { EditMessage };
+import { ExpireDisappearingMessages } from "./expire_disappearing_messages_reducer.ts";
+export { ExpireDisappearingMessages };
+import { ExpireMutes } from "./expire_mutes_reducer.ts";
+export { ExpireMutes };
+import { FireReminder } from "./fire_reminder_reducer.ts";
+export { FireReminder };
+import { ForwardMessage } from "./forward_message_reducer.ts";
+export { ForwardMessage };
+import { GenerateDailyDigests } from "./generate_daily_digests_reducer.ts";
+export { GenerateDailyDigests };
+import { Heartbeat } from "./heartbeat_reducer.ts";
+export { Heartbeat };
 import { IdentityConnected } from "./identity_connected_reducer.ts";
 export { IdentityConnected };
 import { IdentityDisconnected } from "./identity_disconnected_reducer.ts";
 export { IdentityDisconnected };
+import { ImportMessages } from "./import_messages_reducer.ts";
+export { ImportMessages };
+import { JoinByCode } from "./join_by_code_reducer.ts";
+export { JoinByCode };
+import { JoinBySlug } from "./join_by_slug_reducer.ts";
+export { JoinBySlug };
 import { JoinGroupchat } from "./join_groupchat_reducer.ts";
 export { JoinGroupchat };
+import { JoinViaInvite } from "./join_via_invite_reducer.ts";
+export { JoinViaInvite };
+import { KickMember } from "./kick_member_reducer.ts";
+export { KickMember };
+import { LeaveGroupchat } from "./leave_groupchat_reducer.ts";
+export { LeaveGroupchat };
+import { LinkDevice } from "./link_device_reducer.ts";
+export { LinkDevice };
+import { MarkNotificationRead } from "./mark_notification_read_reducer.ts";
+export { MarkNotificationRead };
+import { MarkRead } from "./mark_read_reducer.ts";
+export { MarkRead };
+import { MuteMember } from "./mute_member_reducer.ts";
+export { MuteMember };
+import { PinMessage } from "./pin_message_reducer.ts";
+export { PinMessage };
+import { PromoteMember } from "./promote_member_reducer.ts";
+export { PromoteMember };
+import { PublishGroupKey } from "./publish_group_key_reducer.ts";
+export { PublishGroupKey };
+import { PurgeTypingIndicators } from "./purge_typing_indicators_reducer.ts";
+export { PurgeTypingIndicators };
+import { RegenerateJoinCode } from "./regenerate_join_code_reducer.ts";
+export { RegenerateJoinCode };
+import { RegisterBot } from "./register_bot_reducer.ts";
+export { RegisterBot };
+import { RejectJoinRequest } from "./reject_join_request_reducer.ts";
+export { RejectJoinRequest };
+import { RemindMe } from "./remind_me_reducer.ts";
+export { RemindMe };
+import { RemoveBannedWord } from "./remove_banned_word_reducer.ts";
+export { RemoveBannedWord };
+import { RemoveBookmark } from "./remove_bookmark_reducer.ts";
+export { RemoveBookmark };
+import { RemoveChatFromFolder } from "./remove_chat_from_folder_reducer.ts";
+export { RemoveChatFromFolder };
+import { RemoveCustomEmoji } from "./remove_custom_emoji_reducer.ts";
+export { RemoveCustomEmoji };
+import { RemoveFriend } from "./remove_friend_reducer.ts";
+export { RemoveFriend };
+import { RemoveGroupTag } from "./remove_group_tag_reducer.ts";
+export { RemoveGroupTag };
+import { RemoveReaction } from "./remove_reaction_reducer.ts";
+export { RemoveReaction };
+import { RemoveWebhook } from "./remove_webhook_reducer.ts";
+export { RemoveWebhook };
+import { ReorderChats } from "./reorder_chats_reducer.ts";
+export { ReorderChats };
+import { ReorderFolders } from "./reorder_folders_reducer.ts";
+export { ReorderFolders };
+import { ReplyToMessage } from "./reply_to_message_reducer.ts";
+export { ReplyToMessage };
+import { ReportMessage } from "./report_message_reducer.ts";
+export { ReportMessage };
+import { RequestDataExport } from "./request_data_export_reducer.ts";
+export { RequestDataExport };
+import { RequestToJoin } from "./request_to_join_reducer.ts";
+export { RequestToJoin };
+import { ResetBrokenStreaks } from "./reset_broken_streaks_reducer.ts";
+export { ResetBrokenStreaks };
+import { ResetLeaderboard } from "./reset_leaderboard_reducer.ts";
+export { ResetLeaderboard };
+import { ResolveReport } from "./resolve_report_reducer.ts";
+export { ResolveReport };
+import { RevokeDevice } from "./revoke_device_reducer.ts";
+export { RevokeDevice };
+import { RevokeInvite } from "./revoke_invite_reducer.ts";
+export { RevokeInvite };
+import { RollUpGroupStats } from "./roll_up_group_stats_reducer.ts";
+export { RollUpGroupStats };
+import { Rsvp } from "./rsvp_reducer.ts";
+export { Rsvp };
+import { SaveDraft } from "./save_draft_reducer.ts";
+export { SaveDraft };
+import { SearchMessages } from "./search_messages_reducer.ts";
+export { SearchMessages };
+import { SearchUsers } from "./search_users_reducer.ts";
+export { SearchUsers };
+import { SendDm } from "./send_dm_reducer.ts";
+export { SendDm };
+import { SendEncryptedMessage } from "./send_encrypted_message_reducer.ts";
+export { SendEncryptedMessage };
+import { SendEventReminder } from "./send_event_reminder_reducer.ts";
+export { SendEventReminder };
+import { SendFriendRequest } from "./send_friend_request_reducer.ts";
+export { SendFriendRequest };
 import { SendMessage } from "./send_message_reducer.ts";
 export { SendMessage };
+import { SendMessageWithAttachments } from "./send_message_with_attachments_reducer.ts";
+export { SendMessageWithAttachments };
+import { SendNoteToSelf } from "./send_note_to_self_reducer.ts";
+export { SendNoteToSelf };
+import { SetChatPin } from "./set_chat_pin_reducer.ts";
+export { SetChatPin };
+import { SetDeletedAccountPolicy } from "./set_deleted_account_policy_reducer.ts";
+export { SetDeletedAccountPolicy };
+import { SetDiscoverable } from "./set_discoverable_reducer.ts";
+export { SetDiscoverable };
+import { SetGroupAvatar } from "./set_group_avatar_reducer.ts";
+export { SetGroupAvatar };
+import { SetGroupChatName } from "./set_group_chat_name_reducer.ts";
+export { SetGroupChatName };
+import { SetGroupDescription } from "./set_group_description_reducer.ts";
+export { SetGroupDescription };
+import { SetGroupNickname } from "./set_group_nickname_reducer.ts";
+export { SetGroupNickname };
+import { SetGroupTopic } from "./set_group_topic_reducer.ts";
+export { SetGroupTopic };
+import { SetGroupVisibility } from "./set_group_visibility_reducer.ts";
+export { SetGroupVisibility };
+import { SetMemberLimit } from "./set_member_limit_reducer.ts";
+export { SetMemberLimit };
+import { SetMessageTtl } from "./set_message_ttl_reducer.ts";
+export { SetMessageTtl };
 import { SetName } from "./set_name_reducer.ts";
 export { SetName };
+import { SetNotificationPref } from "./set_notification_pref_reducer.ts";
+export { SetNotificationPref };
+import { SetNsfw } from "./set_nsfw_reducer.ts";
+export { SetNsfw };
+import { SetPermission } from "./set_permission_reducer.ts";
+export { SetPermission };
+import { SetPostPolicy } from "./set_post_policy_reducer.ts";
+export { SetPostPolicy };
+import { SetPresence } from "./set_presence_reducer.ts";
+export { SetPresence };
+import { SetRetentionDays } from "./set_retention_days_reducer.ts";
+export { SetRetentionDays };
+import { SetSessionHint } from "./set_session_hint_reducer.ts";
+export { SetSessionHint };
+import { SetSlowmode } from "./set_slowmode_reducer.ts";
+export { SetSlowmode };
+import { SetStrikePolicy } from "./set_strike_policy_reducer.ts";
+export { SetStrikePolicy };
+import { SetVerified } from "./set_verified_reducer.ts";
+export { SetVerified };
+import { ShareGroupKey } from "./share_group_key_reducer.ts";
+export { ShareGroupKey };
+import { SolveChallenge } from "./solve_challenge_reducer.ts";
+export { SolveChallenge };
+import { StartTyping } from "./start_typing_reducer.ts";
+export { StartTyping };
+import { StoreTranslation } from "./store_translation_reducer.ts";
+export { StoreTranslation };
+import { SweepPresence } from "./sweep_presence_reducer.ts";
+export { SweepPresence };
+import { TerminateSession } from "./terminate_session_reducer.ts";
+export { TerminateSession };
+import { TransferOwnership } from "./transfer_ownership_reducer.ts";
+export { TransferOwnership };
+import { UnarchiveGroupchat } from "./unarchive_groupchat_reducer.ts";
+export { UnarchiveGroupchat };
+import { UnbanMember } from "./unban_member_reducer.ts";
+export { UnbanMember };
+import { UnblockUser } from "./unblock_user_reducer.ts";
+export { UnblockUser };
+import { UnmuteMember } from "./unmute_member_reducer.ts";
+export { UnmuteMember };
+import { UnpinMessage } from "./unpin_message_reducer.ts";
+export { UnpinMessage };
+import { UnsendMessage } from "./unsend_message_reducer.ts";
+export { UnsendMessage };
+import { UpdateConfig } from "./update_config_reducer.ts";
+export { UpdateConfig };
+import { UpdateProfile } from "./update_profile_reducer.ts";
+export { UpdateProfile };
+import { UpdateSettings } from "./update_settings_reducer.ts";
+export { UpdateSettings };
+import { Vote } from "./vote_reducer.ts";
+export { Vote };
+import { WarnMember } from "./warn_member_reducer.ts";
+export { WarnMember };
 
 // Import and reexport all table handle types
+import { AnnouncementTableHandle } from "./announcement_table.ts";
+export { AnnouncementTableHandle };
+import { AttachmentTableHandle } from "./attachment_table.ts";
+export { AttachmentTableHandle };
+import { AuditLogTableHandle } from "./audit_log_table.ts";
+export { AuditLogTableHandle };
+import { AwardedBadgeTableHandle } from "./awarded_badge_table.ts";
+export { AwardedBadgeTableHandle };
+import { BadgeTableHandle } from "./badge_table.ts";
+export { BadgeTableHandle };
+import { BannedWordTableHandle } from "./banned_word_table.ts";
+export { BannedWordTableHandle };
+import { BlockTableHandle } from "./block_table.ts";
+export { BlockTableHandle };
+import { BookmarkTableHandle } from "./bookmark_table.ts";
+export { BookmarkTableHandle };
+import { ChallengeTableHandle } from "./challenge_table.ts";
+export { ChallengeTableHandle };
+import { ChallengeAnswerTableHandle } from "./challenge_answer_table.ts";
+export { ChallengeAnswerTableHandle };
+import { ChatFolderTableHandle } from "./chat_folder_table.ts";
+export { ChatFolderTableHandle };
+import { ConfigTableHandle } from "./config_table.ts";
+export { ConfigTableHandle };
+import { CustomEmojiTableHandle } from "./custom_emoji_table.ts";
+export { CustomEmojiTableHandle };
+import { DailyDigestTableHandle } from "./daily_digest_table.ts";
+export { DailyDigestTableHandle };
+import { DailyDigestScheduleTableHandle } from "./daily_digest_schedule_table.ts";
+export { DailyDigestScheduleTableHandle };
+import { DataExportTableHandle } from "./data_export_table.ts";
+export { DataExportTableHandle };
+import { DirectConversationTableHandle } from "./direct_conversation_table.ts";
+export { DirectConversationTableHandle };
+import { DmMessageTableHandle } from "./dm_message_table.ts";
+export { DmMessageTableHandle };
+import { DraftTableHandle } from "./draft_table.ts";
+export { DraftTableHandle };
+import { EventReminderTableHandle } from "./event_reminder_table.ts";
+export { EventReminderTableHandle };
+import { EventRsvpTableHandle } from "./event_rsvp_table.ts";
+export { EventRsvpTableHandle };
+import { FolderMembershipTableHandle } from "./folder_membership_table.ts";
+export { FolderMembershipTableHandle };
+import { FriendRequestTableHandle } from "./friend_request_table.ts";
+export { FriendRequestTableHandle };
+import { FriendshipTableHandle } from "./friendship_table.ts";
+export { FriendshipTableHandle };
+import { GroupCreationLimitTableHandle } from "./group_creation_limit_table.ts";
+export { GroupCreationLimitTableHandle };
+import { GroupEventTableHandle } from "./group_event_table.ts";
+export { GroupEventTableHandle };
+import { GroupJoinCodeTableHandle } from "./group_join_code_table.ts";
+export { GroupJoinCodeTableHandle };
+import { GroupKeyTableHandle } from "./group_key_table.ts";
+export { GroupKeyTableHandle };
+import { GroupKeyEpochTableHandle } from "./group_key_epoch_table.ts";
+export { GroupKeyEpochTableHandle };
+import { GroupPermissionTableHandle } from "./group_permission_table.ts";
+export { GroupPermissionTableHandle };
+import { GroupStatsTableHandle } from "./group_stats_table.ts";
+export { GroupStatsTableHandle };
+import { GroupStatsRollupTableHandle } from "./group_stats_rollup_table.ts";
+export { GroupStatsRollupTableHandle };
+import { GroupTagTableHandle } from "./group_tag_table.ts";
+export { GroupTagTableHandle };
 import { GroupchatTableHandle } from "./groupchat_table.ts";
 export { GroupchatTableHandle };
+import { GroupchatBanTableHandle } from "./groupchat_ban_table.ts";
+export { GroupchatBanTableHandle };
+import { GroupchatInviteTableHandle } from "./groupchat_invite_table.ts";
+export { GroupchatInviteTableHandle };
 import { GroupchatMembershipTableHandle } from "./groupchat_membership_table.ts";
 export { GroupchatMembershipTableHandle };
+import { JoinCodeAttemptTableHandle } from "./join_code_attempt_table.ts";
+export { JoinCodeAttemptTableHandle };
+import { JoinRequestTableHandle } from "./join_request_table.ts";
+export { JoinRequestTableHandle };
+import { KarmaTableHandle } from "./karma_table.ts";
+export { KarmaTableHandle };
+import { KarmaTransactionTableHandle } from "./karma_transaction_table.ts";
+export { KarmaTransactionTableHandle };
+import { LinkTokenTableHandle } from "./link_token_table.ts";
+export { LinkTokenTableHandle };
+import { LinkedDeviceTableHandle } from "./linked_device_table.ts";
+export { LinkedDeviceTableHandle };
+import { MemberStatsTableHandle } from "./member_stats_table.ts";
+export { MemberStatsTableHandle };
+import { MentionTableHandle } from "./mention_table.ts";
+export { MentionTableHandle };
 import { MessageTableHandle } from "./message_table.ts";
 export { MessageTableHandle };
+import { MessageEditTableHandle } from "./message_edit_table.ts";
+export { MessageEditTableHandle };
+import { MessageExpirySweepTableHandle } from "./message_expiry_sweep_table.ts";
+export { MessageExpirySweepTableHandle };
+import { MessageReceiptTableHandle } from "./message_receipt_table.ts";
+export { MessageReceiptTableHandle };
+import { MuteTableHandle } from "./mute_table.ts";
+export { MuteTableHandle };
+import { MuteExpirySweepTableHandle } from "./mute_expiry_sweep_table.ts";
+export { MuteExpirySweepTableHandle };
+import { NotificationTableHandle } from "./notification_table.ts";
+export { NotificationTableHandle };
+import { NotificationPrefTableHandle } from "./notification_pref_table.ts";
+export { NotificationPrefTableHandle };
+import { PinnedMessageTableHandle } from "./pinned_message_table.ts";
+export { PinnedMessageTableHandle };
+import { PollTableHandle } from "./poll_table.ts";
+export { PollTableHandle };
+import { PollVoteTableHandle } from "./poll_vote_table.ts";
+export { PollVoteTableHandle };
+import { PresencePreferenceTableHandle } from "./presence_preference_table.ts";
+export { PresencePreferenceTableHandle };
+import { PresenceSweepTableHandle } from "./presence_sweep_table.ts";
+export { PresenceSweepTableHandle };
+import { RateLimitTableHandle } from "./rate_limit_table.ts";
+export { RateLimitTableHandle };
+import { ReactionTableHandle } from "./reaction_table.ts";
+export { ReactionTableHandle };
+import { ReactionCountTableHandle } from "./reaction_count_table.ts";
+export { ReactionCountTableHandle };
+import { ReadStateTableHandle } from "./read_state_table.ts";
+export { ReadStateTableHandle };
+import { ReminderTableHandle } from "./reminder_table.ts";
+export { ReminderTableHandle };
+import { ReportTableHandle } from "./report_table.ts";
+export { ReportTableHandle };
+import { RetentionCleanupTableHandle } from "./retention_cleanup_table.ts";
+export { RetentionCleanupTableHandle };
+import { SearchResultTableHandle } from "./search_result_table.ts";
+export { SearchResultTableHandle };
+import { SentClientIdTableHandle } from "./sent_client_id_table.ts";
+export { SentClientIdTableHandle };
+import { SessionTableHandle } from "./session_table.ts";
+export { SessionTableHandle };
+import { StreakTableHandle } from "./streak_table.ts";
+export { StreakTableHandle };
+import { StreakResetSweepTableHandle } from "./streak_reset_sweep_table.ts";
+export { StreakResetSweepTableHandle };
+import { SuperadminTableHandle } from "./superadmin_table.ts";
+export { SuperadminTableHandle };
+import { ThreadMetaTableHandle } from "./thread_meta_table.ts";
+export { ThreadMetaTableHandle };
+import { TopicTableHandle } from "./topic_table.ts";
+export { TopicTableHandle };
+import { TranslationCacheTableHandle } from "./translation_cache_table.ts";
+export { TranslationCacheTableHandle };
+import { TypingIndicatorTableHandle } from "./typing_indicator_table.ts";
+export { TypingIndicatorTableHandle };
+import { TypingIndicatorCleanupTableHandle } from "./typing_indicator_cleanup_table.ts";
+export { TypingIndicatorCleanupTableHandle };
 import { UserTableHandle } from "./user_table.ts";
 export { UserTableHandle };
+import { UserSearchResultTableHandle } from "./user_search_result_table.ts";
+export { UserSearchResultTableHandle };
+import { UserSettingsTableHandle } from "./user_settings_table.ts";
+export { UserSettingsTableHandle };
+import { UsernameTableHandle } from "./username_table.ts";
+export { UsernameTableHandle };
+import { WarningTableHandle } from "./warning_table.ts";
+export { WarningTableHandle };
+import { WebhookTableHandle } from "./webhook_table.ts";
+export { WebhookTableHandle };
+import { WebhookOutboxTableHandle } from "./webhook_outbox_table.ts";
+export { WebhookOutboxTableHandle };
 
 // Import and reexport all types
+import { Announcement } from "./announcement_type.ts";
+export { Announcement };
+import { Attachment } from "./attachment_type.ts";
+export { Attachment };
+import { AttachmentInput } from "./attachment_input_type.ts";
+export { AttachmentInput };
+import { AttachmentKind } from "./attachment_kind_type.ts";
+export { AttachmentKind };
+import { AuditAction } from "./audit_action_type.ts";
+export { AuditAction };
+import { AuditLog } from "./audit_log_type.ts";
+export { AuditLog };
+import { AwardedBadge } from "./awarded_badge_type.ts";
+export { AwardedBadge };
+import { Badge } from "./badge_type.ts";
+export { Badge };
+import { BannedWord } from "./banned_word_type.ts";
+export { BannedWord };
+import { Block } from "./block_type.ts";
+export { Block };
+import { Bookmark } from "./bookmark_type.ts";
+export { Bookmark };
+import { BuiltinBadge } from "./builtin_badge_type.ts";
+export { BuiltinBadge };
+import { Challenge } from "./challenge_type.ts";
+export { Challenge };
+import { ChallengeAnswer } from "./challenge_answer_type.ts";
+export { ChallengeAnswer };
+import { ChatFolder } from "./chat_folder_type.ts";
+export { ChatFolder };
+import { Config } from "./config_type.ts";
+export { Config };
+import { CustomEmoji } from "./custom_emoji_type.ts";
+export { CustomEmoji };
+import { DailyDigest } from "./daily_digest_type.ts";
+export { DailyDigest };
+import { DailyDigestSchedule } from "./daily_digest_schedule_type.ts";
+export { DailyDigestSchedule };
+import { DataExport } from "./data_export_type.ts";
+export { DataExport };
+import { DeletedAccountPolicy } from "./deleted_account_policy_type.ts";
+export { DeletedAccountPolicy };
+import { DirectConversation } from "./direct_conversation_type.ts";
+export { DirectConversation };
+import { DmMessage } from "./dm_message_type.ts";
+export { DmMessage };
+import { Draft } from "./draft_type.ts";
+export { Draft };
+import { EventReminder } from "./event_reminder_type.ts";
+export { EventReminder };
+import { EventRsvp } from "./event_rsvp_type.ts";
+export { EventRsvp };
+import { FolderMembership } from "./folder_membership_type.ts";
+export { FolderMembership };
+import { FriendRequest } from "./friend_request_type.ts";
+export { FriendRequest };
+import { Friendship } from "./friendship_type.ts";
+export { Friendship };
+import { GroupAction } from "./group_action_type.ts";
+export { GroupAction };
 import { GroupChat } from "./group_chat_type.ts";
 export { GroupChat };
+import { GroupChatBan } from "./group_chat_ban_type.ts";
+export { GroupChatBan };
+import { GroupChatInvite } from "./group_chat_invite_type.ts";
+export { GroupChatInvite };
 import { GroupChatMembership } from "./group_chat_membership_type.ts";
 export { GroupChatMembership };
+import { GroupCreationLimit } from "./group_creation_limit_type.ts";
+export { GroupCreationLimit };
+import { GroupEvent } from "./group_event_type.ts";
+export { GroupEvent };
+import { GroupJoinCode } from "./group_join_code_type.ts";
+export { GroupJoinCode };
+import { GroupKey } from "./group_key_type.ts";
+export { GroupKey };
+import { GroupKeyEpoch } from "./group_key_epoch_type.ts";
+export { GroupKeyEpoch };
+import { GroupPermission } from "./group_permission_type.ts";
+export { GroupPermission };
+import { GroupStats } from "./group_stats_type.ts";
+export { GroupStats };
+import { GroupStatsRollup } from "./group_stats_rollup_type.ts";
+export { GroupStatsRollup };
+import { GroupTag } from "./group_tag_type.ts";
+export { GroupTag };
+import { GroupVisibility } from "./group_visibility_type.ts";
+export { GroupVisibility };
+import { ImportedMessage } from "./imported_message_type.ts";
+export { ImportedMessage };
+import { JoinCodeAttempt } from "./join_code_attempt_type.ts";
+export { JoinCodeAttempt };
+import { JoinRequest } from "./join_request_type.ts";
+export { JoinRequest };
+import { Karma } from "./karma_type.ts";
+export { Karma };
+import { KarmaTransaction } from "./karma_transaction_type.ts";
+export { KarmaTransaction };
+import { LinkToken } from "./link_token_type.ts";
+export { LinkToken };
+import { LinkedDevice } from "./linked_device_type.ts";
+export { LinkedDevice };
+import { MemberStats } from "./member_stats_type.ts";
+export { MemberStats };
+import { MembershipRole } from "./membership_role_type.ts";
+export { MembershipRole };
+import { Mention } from "./mention_type.ts";
+export { Mention };
 import { Message } from "./message_type.ts";
 export { Message };
+import { MessageEdit } from "./message_edit_type.ts";
+export { MessageEdit };
+import { MessageExpirySweep } from "./message_expiry_sweep_type.ts";
+export { MessageExpirySweep };
+import { MessageKind } from "./message_kind_type.ts";
+export { MessageKind };
+import { MessageReceipt } from "./message_receipt_type.ts";
+export { MessageReceipt };
+import { Mute } from "./mute_type.ts";
+export { Mute };
+import { MuteExpirySweep } from "./mute_expiry_sweep_type.ts";
+export { MuteExpirySweep };
+import { Notification } from "./notification_type.ts";
+export { Notification };
+import { NotificationKind } from "./notification_kind_type.ts";
+export { NotificationKind };
+import { NotificationMode } from "./notification_mode_type.ts";
+export { NotificationMode };
+import { NotificationPref } from "./notification_pref_type.ts";
+export { NotificationPref };
+import { PinnedMessage } from "./pinned_message_type.ts";
+export { PinnedMessage };
+import { Poll } from "./poll_type.ts";
+export { Poll };
+import { PollVote } from "./poll_vote_type.ts";
+export { PollVote };
+import { PostPolicy } from "./post_policy_type.ts";
+export { PostPolicy };
+import { Presence } from "./presence_type.ts";
+export { Presence };
+import { PresencePreference } from "./presence_preference_type.ts";
+export { PresencePreference };
+import { PresenceSweep } from "./presence_sweep_type.ts";
+export { PresenceSweep };
+import { RateLimit } from "./rate_limit_type.ts";
+export { RateLimit };
+import { Reaction } from "./reaction_type.ts";
+export { Reaction };
+import { ReactionCount } from "./reaction_count_type.ts";
+export { ReactionCount };
+import { ReadState } from "./read_state_type.ts";
+export { ReadState };
+import { ReceiptState } from "./receipt_state_type.ts";
+export { ReceiptState };
+import { Reminder } from "./reminder_type.ts";
+export { Reminder };
+import { Report } from "./report_type.ts";
+export { Report };
+import { ReportStatus } from "./report_status_type.ts";
+export { ReportStatus };
+import { RetentionCleanup } from "./retention_cleanup_type.ts";
+export { RetentionCleanup };
+import { RsvpStatus } from "./rsvp_status_type.ts";
+export { RsvpStatus };
+import { SearchResult } from "./search_result_type.ts";
+export { SearchResult };
+import { SentClientId } from "./sent_client_id_type.ts";
+export { SentClientId };
+import { Session } from "./session_type.ts";
+export { Session };
+import { Streak } from "./streak_type.ts";
+export { Streak };
+import { StreakResetSweep } from "./streak_reset_sweep_type.ts";
+export { StreakResetSweep };
+import { StrikeEscalation } from "./strike_escalation_type.ts";
+export { StrikeEscalation };
+import { Superadmin } from "./superadmin_type.ts";
+export { Superadmin };
+import { Theme } from "./theme_type.ts";
+export { Theme };
+import { ThreadMeta } from "./thread_meta_type.ts";
+export { ThreadMeta };
+import { Topic } from "./topic_type.ts";
+export { Topic };
+import { TranslationCache } from "./translation_cache_type.ts";
+export { TranslationCache };
+import { TypingIndicator } from "./typing_indicator_type.ts";
+export { TypingIndicator };
+import { TypingIndicatorCleanup } from "./typing_indicator_cleanup_type.ts";
+export { TypingIndicatorCleanup };
 import { User } from "./user_type.ts";
 export { User };
+import { UserSearchResult } from "./user_search_result_type.ts";
+export { UserSearchResult };
+import { UserSettings } from "./user_settings_type.ts";
+export { UserSettings };
+import { Username } from "./username_type.ts";
+export { Username };
+import { Warning } from "./warning_type.ts";
+export { Warning };
+import { Webhook } from "./webhook_type.ts";
+export { Webhook };
+import { WebhookOutbox } from "./webhook_outbox_type.ts";
+export { WebhookOutbox };
+import { WrappedKey } from "./wrapped_key_type.ts";
+export { WrappedKey };
 
 const REMOTE_MODULE = {
   tables: {
+    announcement: {
+      tableName: "announcement" as const,
+      rowType: Announcement.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Announcement.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    attachment: {
+      tableName: "attachment" as const,
+      rowType: Attachment.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Attachment.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    audit_log: {
+      tableName: "audit_log" as const,
+      rowType: AuditLog.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (AuditLog.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    awarded_badge: {
+      tableName: "awarded_badge" as const,
+      rowType: AwardedBadge.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (AwardedBadge.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    badge: {
+      tableName: "badge" as const,
+      rowType: Badge.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Badge.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    banned_word: {
+      tableName: "banned_word" as const,
+      rowType: BannedWord.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (BannedWord.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    block: {
+      tableName: "block" as const,
+      rowType: Block.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Block.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    bookmark: {
+      tableName: "bookmark" as const,
+      rowType: Bookmark.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Bookmark.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    challenge: {
+      tableName: "challenge" as const,
+      rowType: Challenge.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (Challenge.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    challenge_answer: {
+      tableName: "challenge_answer" as const,
+      rowType: ChallengeAnswer.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (ChallengeAnswer.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    chat_folder: {
+      tableName: "chat_folder" as const,
+      rowType: ChatFolder.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (ChatFolder.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    config: {
+      tableName: "config" as const,
+      rowType: Config.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Config.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    custom_emoji: {
+      tableName: "custom_emoji" as const,
+      rowType: CustomEmoji.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (CustomEmoji.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    daily_digest: {
+      tableName: "daily_digest" as const,
+      rowType: DailyDigest.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (DailyDigest.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    daily_digest_schedule: {
+      tableName: "daily_digest_schedule" as const,
+      rowType: DailyDigestSchedule.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (DailyDigestSchedule.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    data_export: {
+      tableName: "data_export" as const,
+      rowType: DataExport.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (DataExport.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    direct_conversation: {
+      tableName: "direct_conversation" as const,
+      rowType: DirectConversation.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (DirectConversation.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    dm_message: {
+      tableName: "dm_message" as const,
+      rowType: DmMessage.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (DmMessage.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    draft: {
+      tableName: "draft" as const,
+      rowType: Draft.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Draft.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    event_reminder: {
+      tableName: "event_reminder" as const,
+      rowType: EventReminder.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (EventReminder.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    event_rsvp: {
+      tableName: "event_rsvp" as const,
+      rowType: EventRsvp.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (EventRsvp.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    folder_membership: {
+      tableName: "folder_membership" as const,
+      rowType: FolderMembership.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (FolderMembership.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    friend_request: {
+      tableName: "friend_request" as const,
+      rowType: FriendRequest.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (FriendRequest.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    friendship: {
+      tableName: "friendship" as const,
+      rowType: Friendship.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Friendship.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    group_creation_limit: {
+      tableName: "group_creation_limit" as const,
+      rowType: GroupCreationLimit.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (GroupCreationLimit.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    group_event: {
+      tableName: "group_event" as const,
+      rowType: GroupEvent.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (GroupEvent.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    group_join_code: {
+      tableName: "group_join_code" as const,
+      rowType: GroupJoinCode.getTypeScriptAlgebraicType(),
+      primaryKey: "groupchatId",
+      primaryKeyInfo: {
+        colName: "groupchatId",
+        colType: (GroupJoinCode.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    group_key: {
+      tableName: "group_key" as const,
+      rowType: GroupKey.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (GroupKey.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    group_key_epoch: {
+      tableName: "group_key_epoch" as const,
+      rowType: GroupKeyEpoch.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (GroupKeyEpoch.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    group_permission: {
+      tableName: "group_permission" as const,
+      rowType: GroupPermission.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (GroupPermission.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    group_stats: {
+      tableName: "group_stats" as const,
+      rowType: GroupStats.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (GroupStats.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    group_stats_rollup: {
+      tableName: "group_stats_rollup" as const,
+      rowType: GroupStatsRollup.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (GroupStatsRollup.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    group_tag: {
+      tableName: "group_tag" as const,
+      rowType: GroupTag.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (GroupTag.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
     groupchat: {
       tableName: "groupchat" as const,
       rowType: GroupChat.getTypeScriptAlgebraicType(),
@@ -75,6 +994,24 @@ const REMOTE_MODULE = {
         colType: (GroupChat.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
       },
     },
+    groupchat_ban: {
+      tableName: "groupchat_ban" as const,
+      rowType: GroupChatBan.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (GroupChatBan.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    groupchat_invite: {
+      tableName: "groupchat_invite" as const,
+      rowType: GroupChatInvite.getTypeScriptAlgebraicType(),
+      primaryKey: "code",
+      primaryKeyInfo: {
+        colName: "code",
+        colType: (GroupChatInvite.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
     groupchat_membership: {
       tableName: "groupchat_membership" as const,
       rowType: GroupChatMembership.getTypeScriptAlgebraicType(),
@@ -84,213 +1021,4467 @@ const REMOTE_MODULE = {
         colType: (GroupChatMembership.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
       },
     },
+    join_code_attempt: {
+      tableName: "join_code_attempt" as const,
+      rowType: JoinCodeAttempt.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (JoinCodeAttempt.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    join_request: {
+      tableName: "join_request" as const,
+      rowType: JoinRequest.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (JoinRequest.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    karma: {
+      tableName: "karma" as const,
+      rowType: Karma.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (Karma.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    karma_transaction: {
+      tableName: "karma_transaction" as const,
+      rowType: KarmaTransaction.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (KarmaTransaction.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    link_token: {
+      tableName: "link_token" as const,
+      rowType: LinkToken.getTypeScriptAlgebraicType(),
+      primaryKey: "token",
+      primaryKeyInfo: {
+        colName: "token",
+        colType: (LinkToken.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    linked_device: {
+      tableName: "linked_device" as const,
+      rowType: LinkedDevice.getTypeScriptAlgebraicType(),
+      primaryKey: "device",
+      primaryKeyInfo: {
+        colName: "device",
+        colType: (LinkedDevice.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    member_stats: {
+      tableName: "member_stats" as const,
+      rowType: MemberStats.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (MemberStats.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    mention: {
+      tableName: "mention" as const,
+      rowType: Mention.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Mention.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
     message: {
       tableName: "message" as const,
       rowType: Message.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Message.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
     },
-    user: {
-      tableName: "user" as const,
-      rowType: User.getTypeScriptAlgebraicType(),
-      primaryKey: "identity",
+    message_edit: {
+      tableName: "message_edit" as const,
+      rowType: MessageEdit.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
       primaryKeyInfo: {
-        colName: "identity",
-        colType: (User.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+        colName: "id",
+        colType: (MessageEdit.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
       },
     },
-  },
-  reducers: {
-    create_groupchat: {
-      reducerName: "create_groupchat",
-      argsType: CreateGroupchat.getTypeScriptAlgebraicType(),
+    message_expiry_sweep: {
+      tableName: "message_expiry_sweep" as const,
+      rowType: MessageExpirySweep.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (MessageExpirySweep.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
     },
-    identity_connected: {
-      reducerName: "identity_connected",
-      argsType: IdentityConnected.getTypeScriptAlgebraicType(),
+    message_receipt: {
+      tableName: "message_receipt" as const,
+      rowType: MessageReceipt.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (MessageReceipt.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
     },
-    identity_disconnected: {
-      reducerName: "identity_disconnected",
-      argsType: IdentityDisconnected.getTypeScriptAlgebraicType(),
+    mute: {
+      tableName: "mute" as const,
+      rowType: Mute.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Mute.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
     },
-    join_groupchat: {
-      reducerName: "join_groupchat",
-      argsType: JoinGroupchat.getTypeScriptAlgebraicType(),
+    mute_expiry_sweep: {
+      tableName: "mute_expiry_sweep" as const,
+      rowType: MuteExpirySweep.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (MuteExpirySweep.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
     },
-    send_message: {
-      reducerName: "send_message",
-      argsType: SendMessage.getTypeScriptAlgebraicType(),
+    notification: {
+      tableName: "notification" as const,
+      rowType: Notification.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Notification.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
     },
-    set_name: {
-      reducerName: "set_name",
-      argsType: SetName.getTypeScriptAlgebraicType(),
+    notification_pref: {
+      tableName: "notification_pref" as const,
+      rowType: NotificationPref.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (NotificationPref.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    pinned_message: {
+      tableName: "pinned_message" as const,
+      rowType: PinnedMessage.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (PinnedMessage.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    poll: {
+      tableName: "poll" as const,
+      rowType: Poll.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Poll.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    poll_vote: {
+      tableName: "poll_vote" as const,
+      rowType: PollVote.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (PollVote.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    presence_preference: {
+      tableName: "presence_preference" as const,
+      rowType: PresencePreference.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (PresencePreference.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    presence_sweep: {
+      tableName: "presence_sweep" as const,
+      rowType: PresenceSweep.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (PresenceSweep.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    rate_limit: {
+      tableName: "rate_limit" as const,
+      rowType: RateLimit.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (RateLimit.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    reaction: {
+      tableName: "reaction" as const,
+      rowType: Reaction.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Reaction.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    reaction_count: {
+      tableName: "reaction_count" as const,
+      rowType: ReactionCount.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (ReactionCount.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    read_state: {
+      tableName: "read_state" as const,
+      rowType: ReadState.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (ReadState.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    reminder: {
+      tableName: "reminder" as const,
+      rowType: Reminder.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (Reminder.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    report: {
+      tableName: "report" as const,
+      rowType: Report.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Report.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    retention_cleanup: {
+      tableName: "retention_cleanup" as const,
+      rowType: RetentionCleanup.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (RetentionCleanup.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    search_result: {
+      tableName: "search_result" as const,
+      rowType: SearchResult.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (SearchResult.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    sent_client_id: {
+      tableName: "sent_client_id" as const,
+      rowType: SentClientId.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (SentClientId.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    session: {
+      tableName: "session" as const,
+      rowType: Session.getTypeScriptAlgebraicType(),
+      primaryKey: "connectionId",
+      primaryKeyInfo: {
+        colName: "connectionId",
+        colType: (Session.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    streak: {
+      tableName: "streak" as const,
+      rowType: Streak.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Streak.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    streak_reset_sweep: {
+      tableName: "streak_reset_sweep" as const,
+      rowType: StreakResetSweep.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (StreakResetSweep.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    superadmin: {
+      tableName: "superadmin" as const,
+      rowType: Superadmin.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (Superadmin.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    thread_meta: {
+      tableName: "thread_meta" as const,
+      rowType: ThreadMeta.getTypeScriptAlgebraicType(),
+      primaryKey: "rootMessageId",
+      primaryKeyInfo: {
+        colName: "rootMessageId",
+        colType: (ThreadMeta.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    topic: {
+      tableName: "topic" as const,
+      rowType: Topic.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Topic.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    translation_cache: {
+      tableName: "translation_cache" as const,
+      rowType: TranslationCache.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (TranslationCache.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    typing_indicator: {
+      tableName: "typing_indicator" as const,
+      rowType: TypingIndicator.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (TypingIndicator.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    typing_indicator_cleanup: {
+      tableName: "typing_indicator_cleanup" as const,
+      rowType: TypingIndicatorCleanup.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (TypingIndicatorCleanup.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    user: {
+      tableName: "user" as const,
+      rowType: User.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (User.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    user_search_result: {
+      tableName: "user_search_result" as const,
+      rowType: UserSearchResult.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (UserSearchResult.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    user_settings: {
+      tableName: "user_settings" as const,
+      rowType: UserSettings.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (UserSettings.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    username: {
+      tableName: "username" as const,
+      rowType: Username.getTypeScriptAlgebraicType(),
+      primaryKey: "normalizedName",
+      primaryKeyInfo: {
+        colName: "normalizedName",
+        colType: (Username.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    warning: {
+      tableName: "warning" as const,
+      rowType: Warning.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Warning.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    webhook: {
+      tableName: "webhook" as const,
+      rowType: Webhook.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Webhook.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    webhook_outbox: {
+      tableName: "webhook_outbox" as const,
+      rowType: WebhookOutbox.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (WebhookOutbox.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
     },
   },
-  versionInfo: {
-    cliVersion: "1.6.0",
-  },
-  // Constructors which are used by the DbConnectionImpl to
-  // extract type information from the generated RemoteModule.
-  //
-  // NOTE: This is not strictly necessary for `eventContextConstructor` because
-  // all we do is build a TypeScript object which we could have done inside the
-  // SDK, but if in the future we wanted to create a class this would be
-  // necessary because classes have methods, so we'll keep it.
-  eventContextConstructor: (imp: __DbConnectionImpl, event: __Event<Reducer>) => {
-    return {
-      ...(imp as DbConnection),
-      event
-    }
-  },
-  dbViewConstructor: (imp: __DbConnectionImpl) => {
-    return new RemoteTables(imp);
-  },
-  reducersConstructor: (imp: __DbConnectionImpl, setReducerFlags: SetReducerFlags) => {
-    return new RemoteReducers(imp, setReducerFlags);
-  },
-  setReducerFlagsConstructor: () => {
-    return new SetReducerFlags();
+  reducers: {
+    accept_friend_request: {
+      reducerName: "accept_friend_request",
+      argsType: AcceptFriendRequest.getTypeScriptAlgebraicType(),
+    },
+    accept_tos: {
+      reducerName: "accept_tos",
+      argsType: AcceptTos.getTypeScriptAlgebraicType(),
+    },
+    ack_delivery: {
+      reducerName: "ack_delivery",
+      argsType: AckDelivery.getTypeScriptAlgebraicType(),
+    },
+    ack_mention: {
+      reducerName: "ack_mention",
+      argsType: AckMention.getTypeScriptAlgebraicType(),
+    },
+    add_banned_word: {
+      reducerName: "add_banned_word",
+      argsType: AddBannedWord.getTypeScriptAlgebraicType(),
+    },
+    add_chat_to_folder: {
+      reducerName: "add_chat_to_folder",
+      argsType: AddChatToFolder.getTypeScriptAlgebraicType(),
+    },
+    add_custom_emoji: {
+      reducerName: "add_custom_emoji",
+      argsType: AddCustomEmoji.getTypeScriptAlgebraicType(),
+    },
+    add_group_tag: {
+      reducerName: "add_group_tag",
+      argsType: AddGroupTag.getTypeScriptAlgebraicType(),
+    },
+    add_reaction: {
+      reducerName: "add_reaction",
+      argsType: AddReaction.getTypeScriptAlgebraicType(),
+    },
+    add_webhook: {
+      reducerName: "add_webhook",
+      argsType: AddWebhook.getTypeScriptAlgebraicType(),
+    },
+    apply_retention_policies: {
+      reducerName: "apply_retention_policies",
+      argsType: ApplyRetentionPolicies.getTypeScriptAlgebraicType(),
+    },
+    approve_join_request: {
+      reducerName: "approve_join_request",
+      argsType: ApproveJoinRequest.getTypeScriptAlgebraicType(),
+    },
+    archive_groupchat: {
+      reducerName: "archive_groupchat",
+      argsType: ArchiveGroupchat.getTypeScriptAlgebraicType(),
+    },
+    archive_topic: {
+      reducerName: "archive_topic",
+      argsType: ArchiveTopic.getTypeScriptAlgebraicType(),
+    },
+    award_badge: {
+      reducerName: "award_badge",
+      argsType: AwardBadge.getTypeScriptAlgebraicType(),
+    },
+    award_points: {
+      reducerName: "award_points",
+      argsType: AwardPoints.getTypeScriptAlgebraicType(),
+    },
+    ban_member: {
+      reducerName: "ban_member",
+      argsType: BanMember.getTypeScriptAlgebraicType(),
+    },
+    block_user: {
+      reducerName: "block_user",
+      argsType: BlockUser.getTypeScriptAlgebraicType(),
+    },
+    bookmark_message: {
+      reducerName: "bookmark_message",
+      argsType: BookmarkMessage.getTypeScriptAlgebraicType(),
+    },
+    broadcast: {
+      reducerName: "broadcast",
+      argsType: Broadcast.getTypeScriptAlgebraicType(),
+    },
+    cancel_event: {
+      reducerName: "cancel_event",
+      argsType: CancelEvent.getTypeScriptAlgebraicType(),
+    },
+    clear_draft: {
+      reducerName: "clear_draft",
+      argsType: ClearDraft.getTypeScriptAlgebraicType(),
+    },
+    clear_notifications: {
+      reducerName: "clear_notifications",
+      argsType: ClearNotifications.getTypeScriptAlgebraicType(),
+    },
+    close_poll: {
+      reducerName: "close_poll",
+      argsType: ClosePoll.getTypeScriptAlgebraicType(),
+    },
+    create_badge: {
+      reducerName: "create_badge",
+      argsType: CreateBadge.getTypeScriptAlgebraicType(),
+    },
+    create_event: {
+      reducerName: "create_event",
+      argsType: CreateEvent.getTypeScriptAlgebraicType(),
+    },
+    create_folder: {
+      reducerName: "create_folder",
+      argsType: CreateFolder.getTypeScriptAlgebraicType(),
+    },
+    create_groupchat: {
+      reducerName: "create_groupchat",
+      argsType: CreateGroupchat.getTypeScriptAlgebraicType(),
+    },
+    create_invite: {
+      reducerName: "create_invite",
+      argsType: CreateInvite.getTypeScriptAlgebraicType(),
+    },
+    create_link_token: {
+      reducerName: "create_link_token",
+      argsType: CreateLinkToken.getTypeScriptAlgebraicType(),
+    },
+    create_poll: {
+      reducerName: "create_poll",
+      argsType: CreatePoll.getTypeScriptAlgebraicType(),
+    },
+    create_topic: {
+      reducerName: "create_topic",
+      argsType: CreateTopic.getTypeScriptAlgebraicType(),
+    },
+    crosspost_message: {
+      reducerName: "crosspost_message",
+      argsType: CrosspostMessage.getTypeScriptAlgebraicType(),
+    },
+    decline_friend_request: {
+      reducerName: "decline_friend_request",
+      argsType: DeclineFriendRequest.getTypeScriptAlgebraicType(),
+    },
+    delete_account: {
+      reducerName: "delete_account",
+      argsType: DeleteAccount.getTypeScriptAlgebraicType(),
+    },
+    delete_folder: {
+      reducerName: "delete_folder",
+      argsType: DeleteFolder.getTypeScriptAlgebraicType(),
+    },
+    delete_groupchat: {
+      reducerName: "delete_groupchat",
+      argsType: DeleteGroupchat.getTypeScriptAlgebraicType(),
+    },
+    delete_message: {
+      reducerName: "delete_message",
+      argsType: DeleteMessage.getTypeScriptAlgebraicType(),
+    },
+    demote_member: {
+      reducerName: "demote_member",
+      argsType: DemoteMember.getTypeScriptAlgebraicType(),
+    },
+    dismiss_report: {
+      reducerName: "dismiss_report",
+      argsType: DismissReport.getTypeScriptAlgebraicType(),
+    },
+    edit_message: {
+      reducerName: "edit_message",
+      argsType: EditMessage.getTypeScriptAlgebraicType(),
+    },
+    expire_disappearing_messages: {
+      reducerName: "expire_disappearing_messages",
+      argsType: ExpireDisappearingMessages.getTypeScriptAlgebraicType(),
+    },
+    expire_mutes: {
+      reducerName: "expire_mutes",
+      argsType: ExpireMutes.getTypeScriptAlgebraicType(),
+    },
+    fire_reminder: {
+      reducerName: "fire_reminder",
+      argsType: FireReminder.getTypeScriptAlgebraicType(),
+    },
+    forward_message: {
+      reducerName: "forward_message",
+      argsType: ForwardMessage.getTypeScriptAlgebraicType(),
+    },
+    generate_daily_digests: {
+      reducerName: "generate_daily_digests",
+      argsType: GenerateDailyDigests.getTypeScriptAlgebraicType(),
+    },
+    heartbeat: {
+      reducerName: "heartbeat",
+      argsType: Heartbeat.getTypeScriptAlgebraicType(),
+    },
+    identity_connected: {
+      reducerName: "identity_connected",
+      argsType: IdentityConnected.getTypeScriptAlgebraicType(),
+    },
+    identity_disconnected: {
+      reducerName: "identity_disconnected",
+      argsType: IdentityDisconnected.getTypeScriptAlgebraicType(),
+    },
+    import_messages: {
+      reducerName: "import_messages",
+      argsType: ImportMessages.getTypeScriptAlgebraicType(),
+    },
+    join_by_code: {
+      reducerName: "join_by_code",
+      argsType: JoinByCode.getTypeScriptAlgebraicType(),
+    },
+    join_by_slug: {
+      reducerName: "join_by_slug",
+      argsType: JoinBySlug.getTypeScriptAlgebraicType(),
+    },
+    join_groupchat: {
+      reducerName: "join_groupchat",
+      argsType: JoinGroupchat.getTypeScriptAlgebraicType(),
+    },
+    join_via_invite: {
+      reducerName: "join_via_invite",
+      argsType: JoinViaInvite.getTypeScriptAlgebraicType(),
+    },
+    kick_member: {
+      reducerName: "kick_member",
+      argsType: KickMember.getTypeScriptAlgebraicType(),
+    },
+    leave_groupchat: {
+      reducerName: "leave_groupchat",
+      argsType: LeaveGroupchat.getTypeScriptAlgebraicType(),
+    },
+    link_device: {
+      reducerName: "link_device",
+      argsType: LinkDevice.getTypeScriptAlgebraicType(),
+    },
+    mark_notification_read: {
+      reducerName: "mark_notification_read",
+      argsType: MarkNotificationRead.getTypeScriptAlgebraicType(),
+    },
+    mark_read: {
+      reducerName: "mark_read",
+      argsType: MarkRead.getTypeScriptAlgebraicType(),
+    },
+    mute_member: {
+      reducerName: "mute_member",
+      argsType: MuteMember.getTypeScriptAlgebraicType(),
+    },
+    pin_message: {
+      reducerName: "pin_message",
+      argsType: PinMessage.getTypeScriptAlgebraicType(),
+    },
+    promote_member: {
+      reducerName: "promote_member",
+      argsType: PromoteMember.getTypeScriptAlgebraicType(),
+    },
+    publish_group_key: {
+      reducerName: "publish_group_key",
+      argsType: PublishGroupKey.getTypeScriptAlgebraicType(),
+    },
+    purge_typing_indicators: {
+      reducerName: "purge_typing_indicators",
+      argsType: PurgeTypingIndicators.getTypeScriptAlgebraicType(),
+    },
+    regenerate_join_code: {
+      reducerName: "regenerate_join_code",
+      argsType: RegenerateJoinCode.getTypeScriptAlgebraicType(),
+    },
+    register_bot: {
+      reducerName: "register_bot",
+      argsType: RegisterBot.getTypeScriptAlgebraicType(),
+    },
+    reject_join_request: {
+      reducerName: "reject_join_request",
+      argsType: RejectJoinRequest.getTypeScriptAlgebraicType(),
+    },
+    remind_me: {
+      reducerName: "remind_me",
+      argsType: RemindMe.getTypeScriptAlgebraicType(),
+    },
+    remove_banned_word: {
+      reducerName: "remove_banned_word",
+      argsType: RemoveBannedWord.getTypeScriptAlgebraicType(),
+    },
+    remove_bookmark: {
+      reducerName: "remove_bookmark",
+      argsType: RemoveBookmark.getTypeScriptAlgebraicType(),
+    },
+    remove_chat_from_folder: {
+      reducerName: "remove_chat_from_folder",
+      argsType: RemoveChatFromFolder.getTypeScriptAlgebraicType(),
+    },
+    remove_custom_emoji: {
+      reducerName: "remove_custom_emoji",
+      argsType: RemoveCustomEmoji.getTypeScriptAlgebraicType(),
+    },
+    remove_friend: {
+      reducerName: "remove_friend",
+      argsType: RemoveFriend.getTypeScriptAlgebraicType(),
+    },
+    remove_group_tag: {
+      reducerName: "remove_group_tag",
+      argsType: RemoveGroupTag.getTypeScriptAlgebraicType(),
+    },
+    remove_reaction: {
+      reducerName: "remove_reaction",
+      argsType: RemoveReaction.getTypeScriptAlgebraicType(),
+    },
+    remove_webhook: {
+      reducerName: "remove_webhook",
+      argsType: RemoveWebhook.getTypeScriptAlgebraicType(),
+    },
+    reorder_chats: {
+      reducerName: "reorder_chats",
+      argsType: ReorderChats.getTypeScriptAlgebraicType(),
+    },
+    reorder_folders: {
+      reducerName: "reorder_folders",
+      argsType: ReorderFolders.getTypeScriptAlgebraicType(),
+    },
+    reply_to_message: {
+      reducerName: "reply_to_message",
+      argsType: ReplyToMessage.getTypeScriptAlgebraicType(),
+    },
+    report_message: {
+      reducerName: "report_message",
+      argsType: ReportMessage.getTypeScriptAlgebraicType(),
+    },
+    request_data_export: {
+      reducerName: "request_data_export",
+      argsType: RequestDataExport.getTypeScriptAlgebraicType(),
+    },
+    request_to_join: {
+      reducerName: "request_to_join",
+      argsType: RequestToJoin.getTypeScriptAlgebraicType(),
+    },
+    reset_broken_streaks: {
+      reducerName: "reset_broken_streaks",
+      argsType: ResetBrokenStreaks.getTypeScriptAlgebraicType(),
+    },
+    reset_leaderboard: {
+      reducerName: "reset_leaderboard",
+      argsType: ResetLeaderboard.getTypeScriptAlgebraicType(),
+    },
+    resolve_report: {
+      reducerName: "resolve_report",
+      argsType: ResolveReport.getTypeScriptAlgebraicType(),
+    },
+    revoke_device: {
+      reducerName: "revoke_device",
+      argsType: RevokeDevice.getTypeScriptAlgebraicType(),
+    },
+    revoke_invite: {
+      reducerName: "revoke_invite",
+      argsType: RevokeInvite.getTypeScriptAlgebraicType(),
+    },
+    roll_up_group_stats: {
+      reducerName: "roll_up_group_stats",
+      argsType: RollUpGroupStats.getTypeScriptAlgebraicType(),
+    },
+    rsvp: {
+      reducerName: "rsvp",
+      argsType: Rsvp.getTypeScriptAlgebraicType(),
+    },
+    save_draft: {
+      reducerName: "save_draft",
+      argsType: SaveDraft.getTypeScriptAlgebraicType(),
+    },
+    search_messages: {
+      reducerName: "search_messages",
+      argsType: SearchMessages.getTypeScriptAlgebraicType(),
+    },
+    search_users: {
+      reducerName: "search_users",
+      argsType: SearchUsers.getTypeScriptAlgebraicType(),
+    },
+    send_dm: {
+      reducerName: "send_dm",
+      argsType: SendDm.getTypeScriptAlgebraicType(),
+    },
+    send_encrypted_message: {
+      reducerName: "send_encrypted_message",
+      argsType: SendEncryptedMessage.getTypeScriptAlgebraicType(),
+    },
+    send_event_reminder: {
+      reducerName: "send_event_reminder",
+      argsType: SendEventReminder.getTypeScriptAlgebraicType(),
+    },
+    send_friend_request: {
+      reducerName: "send_friend_request",
+      argsType: SendFriendRequest.getTypeScriptAlgebraicType(),
+    },
+    send_message: {
+      reducerName: "send_message",
+      argsType: SendMessage.getTypeScriptAlgebraicType(),
+    },
+    send_message_with_attachments: {
+      reducerName: "send_message_with_attachments",
+      argsType: SendMessageWithAttachments.getTypeScriptAlgebraicType(),
+    },
+    send_note_to_self: {
+      reducerName: "send_note_to_self",
+      argsType: SendNoteToSelf.getTypeScriptAlgebraicType(),
+    },
+    set_chat_pin: {
+      reducerName: "set_chat_pin",
+      argsType: SetChatPin.getTypeScriptAlgebraicType(),
+    },
+    set_deleted_account_policy: {
+      reducerName: "set_deleted_account_policy",
+      argsType: SetDeletedAccountPolicy.getTypeScriptAlgebraicType(),
+    },
+    set_discoverable: {
+      reducerName: "set_discoverable",
+      argsType: SetDiscoverable.getTypeScriptAlgebraicType(),
+    },
+    set_group_avatar: {
+      reducerName: "set_group_avatar",
+      argsType: SetGroupAvatar.getTypeScriptAlgebraicType(),
+    },
+    set_group_chat_name: {
+      reducerName: "set_group_chat_name",
+      argsType: SetGroupChatName.getTypeScriptAlgebraicType(),
+    },
+    set_group_description: {
+      reducerName: "set_group_description",
+      argsType: SetGroupDescription.getTypeScriptAlgebraicType(),
+    },
+    set_group_nickname: {
+      reducerName: "set_group_nickname",
+      argsType: SetGroupNickname.getTypeScriptAlgebraicType(),
+    },
+    set_group_topic: {
+      reducerName: "set_group_topic",
+      argsType: SetGroupTopic.getTypeScriptAlgebraicType(),
+    },
+    set_group_visibility: {
+      reducerName: "set_group_visibility",
+      argsType: SetGroupVisibility.getTypeScriptAlgebraicType(),
+    },
+    set_member_limit: {
+      reducerName: "set_member_limit",
+      argsType: SetMemberLimit.getTypeScriptAlgebraicType(),
+    },
+    set_message_ttl: {
+      reducerName: "set_message_ttl",
+      argsType: SetMessageTtl.getTypeScriptAlgebraicType(),
+    },
+    set_name: {
+      reducerName: "set_name",
+      argsType: SetName.getTypeScriptAlgebraicType(),
+    },
+    set_notification_pref: {
+      reducerName: "set_notification_pref",
+      argsType: SetNotificationPref.getTypeScriptAlgebraicType(),
+    },
+    set_nsfw: {
+      reducerName: "set_nsfw",
+      argsType: SetNsfw.getTypeScriptAlgebraicType(),
+    },
+    set_permission: {
+      reducerName: "set_permission",
+      argsType: SetPermission.getTypeScriptAlgebraicType(),
+    },
+    set_post_policy: {
+      reducerName: "set_post_policy",
+      argsType: SetPostPolicy.getTypeScriptAlgebraicType(),
+    },
+    set_presence: {
+      reducerName: "set_presence",
+      argsType: SetPresence.getTypeScriptAlgebraicType(),
+    },
+    set_retention_days: {
+      reducerName: "set_retention_days",
+      argsType: SetRetentionDays.getTypeScriptAlgebraicType(),
+    },
+    set_session_hint: {
+      reducerName: "set_session_hint",
+      argsType: SetSessionHint.getTypeScriptAlgebraicType(),
+    },
+    set_slowmode: {
+      reducerName: "set_slowmode",
+      argsType: SetSlowmode.getTypeScriptAlgebraicType(),
+    },
+    set_strike_policy: {
+      reducerName: "set_strike_policy",
+      argsType: SetStrikePolicy.getTypeScriptAlgebraicType(),
+    },
+    set_verified: {
+      reducerName: "set_verified",
+      argsType: SetVerified.getTypeScriptAlgebraicType(),
+    },
+    share_group_key: {
+      reducerName: "share_group_key",
+      argsType: ShareGroupKey.getTypeScriptAlgebraicType(),
+    },
+    solve_challenge: {
+      reducerName: "solve_challenge",
+      argsType: SolveChallenge.getTypeScriptAlgebraicType(),
+    },
+    start_typing: {
+      reducerName: "start_typing",
+      argsType: StartTyping.getTypeScriptAlgebraicType(),
+    },
+    store_translation: {
+      reducerName: "store_translation",
+      argsType: StoreTranslation.getTypeScriptAlgebraicType(),
+    },
+    sweep_presence: {
+      reducerName: "sweep_presence",
+      argsType: SweepPresence.getTypeScriptAlgebraicType(),
+    },
+    terminate_session: {
+      reducerName: "terminate_session",
+      argsType: TerminateSession.getTypeScriptAlgebraicType(),
+    },
+    transfer_ownership: {
+      reducerName: "transfer_ownership",
+      argsType: TransferOwnership.getTypeScriptAlgebraicType(),
+    },
+    unarchive_groupchat: {
+      reducerName: "unarchive_groupchat",
+      argsType: UnarchiveGroupchat.getTypeScriptAlgebraicType(),
+    },
+    unban_member: {
+      reducerName: "unban_member",
+      argsType: UnbanMember.getTypeScriptAlgebraicType(),
+    },
+    unblock_user: {
+      reducerName: "unblock_user",
+      argsType: UnblockUser.getTypeScriptAlgebraicType(),
+    },
+    unmute_member: {
+      reducerName: "unmute_member",
+      argsType: UnmuteMember.getTypeScriptAlgebraicType(),
+    },
+    unpin_message: {
+      reducerName: "unpin_message",
+      argsType: UnpinMessage.getTypeScriptAlgebraicType(),
+    },
+    unsend_message: {
+      reducerName: "unsend_message",
+      argsType: UnsendMessage.getTypeScriptAlgebraicType(),
+    },
+    update_config: {
+      reducerName: "update_config",
+      argsType: UpdateConfig.getTypeScriptAlgebraicType(),
+    },
+    update_profile: {
+      reducerName: "update_profile",
+      argsType: UpdateProfile.getTypeScriptAlgebraicType(),
+    },
+    update_settings: {
+      reducerName: "update_settings",
+      argsType: UpdateSettings.getTypeScriptAlgebraicType(),
+    },
+    vote: {
+      reducerName: "vote",
+      argsType: Vote.getTypeScriptAlgebraicType(),
+    },
+    warn_member: {
+      reducerName: "warn_member",
+      argsType: WarnMember.getTypeScriptAlgebraicType(),
+    },
+  },
+  versionInfo: {
+    cliVersion: "1.6.0",
+  },
+  // Constructors which are used by the DbConnectionImpl to
+  // extract type information from the generated RemoteModule.
+  //
+  // NOTE: This is not strictly necessary for `eventContextConstructor` because
+  // all we do is build a TypeScript object which we could have done inside the
+  // SDK, but if in the future we wanted to create a class this would be
+  // necessary because classes have methods, so we'll keep it.
+  eventContextConstructor: (imp: __DbConnectionImpl, event: __Event<Reducer>) => {
+    return {
+      ...(imp as DbConnection),
+      event
+    }
+  },
+  dbViewConstructor: (imp: __DbConnectionImpl) => {
+    return new RemoteTables(imp);
+  },
+  reducersConstructor: (imp: __DbConnectionImpl, setReducerFlags: SetReducerFlags) => {
+    return new RemoteReducers(imp, setReducerFlags);
+  },
+  setReducerFlagsConstructor: () => {
+    return new SetReducerFlags();
+  }
+}
+
+// A type representing all the possible variants of a reducer.
+export type Reducer = never
+| { name: "AcceptFriendRequest", args: AcceptFriendRequest }
+| { name: "AcceptTos", args: AcceptTos }
+| { name: "AckDelivery", args: AckDelivery }
+| { name: "AckMention", args: AckMention }
+| { name: "AddBannedWord", args: AddBannedWord }
+| { name: "AddChatToFolder", args: AddChatToFolder }
+| { name: "AddCustomEmoji", args: AddCustomEmoji }
+| { name: "AddGroupTag", args: AddGroupTag }
+| { name: "AddReaction", args: AddReaction }
+| { name: "AddWebhook", args: AddWebhook }
+| { name: "ApplyRetentionPolicies", args: ApplyRetentionPolicies }
+| { name: "ApproveJoinRequest", args: ApproveJoinRequest }
+| { name: "ArchiveGroupchat", args: ArchiveGroupchat }
+| { name: "ArchiveTopic", args: ArchiveTopic }
+| { name: "AwardBadge", args: AwardBadge }
+| { name: "AwardPoints", args: AwardPoints }
+| { name: "BanMember", args: BanMember }
+| { name: "BlockUser", args: BlockUser }
+| { name: "BookmarkMessage", args: BookmarkMessage }
+| { name: "Broadcast", args: Broadcast }
+| { name: "CancelEvent", args: CancelEvent }
+| { name: "ClearDraft", args: ClearDraft }
+| { name: "ClearNotifications", args: ClearNotifications }
+| { name: "ClosePoll", args: ClosePoll }
+| { name: "CreateBadge", args: CreateBadge }
+| { name: "CreateEvent", args: CreateEvent }
+| { name: "CreateFolder", args: CreateFolder }
+| { name: "CreateGroupchat", args: CreateGroupchat }
+| { name: "CreateInvite", args: CreateInvite }
+| { name: "CreateLinkToken", args: CreateLinkToken }
+| { name: "CreatePoll", args: CreatePoll }
+| { name: "CreateTopic", args: CreateTopic }
+| { name: "CrosspostMessage", args: CrosspostMessage }
+| { name: "DeclineFriendRequest", args: DeclineFriendRequest }
+| { name: "DeleteAccount", args: DeleteAccount }
+| { name: "DeleteFolder", args: DeleteFolder }
+| { name: "DeleteGroupchat", args: DeleteGroupchat }
+| { name: "DeleteMessage", args: DeleteMessage }
+| { name: "DemoteMember", args: DemoteMember }
+| { name: "DismissReport", args: DismissReport }
+| { name: "EditMessage", args: EditMessage }
+| { name: "ExpireDisappearingMessages", args: ExpireDisappearingMessages }
+| { name: "ExpireMutes", args: ExpireMutes }
+| { name: "FireReminder", args: FireReminder }
+| { name: "ForwardMessage", args: ForwardMessage }
+| { name: "GenerateDailyDigests", args: GenerateDailyDigests }
+| { name: "Heartbeat", args: Heartbeat }
+| { name: "IdentityConnected", args: IdentityConnected }
+| { name: "IdentityDisconnected", args: IdentityDisconnected }
+| { name: "ImportMessages", args: ImportMessages }
+| { name: "JoinByCode", args: JoinByCode }
+| { name: "JoinBySlug", args: JoinBySlug }
+| { name: "JoinGroupchat", args: JoinGroupchat }
+| { name: "JoinViaInvite", args: JoinViaInvite }
+| { name: "KickMember", args: KickMember }
+| { name: "LeaveGroupchat", args: LeaveGroupchat }
+| { name: "LinkDevice", args: LinkDevice }
+| { name: "MarkNotificationRead", args: MarkNotificationRead }
+| { name: "MarkRead", args: MarkRead }
+| { name: "MuteMember", args: MuteMember }
+| { name: "PinMessage", args: PinMessage }
+| { name: "PromoteMember", args: PromoteMember }
+| { name: "PublishGroupKey", args: PublishGroupKey }
+| { name: "PurgeTypingIndicators", args: PurgeTypingIndicators }
+| { name: "RegenerateJoinCode", args: RegenerateJoinCode }
+| { name: "RegisterBot", args: RegisterBot }
+| { name: "RejectJoinRequest", args: RejectJoinRequest }
+| { name: "RemindMe", args: RemindMe }
+| { name: "RemoveBannedWord", args: RemoveBannedWord }
+| { name: "RemoveBookmark", args: RemoveBookmark }
+| { name: "RemoveChatFromFolder", args: RemoveChatFromFolder }
+| { name: "RemoveCustomEmoji", args: RemoveCustomEmoji }
+| { name: "RemoveFriend", args: RemoveFriend }
+| { name: "RemoveGroupTag", args: RemoveGroupTag }
+| { name: "RemoveReaction", args: RemoveReaction }
+| { name: "RemoveWebhook", args: RemoveWebhook }
+| { name: "ReorderChats", args: ReorderChats }
+| { name: "ReorderFolders", args: ReorderFolders }
+| { name: "ReplyToMessage", args: ReplyToMessage }
+| { name: "ReportMessage", args: ReportMessage }
+| { name: "RequestDataExport", args: RequestDataExport }
+| { name: "RequestToJoin", args: RequestToJoin }
+| { name: "ResetBrokenStreaks", args: ResetBrokenStreaks }
+| { name: "ResetLeaderboard", args: ResetLeaderboard }
+| { name: "ResolveReport", args: ResolveReport }
+| { name: "RevokeDevice", args: RevokeDevice }
+| { name: "RevokeInvite", args: RevokeInvite }
+| { name: "RollUpGroupStats", args: RollUpGroupStats }
+| { name: "Rsvp", args: Rsvp }
+| { name: "SaveDraft", args: SaveDraft }
+| { name: "SearchMessages", args: SearchMessages }
+| { name: "SearchUsers", args: SearchUsers }
+| { name: "SendDm", args: SendDm }
+| { name: "SendEncryptedMessage", args: SendEncryptedMessage }
+| { name: "SendEventReminder", args: SendEventReminder }
+| { name: "SendFriendRequest", args: SendFriendRequest }
+| { name: "SendMessage", args: SendMessage }
+| { name: "SendMessageWithAttachments", args: SendMessageWithAttachments }
+| { name: "SendNoteToSelf", args: SendNoteToSelf }
+| { name: "SetChatPin", args: SetChatPin }
+| { name: "SetDeletedAccountPolicy", args: SetDeletedAccountPolicy }
+| { name: "SetDiscoverable", args: SetDiscoverable }
+| { name: "SetGroupAvatar", args: SetGroupAvatar }
+| { name: "SetGroupChatName", args: SetGroupChatName }
+| { name: "SetGroupDescription", args: SetGroupDescription }
+| { name: "SetGroupNickname", args: SetGroupNickname }
+| { name: "SetGroupTopic", args: SetGroupTopic }
+| { name: "SetGroupVisibility", args: SetGroupVisibility }
+| { name: "SetMemberLimit", args: SetMemberLimit }
+| { name: "SetMessageTtl", args: SetMessageTtl }
+| { name: "SetName", args: SetName }
+| { name: "SetNotificationPref", args: SetNotificationPref }
+| { name: "SetNsfw", args: SetNsfw }
+| { name: "SetPermission", args: SetPermission }
+| { name: "SetPostPolicy", args: SetPostPolicy }
+| { name: "SetPresence", args: SetPresence }
+| { name: "SetRetentionDays", args: SetRetentionDays }
+| { name: "SetSessionHint", args: SetSessionHint }
+| { name: "SetSlowmode", args: SetSlowmode }
+| { name: "SetStrikePolicy", args: SetStrikePolicy }
+| { name: "SetVerified", args: SetVerified }
+| { name: "ShareGroupKey", args: ShareGroupKey }
+| { name: "SolveChallenge", args: SolveChallenge }
+| { name: "StartTyping", args: StartTyping }
+| { name: "StoreTranslation", args: StoreTranslation }
+| { name: "SweepPresence", args: SweepPresence }
+| { name: "TerminateSession", args: TerminateSession }
+| { name: "TransferOwnership", args: TransferOwnership }
+| { name: "UnarchiveGroupchat", args: UnarchiveGroupchat }
+| { name: "UnbanMember", args: UnbanMember }
+| { name: "UnblockUser", args: UnblockUser }
+| { name: "UnmuteMember", args: UnmuteMember }
+| { name: "UnpinMessage", args: UnpinMessage }
+| { name: "UnsendMessage", args: UnsendMessage }
+| { name: "UpdateConfig", args: UpdateConfig }
+| { name: "UpdateProfile", args: UpdateProfile }
+| { name: "UpdateSettings", args: UpdateSettings }
+| { name: "Vote", args: Vote }
+| { name: "WarnMember", args: WarnMember }
+;
+
+export class RemoteReducers {
+  constructor(private connection: __DbConnectionImpl, private setCallReducerFlags: SetReducerFlags) {}
+
+  acceptFriendRequest(requestId: bigint) {
+    const __args = { requestId };
+    let __writer = new __BinaryWriter(1024);
+    AcceptFriendRequest.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("accept_friend_request", __argsBuffer, this.setCallReducerFlags.acceptFriendRequestFlags);
+  }
+
+  onAcceptFriendRequest(callback: (ctx: ReducerEventContext, requestId: bigint) => void) {
+    this.connection.onReducer("accept_friend_request", callback);
+  }
+
+  removeOnAcceptFriendRequest(callback: (ctx: ReducerEventContext, requestId: bigint) => void) {
+    this.connection.offReducer("accept_friend_request", callback);
+  }
+
+  acceptTos(version: number) {
+    const __args = { version };
+    let __writer = new __BinaryWriter(1024);
+    AcceptTos.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("accept_tos", __argsBuffer, this.setCallReducerFlags.acceptTosFlags);
+  }
+
+  onAcceptTos(callback: (ctx: ReducerEventContext, version: number) => void) {
+    this.connection.onReducer("accept_tos", callback);
+  }
+
+  removeOnAcceptTos(callback: (ctx: ReducerEventContext, version: number) => void) {
+    this.connection.offReducer("accept_tos", callback);
+  }
+
+  ackDelivery(messageId: bigint) {
+    const __args = { messageId };
+    let __writer = new __BinaryWriter(1024);
+    AckDelivery.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("ack_delivery", __argsBuffer, this.setCallReducerFlags.ackDeliveryFlags);
+  }
+
+  onAckDelivery(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.onReducer("ack_delivery", callback);
+  }
+
+  removeOnAckDelivery(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.offReducer("ack_delivery", callback);
+  }
+
+  ackMention(mentionId: bigint) {
+    const __args = { mentionId };
+    let __writer = new __BinaryWriter(1024);
+    AckMention.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("ack_mention", __argsBuffer, this.setCallReducerFlags.ackMentionFlags);
+  }
+
+  onAckMention(callback: (ctx: ReducerEventContext, mentionId: bigint) => void) {
+    this.connection.onReducer("ack_mention", callback);
+  }
+
+  removeOnAckMention(callback: (ctx: ReducerEventContext, mentionId: bigint) => void) {
+    this.connection.offReducer("ack_mention", callback);
+  }
+
+  addBannedWord(groupchatId: string | undefined, word: string) {
+    const __args = { groupchatId, word };
+    let __writer = new __BinaryWriter(1024);
+    AddBannedWord.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_banned_word", __argsBuffer, this.setCallReducerFlags.addBannedWordFlags);
+  }
+
+  onAddBannedWord(callback: (ctx: ReducerEventContext, groupchatId: string | undefined, word: string) => void) {
+    this.connection.onReducer("add_banned_word", callback);
+  }
+
+  removeOnAddBannedWord(callback: (ctx: ReducerEventContext, groupchatId: string | undefined, word: string) => void) {
+    this.connection.offReducer("add_banned_word", callback);
+  }
+
+  addChatToFolder(folderId: bigint, groupchatId: string) {
+    const __args = { folderId, groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    AddChatToFolder.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_chat_to_folder", __argsBuffer, this.setCallReducerFlags.addChatToFolderFlags);
+  }
+
+  onAddChatToFolder(callback: (ctx: ReducerEventContext, folderId: bigint, groupchatId: string) => void) {
+    this.connection.onReducer("add_chat_to_folder", callback);
+  }
+
+  removeOnAddChatToFolder(callback: (ctx: ReducerEventContext, folderId: bigint, groupchatId: string) => void) {
+    this.connection.offReducer("add_chat_to_folder", callback);
+  }
+
+  addCustomEmoji(groupchatId: string, shortcode: string, imageUrl: string) {
+    const __args = { groupchatId, shortcode, imageUrl };
+    let __writer = new __BinaryWriter(1024);
+    AddCustomEmoji.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_custom_emoji", __argsBuffer, this.setCallReducerFlags.addCustomEmojiFlags);
+  }
+
+  onAddCustomEmoji(callback: (ctx: ReducerEventContext, groupchatId: string, shortcode: string, imageUrl: string) => void) {
+    this.connection.onReducer("add_custom_emoji", callback);
+  }
+
+  removeOnAddCustomEmoji(callback: (ctx: ReducerEventContext, groupchatId: string, shortcode: string, imageUrl: string) => void) {
+    this.connection.offReducer("add_custom_emoji", callback);
+  }
+
+  addGroupTag(groupchatId: string, tag: string) {
+    const __args = { groupchatId, tag };
+    let __writer = new __BinaryWriter(1024);
+    AddGroupTag.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_group_tag", __argsBuffer, this.setCallReducerFlags.addGroupTagFlags);
+  }
+
+  onAddGroupTag(callback: (ctx: ReducerEventContext, groupchatId: string, tag: string) => void) {
+    this.connection.onReducer("add_group_tag", callback);
+  }
+
+  removeOnAddGroupTag(callback: (ctx: ReducerEventContext, groupchatId: string, tag: string) => void) {
+    this.connection.offReducer("add_group_tag", callback);
+  }
+
+  addReaction(messageId: bigint, emoji: string) {
+    const __args = { messageId, emoji };
+    let __writer = new __BinaryWriter(1024);
+    AddReaction.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_reaction", __argsBuffer, this.setCallReducerFlags.addReactionFlags);
+  }
+
+  onAddReaction(callback: (ctx: ReducerEventContext, messageId: bigint, emoji: string) => void) {
+    this.connection.onReducer("add_reaction", callback);
+  }
+
+  removeOnAddReaction(callback: (ctx: ReducerEventContext, messageId: bigint, emoji: string) => void) {
+    this.connection.offReducer("add_reaction", callback);
+  }
+
+  addWebhook(groupchatId: string, url: string, secret: string, eventMask: number) {
+    const __args = { groupchatId, url, secret, eventMask };
+    let __writer = new __BinaryWriter(1024);
+    AddWebhook.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_webhook", __argsBuffer, this.setCallReducerFlags.addWebhookFlags);
+  }
+
+  onAddWebhook(callback: (ctx: ReducerEventContext, groupchatId: string, url: string, secret: string, eventMask: number) => void) {
+    this.connection.onReducer("add_webhook", callback);
+  }
+
+  removeOnAddWebhook(callback: (ctx: ReducerEventContext, groupchatId: string, url: string, secret: string, eventMask: number) => void) {
+    this.connection.offReducer("add_webhook", callback);
+  }
+
+  applyRetentionPolicies(cleanup: RetentionCleanup) {
+    const __args = { cleanup };
+    let __writer = new __BinaryWriter(1024);
+    ApplyRetentionPolicies.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("apply_retention_policies", __argsBuffer, this.setCallReducerFlags.applyRetentionPoliciesFlags);
+  }
+
+  onApplyRetentionPolicies(callback: (ctx: ReducerEventContext, cleanup: RetentionCleanup) => void) {
+    this.connection.onReducer("apply_retention_policies", callback);
+  }
+
+  removeOnApplyRetentionPolicies(callback: (ctx: ReducerEventContext, cleanup: RetentionCleanup) => void) {
+    this.connection.offReducer("apply_retention_policies", callback);
+  }
+
+  approveJoinRequest(requestId: bigint) {
+    const __args = { requestId };
+    let __writer = new __BinaryWriter(1024);
+    ApproveJoinRequest.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("approve_join_request", __argsBuffer, this.setCallReducerFlags.approveJoinRequestFlags);
+  }
+
+  onApproveJoinRequest(callback: (ctx: ReducerEventContext, requestId: bigint) => void) {
+    this.connection.onReducer("approve_join_request", callback);
+  }
+
+  removeOnApproveJoinRequest(callback: (ctx: ReducerEventContext, requestId: bigint) => void) {
+    this.connection.offReducer("approve_join_request", callback);
+  }
+
+  archiveGroupchat(groupchatId: string) {
+    const __args = { groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    ArchiveGroupchat.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("archive_groupchat", __argsBuffer, this.setCallReducerFlags.archiveGroupchatFlags);
+  }
+
+  onArchiveGroupchat(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.onReducer("archive_groupchat", callback);
+  }
+
+  removeOnArchiveGroupchat(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.offReducer("archive_groupchat", callback);
+  }
+
+  archiveTopic(topicId: bigint) {
+    const __args = { topicId };
+    let __writer = new __BinaryWriter(1024);
+    ArchiveTopic.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("archive_topic", __argsBuffer, this.setCallReducerFlags.archiveTopicFlags);
+  }
+
+  onArchiveTopic(callback: (ctx: ReducerEventContext, topicId: bigint) => void) {
+    this.connection.onReducer("archive_topic", callback);
+  }
+
+  removeOnArchiveTopic(callback: (ctx: ReducerEventContext, topicId: bigint) => void) {
+    this.connection.offReducer("archive_topic", callback);
+  }
+
+  awardBadge(badgeId: bigint, target: __Identity) {
+    const __args = { badgeId, target };
+    let __writer = new __BinaryWriter(1024);
+    AwardBadge.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("award_badge", __argsBuffer, this.setCallReducerFlags.awardBadgeFlags);
+  }
+
+  onAwardBadge(callback: (ctx: ReducerEventContext, badgeId: bigint, target: __Identity) => void) {
+    this.connection.onReducer("award_badge", callback);
+  }
+
+  removeOnAwardBadge(callback: (ctx: ReducerEventContext, badgeId: bigint, target: __Identity) => void) {
+    this.connection.offReducer("award_badge", callback);
+  }
+
+  awardPoints(target: __Identity, amount: number, reason: string | undefined) {
+    const __args = { target, amount, reason };
+    let __writer = new __BinaryWriter(1024);
+    AwardPoints.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("award_points", __argsBuffer, this.setCallReducerFlags.awardPointsFlags);
+  }
+
+  onAwardPoints(callback: (ctx: ReducerEventContext, target: __Identity, amount: number, reason: string | undefined) => void) {
+    this.connection.onReducer("award_points", callback);
+  }
+
+  removeOnAwardPoints(callback: (ctx: ReducerEventContext, target: __Identity, amount: number, reason: string | undefined) => void) {
+    this.connection.offReducer("award_points", callback);
+  }
+
+  banMember(groupchatId: string, target: __Identity, reason: string | undefined) {
+    const __args = { groupchatId, target, reason };
+    let __writer = new __BinaryWriter(1024);
+    BanMember.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("ban_member", __argsBuffer, this.setCallReducerFlags.banMemberFlags);
+  }
+
+  onBanMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity, reason: string | undefined) => void) {
+    this.connection.onReducer("ban_member", callback);
+  }
+
+  removeOnBanMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity, reason: string | undefined) => void) {
+    this.connection.offReducer("ban_member", callback);
+  }
+
+  blockUser(target: __Identity) {
+    const __args = { target };
+    let __writer = new __BinaryWriter(1024);
+    BlockUser.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("block_user", __argsBuffer, this.setCallReducerFlags.blockUserFlags);
+  }
+
+  onBlockUser(callback: (ctx: ReducerEventContext, target: __Identity) => void) {
+    this.connection.onReducer("block_user", callback);
+  }
+
+  removeOnBlockUser(callback: (ctx: ReducerEventContext, target: __Identity) => void) {
+    this.connection.offReducer("block_user", callback);
+  }
+
+  bookmarkMessage(messageId: bigint) {
+    const __args = { messageId };
+    let __writer = new __BinaryWriter(1024);
+    BookmarkMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("bookmark_message", __argsBuffer, this.setCallReducerFlags.bookmarkMessageFlags);
+  }
+
+  onBookmarkMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.onReducer("bookmark_message", callback);
+  }
+
+  removeOnBookmarkMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.offReducer("bookmark_message", callback);
+  }
+
+  broadcast(text: string) {
+    const __args = { text };
+    let __writer = new __BinaryWriter(1024);
+    Broadcast.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("broadcast", __argsBuffer, this.setCallReducerFlags.broadcastFlags);
+  }
+
+  onBroadcast(callback: (ctx: ReducerEventContext, text: string) => void) {
+    this.connection.onReducer("broadcast", callback);
+  }
+
+  removeOnBroadcast(callback: (ctx: ReducerEventContext, text: string) => void) {
+    this.connection.offReducer("broadcast", callback);
+  }
+
+  cancelEvent(eventId: bigint) {
+    const __args = { eventId };
+    let __writer = new __BinaryWriter(1024);
+    CancelEvent.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("cancel_event", __argsBuffer, this.setCallReducerFlags.cancelEventFlags);
+  }
+
+  onCancelEvent(callback: (ctx: ReducerEventContext, eventId: bigint) => void) {
+    this.connection.onReducer("cancel_event", callback);
+  }
+
+  removeOnCancelEvent(callback: (ctx: ReducerEventContext, eventId: bigint) => void) {
+    this.connection.offReducer("cancel_event", callback);
+  }
+
+  clearDraft(groupchatId: string) {
+    const __args = { groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    ClearDraft.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("clear_draft", __argsBuffer, this.setCallReducerFlags.clearDraftFlags);
+  }
+
+  onClearDraft(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.onReducer("clear_draft", callback);
+  }
+
+  removeOnClearDraft(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.offReducer("clear_draft", callback);
+  }
+
+  clearNotifications() {
+    const __args = {  };
+    let __writer = new __BinaryWriter(1024);
+    ClearNotifications.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("clear_notifications", __argsBuffer, this.setCallReducerFlags.clearNotificationsFlags);
+  }
+
+  onClearNotifications(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("clear_notifications", callback);
+  }
+
+  removeOnClearNotifications(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("clear_notifications", callback);
+  }
+
+  closePoll(pollId: bigint) {
+    const __args = { pollId };
+    let __writer = new __BinaryWriter(1024);
+    ClosePoll.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("close_poll", __argsBuffer, this.setCallReducerFlags.closePollFlags);
+  }
+
+  onClosePoll(callback: (ctx: ReducerEventContext, pollId: bigint) => void) {
+    this.connection.onReducer("close_poll", callback);
+  }
+
+  removeOnClosePoll(callback: (ctx: ReducerEventContext, pollId: bigint) => void) {
+    this.connection.offReducer("close_poll", callback);
+  }
+
+  createBadge(groupchatId: string, name: string, description: string | undefined, iconUrl: string | undefined) {
+    const __args = { groupchatId, name, description, iconUrl };
+    let __writer = new __BinaryWriter(1024);
+    CreateBadge.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_badge", __argsBuffer, this.setCallReducerFlags.createBadgeFlags);
+  }
+
+  onCreateBadge(callback: (ctx: ReducerEventContext, groupchatId: string, name: string, description: string | undefined, iconUrl: string | undefined) => void) {
+    this.connection.onReducer("create_badge", callback);
+  }
+
+  removeOnCreateBadge(callback: (ctx: ReducerEventContext, groupchatId: string, name: string, description: string | undefined, iconUrl: string | undefined) => void) {
+    this.connection.offReducer("create_badge", callback);
+  }
+
+  createEvent(groupchatId: string, title: string, startsAt: __Timestamp) {
+    const __args = { groupchatId, title, startsAt };
+    let __writer = new __BinaryWriter(1024);
+    CreateEvent.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_event", __argsBuffer, this.setCallReducerFlags.createEventFlags);
+  }
+
+  onCreateEvent(callback: (ctx: ReducerEventContext, groupchatId: string, title: string, startsAt: __Timestamp) => void) {
+    this.connection.onReducer("create_event", callback);
+  }
+
+  removeOnCreateEvent(callback: (ctx: ReducerEventContext, groupchatId: string, title: string, startsAt: __Timestamp) => void) {
+    this.connection.offReducer("create_event", callback);
+  }
+
+  createFolder(name: string) {
+    const __args = { name };
+    let __writer = new __BinaryWriter(1024);
+    CreateFolder.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_folder", __argsBuffer, this.setCallReducerFlags.createFolderFlags);
+  }
+
+  onCreateFolder(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.onReducer("create_folder", callback);
+  }
+
+  removeOnCreateFolder(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.offReducer("create_folder", callback);
+  }
+
+  createGroupchat(name: string) {
+    const __args = { name };
+    let __writer = new __BinaryWriter(1024);
+    CreateGroupchat.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_groupchat", __argsBuffer, this.setCallReducerFlags.createGroupchatFlags);
+  }
+
+  onCreateGroupchat(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.onReducer("create_groupchat", callback);
+  }
+
+  removeOnCreateGroupchat(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.offReducer("create_groupchat", callback);
+  }
+
+  createInvite(groupchatId: string, expiresInSecs: bigint | undefined, maxUses: number | undefined) {
+    const __args = { groupchatId, expiresInSecs, maxUses };
+    let __writer = new __BinaryWriter(1024);
+    CreateInvite.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_invite", __argsBuffer, this.setCallReducerFlags.createInviteFlags);
+  }
+
+  onCreateInvite(callback: (ctx: ReducerEventContext, groupchatId: string, expiresInSecs: bigint | undefined, maxUses: number | undefined) => void) {
+    this.connection.onReducer("create_invite", callback);
+  }
+
+  removeOnCreateInvite(callback: (ctx: ReducerEventContext, groupchatId: string, expiresInSecs: bigint | undefined, maxUses: number | undefined) => void) {
+    this.connection.offReducer("create_invite", callback);
+  }
+
+  createLinkToken() {
+    const __args = {  };
+    let __writer = new __BinaryWriter(1024);
+    CreateLinkToken.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_link_token", __argsBuffer, this.setCallReducerFlags.createLinkTokenFlags);
+  }
+
+  onCreateLinkToken(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("create_link_token", callback);
+  }
+
+  removeOnCreateLinkToken(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("create_link_token", callback);
+  }
+
+  createPoll(groupchatId: string, question: string, options: string[]) {
+    const __args = { groupchatId, question, options };
+    let __writer = new __BinaryWriter(1024);
+    CreatePoll.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_poll", __argsBuffer, this.setCallReducerFlags.createPollFlags);
+  }
+
+  onCreatePoll(callback: (ctx: ReducerEventContext, groupchatId: string, question: string, options: string[]) => void) {
+    this.connection.onReducer("create_poll", callback);
+  }
+
+  removeOnCreatePoll(callback: (ctx: ReducerEventContext, groupchatId: string, question: string, options: string[]) => void) {
+    this.connection.offReducer("create_poll", callback);
+  }
+
+  createTopic(groupchatId: string, name: string) {
+    const __args = { groupchatId, name };
+    let __writer = new __BinaryWriter(1024);
+    CreateTopic.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_topic", __argsBuffer, this.setCallReducerFlags.createTopicFlags);
+  }
+
+  onCreateTopic(callback: (ctx: ReducerEventContext, groupchatId: string, name: string) => void) {
+    this.connection.onReducer("create_topic", callback);
+  }
+
+  removeOnCreateTopic(callback: (ctx: ReducerEventContext, groupchatId: string, name: string) => void) {
+    this.connection.offReducer("create_topic", callback);
+  }
+
+  crosspostMessage(messageId: bigint, targetGroups: string[]) {
+    const __args = { messageId, targetGroups };
+    let __writer = new __BinaryWriter(1024);
+    CrosspostMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("crosspost_message", __argsBuffer, this.setCallReducerFlags.crosspostMessageFlags);
+  }
+
+  onCrosspostMessage(callback: (ctx: ReducerEventContext, messageId: bigint, targetGroups: string[]) => void) {
+    this.connection.onReducer("crosspost_message", callback);
+  }
+
+  removeOnCrosspostMessage(callback: (ctx: ReducerEventContext, messageId: bigint, targetGroups: string[]) => void) {
+    this.connection.offReducer("crosspost_message", callback);
+  }
+
+  declineFriendRequest(requestId: bigint) {
+    const __args = { requestId };
+    let __writer = new __BinaryWriter(1024);
+    DeclineFriendRequest.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("decline_friend_request", __argsBuffer, this.setCallReducerFlags.declineFriendRequestFlags);
+  }
+
+  onDeclineFriendRequest(callback: (ctx: ReducerEventContext, requestId: bigint) => void) {
+    this.connection.onReducer("decline_friend_request", callback);
+  }
+
+  removeOnDeclineFriendRequest(callback: (ctx: ReducerEventContext, requestId: bigint) => void) {
+    this.connection.offReducer("decline_friend_request", callback);
+  }
+
+  deleteAccount() {
+    const __args = {  };
+    let __writer = new __BinaryWriter(1024);
+    DeleteAccount.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_account", __argsBuffer, this.setCallReducerFlags.deleteAccountFlags);
+  }
+
+  onDeleteAccount(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("delete_account", callback);
+  }
+
+  removeOnDeleteAccount(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("delete_account", callback);
+  }
+
+  deleteFolder(folderId: bigint) {
+    const __args = { folderId };
+    let __writer = new __BinaryWriter(1024);
+    DeleteFolder.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_folder", __argsBuffer, this.setCallReducerFlags.deleteFolderFlags);
+  }
+
+  onDeleteFolder(callback: (ctx: ReducerEventContext, folderId: bigint) => void) {
+    this.connection.onReducer("delete_folder", callback);
+  }
+
+  removeOnDeleteFolder(callback: (ctx: ReducerEventContext, folderId: bigint) => void) {
+    this.connection.offReducer("delete_folder", callback);
+  }
+
+  deleteGroupchat(groupchatId: string) {
+    const __args = { groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    DeleteGroupchat.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_groupchat", __argsBuffer, this.setCallReducerFlags.deleteGroupchatFlags);
+  }
+
+  onDeleteGroupchat(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.onReducer("delete_groupchat", callback);
+  }
+
+  removeOnDeleteGroupchat(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.offReducer("delete_groupchat", callback);
+  }
+
+  deleteMessage(messageId: bigint) {
+    const __args = { messageId };
+    let __writer = new __BinaryWriter(1024);
+    DeleteMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_message", __argsBuffer, this.setCallReducerFlags.deleteMessageFlags);
+  }
+
+  onDeleteMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.onReducer("delete_message", callback);
+  }
+
+  removeOnDeleteMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.offReducer("delete_message", callback);
+  }
+
+  demoteMember(groupchatId: string, target: __Identity) {
+    const __args = { groupchatId, target };
+    let __writer = new __BinaryWriter(1024);
+    DemoteMember.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("demote_member", __argsBuffer, this.setCallReducerFlags.demoteMemberFlags);
+  }
+
+  onDemoteMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.onReducer("demote_member", callback);
+  }
+
+  removeOnDemoteMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.offReducer("demote_member", callback);
+  }
+
+  dismissReport(reportId: bigint) {
+    const __args = { reportId };
+    let __writer = new __BinaryWriter(1024);
+    DismissReport.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("dismiss_report", __argsBuffer, this.setCallReducerFlags.dismissReportFlags);
+  }
+
+  onDismissReport(callback: (ctx: ReducerEventContext, reportId: bigint) => void) {
+    this.connection.onReducer("dismiss_report", callback);
+  }
+
+  removeOnDismissReport(callback: (ctx: ReducerEventContext, reportId: bigint) => void) {
+    this.connection.offReducer("dismiss_report", callback);
+  }
+
+  editMessage(messageId: bigint, newText: string) {
+    const __args = { messageId, newText };
+    let __writer = new __BinaryWriter(1024);
+    EditMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("edit_message", __argsBuffer, this.setCallReducerFlags.editMessageFlags);
+  }
+
+  onEditMessage(callback: (ctx: ReducerEventContext, messageId: bigint, newText: string) => void) {
+    this.connection.onReducer("edit_message", callback);
+  }
+
+  removeOnEditMessage(callback: (ctx: ReducerEventContext, messageId: bigint, newText: string) => void) {
+    this.connection.offReducer("edit_message", callback);
+  }
+
+  expireDisappearingMessages(sweep: MessageExpirySweep) {
+    const __args = { sweep };
+    let __writer = new __BinaryWriter(1024);
+    ExpireDisappearingMessages.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("expire_disappearing_messages", __argsBuffer, this.setCallReducerFlags.expireDisappearingMessagesFlags);
+  }
+
+  onExpireDisappearingMessages(callback: (ctx: ReducerEventContext, sweep: MessageExpirySweep) => void) {
+    this.connection.onReducer("expire_disappearing_messages", callback);
+  }
+
+  removeOnExpireDisappearingMessages(callback: (ctx: ReducerEventContext, sweep: MessageExpirySweep) => void) {
+    this.connection.offReducer("expire_disappearing_messages", callback);
+  }
+
+  expireMutes(sweep: MuteExpirySweep) {
+    const __args = { sweep };
+    let __writer = new __BinaryWriter(1024);
+    ExpireMutes.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("expire_mutes", __argsBuffer, this.setCallReducerFlags.expireMutesFlags);
+  }
+
+  onExpireMutes(callback: (ctx: ReducerEventContext, sweep: MuteExpirySweep) => void) {
+    this.connection.onReducer("expire_mutes", callback);
+  }
+
+  removeOnExpireMutes(callback: (ctx: ReducerEventContext, sweep: MuteExpirySweep) => void) {
+    this.connection.offReducer("expire_mutes", callback);
+  }
+
+  fireReminder(reminder: Reminder) {
+    const __args = { reminder };
+    let __writer = new __BinaryWriter(1024);
+    FireReminder.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("fire_reminder", __argsBuffer, this.setCallReducerFlags.fireReminderFlags);
+  }
+
+  onFireReminder(callback: (ctx: ReducerEventContext, reminder: Reminder) => void) {
+    this.connection.onReducer("fire_reminder", callback);
+  }
+
+  removeOnFireReminder(callback: (ctx: ReducerEventContext, reminder: Reminder) => void) {
+    this.connection.offReducer("fire_reminder", callback);
+  }
+
+  forwardMessage(messageId: bigint, targetGroupchatId: string) {
+    const __args = { messageId, targetGroupchatId };
+    let __writer = new __BinaryWriter(1024);
+    ForwardMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("forward_message", __argsBuffer, this.setCallReducerFlags.forwardMessageFlags);
+  }
+
+  onForwardMessage(callback: (ctx: ReducerEventContext, messageId: bigint, targetGroupchatId: string) => void) {
+    this.connection.onReducer("forward_message", callback);
+  }
+
+  removeOnForwardMessage(callback: (ctx: ReducerEventContext, messageId: bigint, targetGroupchatId: string) => void) {
+    this.connection.offReducer("forward_message", callback);
+  }
+
+  generateDailyDigests(schedule: DailyDigestSchedule) {
+    const __args = { schedule };
+    let __writer = new __BinaryWriter(1024);
+    GenerateDailyDigests.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("generate_daily_digests", __argsBuffer, this.setCallReducerFlags.generateDailyDigestsFlags);
+  }
+
+  onGenerateDailyDigests(callback: (ctx: ReducerEventContext, schedule: DailyDigestSchedule) => void) {
+    this.connection.onReducer("generate_daily_digests", callback);
+  }
+
+  removeOnGenerateDailyDigests(callback: (ctx: ReducerEventContext, schedule: DailyDigestSchedule) => void) {
+    this.connection.offReducer("generate_daily_digests", callback);
+  }
+
+  heartbeat() {
+    const __args = {  };
+    let __writer = new __BinaryWriter(1024);
+    Heartbeat.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("heartbeat", __argsBuffer, this.setCallReducerFlags.heartbeatFlags);
+  }
+
+  onHeartbeat(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("heartbeat", callback);
+  }
+
+  removeOnHeartbeat(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("heartbeat", callback);
+  }
+
+  onIdentityConnected(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("identity_connected", callback);
+  }
+
+  removeOnIdentityConnected(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("identity_connected", callback);
+  }
+
+  onIdentityDisconnected(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("identity_disconnected", callback);
+  }
+
+  removeOnIdentityDisconnected(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("identity_disconnected", callback);
+  }
+
+  importMessages(groupchatId: string, batch: ImportedMessage[]) {
+    const __args = { groupchatId, batch };
+    let __writer = new __BinaryWriter(1024);
+    ImportMessages.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("import_messages", __argsBuffer, this.setCallReducerFlags.importMessagesFlags);
+  }
+
+  onImportMessages(callback: (ctx: ReducerEventContext, groupchatId: string, batch: ImportedMessage[]) => void) {
+    this.connection.onReducer("import_messages", callback);
+  }
+
+  removeOnImportMessages(callback: (ctx: ReducerEventContext, groupchatId: string, batch: ImportedMessage[]) => void) {
+    this.connection.offReducer("import_messages", callback);
+  }
+
+  joinByCode(joinCode: string) {
+    const __args = { joinCode };
+    let __writer = new __BinaryWriter(1024);
+    JoinByCode.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("join_by_code", __argsBuffer, this.setCallReducerFlags.joinByCodeFlags);
+  }
+
+  onJoinByCode(callback: (ctx: ReducerEventContext, joinCode: string) => void) {
+    this.connection.onReducer("join_by_code", callback);
+  }
+
+  removeOnJoinByCode(callback: (ctx: ReducerEventContext, joinCode: string) => void) {
+    this.connection.offReducer("join_by_code", callback);
+  }
+
+  joinBySlug(slug: string) {
+    const __args = { slug };
+    let __writer = new __BinaryWriter(1024);
+    JoinBySlug.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("join_by_slug", __argsBuffer, this.setCallReducerFlags.joinBySlugFlags);
+  }
+
+  onJoinBySlug(callback: (ctx: ReducerEventContext, slug: string) => void) {
+    this.connection.onReducer("join_by_slug", callback);
+  }
+
+  removeOnJoinBySlug(callback: (ctx: ReducerEventContext, slug: string) => void) {
+    this.connection.offReducer("join_by_slug", callback);
+  }
+
+  joinGroupchat(groupchat: string) {
+    const __args = { groupchat };
+    let __writer = new __BinaryWriter(1024);
+    JoinGroupchat.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("join_groupchat", __argsBuffer, this.setCallReducerFlags.joinGroupchatFlags);
+  }
+
+  onJoinGroupchat(callback: (ctx: ReducerEventContext, groupchat: string) => void) {
+    this.connection.onReducer("join_groupchat", callback);
+  }
+
+  removeOnJoinGroupchat(callback: (ctx: ReducerEventContext, groupchat: string) => void) {
+    this.connection.offReducer("join_groupchat", callback);
+  }
+
+  joinViaInvite(code: string) {
+    const __args = { code };
+    let __writer = new __BinaryWriter(1024);
+    JoinViaInvite.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("join_via_invite", __argsBuffer, this.setCallReducerFlags.joinViaInviteFlags);
+  }
+
+  onJoinViaInvite(callback: (ctx: ReducerEventContext, code: string) => void) {
+    this.connection.onReducer("join_via_invite", callback);
+  }
+
+  removeOnJoinViaInvite(callback: (ctx: ReducerEventContext, code: string) => void) {
+    this.connection.offReducer("join_via_invite", callback);
+  }
+
+  kickMember(groupchatId: string, target: __Identity) {
+    const __args = { groupchatId, target };
+    let __writer = new __BinaryWriter(1024);
+    KickMember.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("kick_member", __argsBuffer, this.setCallReducerFlags.kickMemberFlags);
+  }
+
+  onKickMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.onReducer("kick_member", callback);
+  }
+
+  removeOnKickMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.offReducer("kick_member", callback);
+  }
+
+  leaveGroupchat(groupchatId: string) {
+    const __args = { groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    LeaveGroupchat.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("leave_groupchat", __argsBuffer, this.setCallReducerFlags.leaveGroupchatFlags);
+  }
+
+  onLeaveGroupchat(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.onReducer("leave_groupchat", callback);
+  }
+
+  removeOnLeaveGroupchat(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.offReducer("leave_groupchat", callback);
+  }
+
+  linkDevice(linkToken: string) {
+    const __args = { linkToken };
+    let __writer = new __BinaryWriter(1024);
+    LinkDevice.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("link_device", __argsBuffer, this.setCallReducerFlags.linkDeviceFlags);
+  }
+
+  onLinkDevice(callback: (ctx: ReducerEventContext, linkToken: string) => void) {
+    this.connection.onReducer("link_device", callback);
+  }
+
+  removeOnLinkDevice(callback: (ctx: ReducerEventContext, linkToken: string) => void) {
+    this.connection.offReducer("link_device", callback);
+  }
+
+  markNotificationRead(notificationId: bigint) {
+    const __args = { notificationId };
+    let __writer = new __BinaryWriter(1024);
+    MarkNotificationRead.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("mark_notification_read", __argsBuffer, this.setCallReducerFlags.markNotificationReadFlags);
+  }
+
+  onMarkNotificationRead(callback: (ctx: ReducerEventContext, notificationId: bigint) => void) {
+    this.connection.onReducer("mark_notification_read", callback);
+  }
+
+  removeOnMarkNotificationRead(callback: (ctx: ReducerEventContext, notificationId: bigint) => void) {
+    this.connection.offReducer("mark_notification_read", callback);
+  }
+
+  markRead(groupchatId: string, upTo: bigint) {
+    const __args = { groupchatId, upTo };
+    let __writer = new __BinaryWriter(1024);
+    MarkRead.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("mark_read", __argsBuffer, this.setCallReducerFlags.markReadFlags);
+  }
+
+  onMarkRead(callback: (ctx: ReducerEventContext, groupchatId: string, upTo: bigint) => void) {
+    this.connection.onReducer("mark_read", callback);
+  }
+
+  removeOnMarkRead(callback: (ctx: ReducerEventContext, groupchatId: string, upTo: bigint) => void) {
+    this.connection.offReducer("mark_read", callback);
+  }
+
+  muteMember(groupchatId: string, target: __Identity, durationSecs: bigint) {
+    const __args = { groupchatId, target, durationSecs };
+    let __writer = new __BinaryWriter(1024);
+    MuteMember.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("mute_member", __argsBuffer, this.setCallReducerFlags.muteMemberFlags);
+  }
+
+  onMuteMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity, durationSecs: bigint) => void) {
+    this.connection.onReducer("mute_member", callback);
+  }
+
+  removeOnMuteMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity, durationSecs: bigint) => void) {
+    this.connection.offReducer("mute_member", callback);
+  }
+
+  pinMessage(messageId: bigint) {
+    const __args = { messageId };
+    let __writer = new __BinaryWriter(1024);
+    PinMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("pin_message", __argsBuffer, this.setCallReducerFlags.pinMessageFlags);
+  }
+
+  onPinMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.onReducer("pin_message", callback);
+  }
+
+  removeOnPinMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.offReducer("pin_message", callback);
+  }
+
+  promoteMember(groupchatId: string, target: __Identity) {
+    const __args = { groupchatId, target };
+    let __writer = new __BinaryWriter(1024);
+    PromoteMember.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("promote_member", __argsBuffer, this.setCallReducerFlags.promoteMemberFlags);
+  }
+
+  onPromoteMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.onReducer("promote_member", callback);
+  }
+
+  removeOnPromoteMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.offReducer("promote_member", callback);
+  }
+
+  publishGroupKey(groupchatId: string, epoch: bigint, wrappedKeys: WrappedKey[]) {
+    const __args = { groupchatId, epoch, wrappedKeys };
+    let __writer = new __BinaryWriter(1024);
+    PublishGroupKey.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("publish_group_key", __argsBuffer, this.setCallReducerFlags.publishGroupKeyFlags);
+  }
+
+  onPublishGroupKey(callback: (ctx: ReducerEventContext, groupchatId: string, epoch: bigint, wrappedKeys: WrappedKey[]) => void) {
+    this.connection.onReducer("publish_group_key", callback);
+  }
+
+  removeOnPublishGroupKey(callback: (ctx: ReducerEventContext, groupchatId: string, epoch: bigint, wrappedKeys: WrappedKey[]) => void) {
+    this.connection.offReducer("publish_group_key", callback);
+  }
+
+  purgeTypingIndicators(cleanup: TypingIndicatorCleanup) {
+    const __args = { cleanup };
+    let __writer = new __BinaryWriter(1024);
+    PurgeTypingIndicators.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("purge_typing_indicators", __argsBuffer, this.setCallReducerFlags.purgeTypingIndicatorsFlags);
+  }
+
+  onPurgeTypingIndicators(callback: (ctx: ReducerEventContext, cleanup: TypingIndicatorCleanup) => void) {
+    this.connection.onReducer("purge_typing_indicators", callback);
+  }
+
+  removeOnPurgeTypingIndicators(callback: (ctx: ReducerEventContext, cleanup: TypingIndicatorCleanup) => void) {
+    this.connection.offReducer("purge_typing_indicators", callback);
+  }
+
+  regenerateJoinCode(groupchatId: string) {
+    const __args = { groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    RegenerateJoinCode.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("regenerate_join_code", __argsBuffer, this.setCallReducerFlags.regenerateJoinCodeFlags);
+  }
+
+  onRegenerateJoinCode(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.onReducer("regenerate_join_code", callback);
+  }
+
+  removeOnRegenerateJoinCode(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.offReducer("regenerate_join_code", callback);
+  }
+
+  registerBot(bot: __Identity, name: string) {
+    const __args = { bot, name };
+    let __writer = new __BinaryWriter(1024);
+    RegisterBot.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("register_bot", __argsBuffer, this.setCallReducerFlags.registerBotFlags);
+  }
+
+  onRegisterBot(callback: (ctx: ReducerEventContext, bot: __Identity, name: string) => void) {
+    this.connection.onReducer("register_bot", callback);
+  }
+
+  removeOnRegisterBot(callback: (ctx: ReducerEventContext, bot: __Identity, name: string) => void) {
+    this.connection.offReducer("register_bot", callback);
+  }
+
+  rejectJoinRequest(requestId: bigint) {
+    const __args = { requestId };
+    let __writer = new __BinaryWriter(1024);
+    RejectJoinRequest.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("reject_join_request", __argsBuffer, this.setCallReducerFlags.rejectJoinRequestFlags);
+  }
+
+  onRejectJoinRequest(callback: (ctx: ReducerEventContext, requestId: bigint) => void) {
+    this.connection.onReducer("reject_join_request", callback);
+  }
+
+  removeOnRejectJoinRequest(callback: (ctx: ReducerEventContext, requestId: bigint) => void) {
+    this.connection.offReducer("reject_join_request", callback);
+  }
+
+  remindMe(groupchatId: string, messageId: bigint, afterSecs: bigint) {
+    const __args = { groupchatId, messageId, afterSecs };
+    let __writer = new __BinaryWriter(1024);
+    RemindMe.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remind_me", __argsBuffer, this.setCallReducerFlags.remindMeFlags);
+  }
+
+  onRemindMe(callback: (ctx: ReducerEventContext, groupchatId: string, messageId: bigint, afterSecs: bigint) => void) {
+    this.connection.onReducer("remind_me", callback);
+  }
+
+  removeOnRemindMe(callback: (ctx: ReducerEventContext, groupchatId: string, messageId: bigint, afterSecs: bigint) => void) {
+    this.connection.offReducer("remind_me", callback);
+  }
+
+  removeBannedWord(bannedWordId: bigint) {
+    const __args = { bannedWordId };
+    let __writer = new __BinaryWriter(1024);
+    RemoveBannedWord.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_banned_word", __argsBuffer, this.setCallReducerFlags.removeBannedWordFlags);
+  }
+
+  onRemoveBannedWord(callback: (ctx: ReducerEventContext, bannedWordId: bigint) => void) {
+    this.connection.onReducer("remove_banned_word", callback);
+  }
+
+  removeOnRemoveBannedWord(callback: (ctx: ReducerEventContext, bannedWordId: bigint) => void) {
+    this.connection.offReducer("remove_banned_word", callback);
+  }
+
+  removeBookmark(messageId: bigint) {
+    const __args = { messageId };
+    let __writer = new __BinaryWriter(1024);
+    RemoveBookmark.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_bookmark", __argsBuffer, this.setCallReducerFlags.removeBookmarkFlags);
+  }
+
+  onRemoveBookmark(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.onReducer("remove_bookmark", callback);
+  }
+
+  removeOnRemoveBookmark(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.offReducer("remove_bookmark", callback);
+  }
+
+  removeChatFromFolder(folderId: bigint, groupchatId: string) {
+    const __args = { folderId, groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    RemoveChatFromFolder.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_chat_from_folder", __argsBuffer, this.setCallReducerFlags.removeChatFromFolderFlags);
+  }
+
+  onRemoveChatFromFolder(callback: (ctx: ReducerEventContext, folderId: bigint, groupchatId: string) => void) {
+    this.connection.onReducer("remove_chat_from_folder", callback);
+  }
+
+  removeOnRemoveChatFromFolder(callback: (ctx: ReducerEventContext, folderId: bigint, groupchatId: string) => void) {
+    this.connection.offReducer("remove_chat_from_folder", callback);
+  }
+
+  removeCustomEmoji(customEmojiId: bigint) {
+    const __args = { customEmojiId };
+    let __writer = new __BinaryWriter(1024);
+    RemoveCustomEmoji.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_custom_emoji", __argsBuffer, this.setCallReducerFlags.removeCustomEmojiFlags);
+  }
+
+  onRemoveCustomEmoji(callback: (ctx: ReducerEventContext, customEmojiId: bigint) => void) {
+    this.connection.onReducer("remove_custom_emoji", callback);
+  }
+
+  removeOnRemoveCustomEmoji(callback: (ctx: ReducerEventContext, customEmojiId: bigint) => void) {
+    this.connection.offReducer("remove_custom_emoji", callback);
+  }
+
+  removeFriend(friend: __Identity) {
+    const __args = { friend };
+    let __writer = new __BinaryWriter(1024);
+    RemoveFriend.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_friend", __argsBuffer, this.setCallReducerFlags.removeFriendFlags);
+  }
+
+  onRemoveFriend(callback: (ctx: ReducerEventContext, friend: __Identity) => void) {
+    this.connection.onReducer("remove_friend", callback);
+  }
+
+  removeOnRemoveFriend(callback: (ctx: ReducerEventContext, friend: __Identity) => void) {
+    this.connection.offReducer("remove_friend", callback);
+  }
+
+  removeGroupTag(groupchatId: string, tag: string) {
+    const __args = { groupchatId, tag };
+    let __writer = new __BinaryWriter(1024);
+    RemoveGroupTag.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_group_tag", __argsBuffer, this.setCallReducerFlags.removeGroupTagFlags);
+  }
+
+  onRemoveGroupTag(callback: (ctx: ReducerEventContext, groupchatId: string, tag: string) => void) {
+    this.connection.onReducer("remove_group_tag", callback);
+  }
+
+  removeOnRemoveGroupTag(callback: (ctx: ReducerEventContext, groupchatId: string, tag: string) => void) {
+    this.connection.offReducer("remove_group_tag", callback);
+  }
+
+  removeReaction(messageId: bigint, emoji: string) {
+    const __args = { messageId, emoji };
+    let __writer = new __BinaryWriter(1024);
+    RemoveReaction.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_reaction", __argsBuffer, this.setCallReducerFlags.removeReactionFlags);
+  }
+
+  onRemoveReaction(callback: (ctx: ReducerEventContext, messageId: bigint, emoji: string) => void) {
+    this.connection.onReducer("remove_reaction", callback);
+  }
+
+  removeOnRemoveReaction(callback: (ctx: ReducerEventContext, messageId: bigint, emoji: string) => void) {
+    this.connection.offReducer("remove_reaction", callback);
+  }
+
+  removeWebhook(webhookId: bigint) {
+    const __args = { webhookId };
+    let __writer = new __BinaryWriter(1024);
+    RemoveWebhook.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_webhook", __argsBuffer, this.setCallReducerFlags.removeWebhookFlags);
+  }
+
+  onRemoveWebhook(callback: (ctx: ReducerEventContext, webhookId: bigint) => void) {
+    this.connection.onReducer("remove_webhook", callback);
+  }
+
+  removeOnRemoveWebhook(callback: (ctx: ReducerEventContext, webhookId: bigint) => void) {
+    this.connection.offReducer("remove_webhook", callback);
+  }
+
+  reorderChats(groupchatIds: string[]) {
+    const __args = { groupchatIds };
+    let __writer = new __BinaryWriter(1024);
+    ReorderChats.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("reorder_chats", __argsBuffer, this.setCallReducerFlags.reorderChatsFlags);
+  }
+
+  onReorderChats(callback: (ctx: ReducerEventContext, groupchatIds: string[]) => void) {
+    this.connection.onReducer("reorder_chats", callback);
+  }
+
+  removeOnReorderChats(callback: (ctx: ReducerEventContext, groupchatIds: string[]) => void) {
+    this.connection.offReducer("reorder_chats", callback);
+  }
+
+  reorderFolders(folderIds: bigint[]) {
+    const __args = { folderIds };
+    let __writer = new __BinaryWriter(1024);
+    ReorderFolders.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("reorder_folders", __argsBuffer, this.setCallReducerFlags.reorderFoldersFlags);
+  }
+
+  onReorderFolders(callback: (ctx: ReducerEventContext, folderIds: bigint[]) => void) {
+    this.connection.onReducer("reorder_folders", callback);
+  }
+
+  removeOnReorderFolders(callback: (ctx: ReducerEventContext, folderIds: bigint[]) => void) {
+    this.connection.offReducer("reorder_folders", callback);
+  }
+
+  replyToMessage(parentId: bigint, text: string) {
+    const __args = { parentId, text };
+    let __writer = new __BinaryWriter(1024);
+    ReplyToMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("reply_to_message", __argsBuffer, this.setCallReducerFlags.replyToMessageFlags);
+  }
+
+  onReplyToMessage(callback: (ctx: ReducerEventContext, parentId: bigint, text: string) => void) {
+    this.connection.onReducer("reply_to_message", callback);
+  }
+
+  removeOnReplyToMessage(callback: (ctx: ReducerEventContext, parentId: bigint, text: string) => void) {
+    this.connection.offReducer("reply_to_message", callback);
+  }
+
+  reportMessage(messageId: bigint, reason: string) {
+    const __args = { messageId, reason };
+    let __writer = new __BinaryWriter(1024);
+    ReportMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("report_message", __argsBuffer, this.setCallReducerFlags.reportMessageFlags);
+  }
+
+  onReportMessage(callback: (ctx: ReducerEventContext, messageId: bigint, reason: string) => void) {
+    this.connection.onReducer("report_message", callback);
+  }
+
+  removeOnReportMessage(callback: (ctx: ReducerEventContext, messageId: bigint, reason: string) => void) {
+    this.connection.offReducer("report_message", callback);
+  }
+
+  requestDataExport() {
+    const __args = {  };
+    let __writer = new __BinaryWriter(1024);
+    RequestDataExport.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("request_data_export", __argsBuffer, this.setCallReducerFlags.requestDataExportFlags);
+  }
+
+  onRequestDataExport(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("request_data_export", callback);
+  }
+
+  removeOnRequestDataExport(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("request_data_export", callback);
+  }
+
+  requestToJoin(groupchatId: string) {
+    const __args = { groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    RequestToJoin.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("request_to_join", __argsBuffer, this.setCallReducerFlags.requestToJoinFlags);
+  }
+
+  onRequestToJoin(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.onReducer("request_to_join", callback);
+  }
+
+  removeOnRequestToJoin(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.offReducer("request_to_join", callback);
+  }
+
+  resetBrokenStreaks(sweep: StreakResetSweep) {
+    const __args = { sweep };
+    let __writer = new __BinaryWriter(1024);
+    ResetBrokenStreaks.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("reset_broken_streaks", __argsBuffer, this.setCallReducerFlags.resetBrokenStreaksFlags);
+  }
+
+  onResetBrokenStreaks(callback: (ctx: ReducerEventContext, sweep: StreakResetSweep) => void) {
+    this.connection.onReducer("reset_broken_streaks", callback);
+  }
+
+  removeOnResetBrokenStreaks(callback: (ctx: ReducerEventContext, sweep: StreakResetSweep) => void) {
+    this.connection.offReducer("reset_broken_streaks", callback);
+  }
+
+  resetLeaderboard(groupchatId: string) {
+    const __args = { groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    ResetLeaderboard.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("reset_leaderboard", __argsBuffer, this.setCallReducerFlags.resetLeaderboardFlags);
+  }
+
+  onResetLeaderboard(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.onReducer("reset_leaderboard", callback);
+  }
+
+  removeOnResetLeaderboard(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.offReducer("reset_leaderboard", callback);
+  }
+
+  resolveReport(reportId: bigint) {
+    const __args = { reportId };
+    let __writer = new __BinaryWriter(1024);
+    ResolveReport.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("resolve_report", __argsBuffer, this.setCallReducerFlags.resolveReportFlags);
+  }
+
+  onResolveReport(callback: (ctx: ReducerEventContext, reportId: bigint) => void) {
+    this.connection.onReducer("resolve_report", callback);
+  }
+
+  removeOnResolveReport(callback: (ctx: ReducerEventContext, reportId: bigint) => void) {
+    this.connection.offReducer("resolve_report", callback);
+  }
+
+  revokeDevice(device: __Identity) {
+    const __args = { device };
+    let __writer = new __BinaryWriter(1024);
+    RevokeDevice.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("revoke_device", __argsBuffer, this.setCallReducerFlags.revokeDeviceFlags);
+  }
+
+  onRevokeDevice(callback: (ctx: ReducerEventContext, device: __Identity) => void) {
+    this.connection.onReducer("revoke_device", callback);
+  }
+
+  removeOnRevokeDevice(callback: (ctx: ReducerEventContext, device: __Identity) => void) {
+    this.connection.offReducer("revoke_device", callback);
+  }
+
+  revokeInvite(code: string) {
+    const __args = { code };
+    let __writer = new __BinaryWriter(1024);
+    RevokeInvite.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("revoke_invite", __argsBuffer, this.setCallReducerFlags.revokeInviteFlags);
+  }
+
+  onRevokeInvite(callback: (ctx: ReducerEventContext, code: string) => void) {
+    this.connection.onReducer("revoke_invite", callback);
+  }
+
+  removeOnRevokeInvite(callback: (ctx: ReducerEventContext, code: string) => void) {
+    this.connection.offReducer("revoke_invite", callback);
+  }
+
+  rollUpGroupStats(rollup: GroupStatsRollup) {
+    const __args = { rollup };
+    let __writer = new __BinaryWriter(1024);
+    RollUpGroupStats.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("roll_up_group_stats", __argsBuffer, this.setCallReducerFlags.rollUpGroupStatsFlags);
+  }
+
+  onRollUpGroupStats(callback: (ctx: ReducerEventContext, rollup: GroupStatsRollup) => void) {
+    this.connection.onReducer("roll_up_group_stats", callback);
+  }
+
+  removeOnRollUpGroupStats(callback: (ctx: ReducerEventContext, rollup: GroupStatsRollup) => void) {
+    this.connection.offReducer("roll_up_group_stats", callback);
+  }
+
+  rsvp(eventId: bigint, status: RsvpStatus) {
+    const __args = { eventId, status };
+    let __writer = new __BinaryWriter(1024);
+    Rsvp.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("rsvp", __argsBuffer, this.setCallReducerFlags.rsvpFlags);
+  }
+
+  onRsvp(callback: (ctx: ReducerEventContext, eventId: bigint, status: RsvpStatus) => void) {
+    this.connection.onReducer("rsvp", callback);
+  }
+
+  removeOnRsvp(callback: (ctx: ReducerEventContext, eventId: bigint, status: RsvpStatus) => void) {
+    this.connection.offReducer("rsvp", callback);
+  }
+
+  saveDraft(groupchatId: string, text: string) {
+    const __args = { groupchatId, text };
+    let __writer = new __BinaryWriter(1024);
+    SaveDraft.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("save_draft", __argsBuffer, this.setCallReducerFlags.saveDraftFlags);
+  }
+
+  onSaveDraft(callback: (ctx: ReducerEventContext, groupchatId: string, text: string) => void) {
+    this.connection.onReducer("save_draft", callback);
+  }
+
+  removeOnSaveDraft(callback: (ctx: ReducerEventContext, groupchatId: string, text: string) => void) {
+    this.connection.offReducer("save_draft", callback);
+  }
+
+  searchMessages(groupchatId: string, query: string) {
+    const __args = { groupchatId, query };
+    let __writer = new __BinaryWriter(1024);
+    SearchMessages.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("search_messages", __argsBuffer, this.setCallReducerFlags.searchMessagesFlags);
+  }
+
+  onSearchMessages(callback: (ctx: ReducerEventContext, groupchatId: string, query: string) => void) {
+    this.connection.onReducer("search_messages", callback);
+  }
+
+  removeOnSearchMessages(callback: (ctx: ReducerEventContext, groupchatId: string, query: string) => void) {
+    this.connection.offReducer("search_messages", callback);
+  }
+
+  searchUsers(prefix: string) {
+    const __args = { prefix };
+    let __writer = new __BinaryWriter(1024);
+    SearchUsers.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("search_users", __argsBuffer, this.setCallReducerFlags.searchUsersFlags);
+  }
+
+  onSearchUsers(callback: (ctx: ReducerEventContext, prefix: string) => void) {
+    this.connection.onReducer("search_users", callback);
+  }
+
+  removeOnSearchUsers(callback: (ctx: ReducerEventContext, prefix: string) => void) {
+    this.connection.offReducer("search_users", callback);
+  }
+
+  sendDm(recipient: __Identity, text: string) {
+    const __args = { recipient, text };
+    let __writer = new __BinaryWriter(1024);
+    SendDm.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_dm", __argsBuffer, this.setCallReducerFlags.sendDmFlags);
+  }
+
+  onSendDm(callback: (ctx: ReducerEventContext, recipient: __Identity, text: string) => void) {
+    this.connection.onReducer("send_dm", callback);
+  }
+
+  removeOnSendDm(callback: (ctx: ReducerEventContext, recipient: __Identity, text: string) => void) {
+    this.connection.offReducer("send_dm", callback);
+  }
+
+  sendEncryptedMessage(groupchatId: string, ciphertext: Uint8Array, nonce: Uint8Array, keyId: string) {
+    const __args = { groupchatId, ciphertext, nonce, keyId };
+    let __writer = new __BinaryWriter(1024);
+    SendEncryptedMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_encrypted_message", __argsBuffer, this.setCallReducerFlags.sendEncryptedMessageFlags);
+  }
+
+  onSendEncryptedMessage(callback: (ctx: ReducerEventContext, groupchatId: string, ciphertext: Uint8Array, nonce: Uint8Array, keyId: string) => void) {
+    this.connection.onReducer("send_encrypted_message", callback);
+  }
+
+  removeOnSendEncryptedMessage(callback: (ctx: ReducerEventContext, groupchatId: string, ciphertext: Uint8Array, nonce: Uint8Array, keyId: string) => void) {
+    this.connection.offReducer("send_encrypted_message", callback);
+  }
+
+  sendEventReminder(reminder: EventReminder) {
+    const __args = { reminder };
+    let __writer = new __BinaryWriter(1024);
+    SendEventReminder.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_event_reminder", __argsBuffer, this.setCallReducerFlags.sendEventReminderFlags);
+  }
+
+  onSendEventReminder(callback: (ctx: ReducerEventContext, reminder: EventReminder) => void) {
+    this.connection.onReducer("send_event_reminder", callback);
+  }
+
+  removeOnSendEventReminder(callback: (ctx: ReducerEventContext, reminder: EventReminder) => void) {
+    this.connection.offReducer("send_event_reminder", callback);
+  }
+
+  sendFriendRequest(target: __Identity) {
+    const __args = { target };
+    let __writer = new __BinaryWriter(1024);
+    SendFriendRequest.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_friend_request", __argsBuffer, this.setCallReducerFlags.sendFriendRequestFlags);
+  }
+
+  onSendFriendRequest(callback: (ctx: ReducerEventContext, target: __Identity) => void) {
+    this.connection.onReducer("send_friend_request", callback);
+  }
+
+  removeOnSendFriendRequest(callback: (ctx: ReducerEventContext, target: __Identity) => void) {
+    this.connection.offReducer("send_friend_request", callback);
+  }
+
+  sendMessage(groupchat: string, text: string, clientId: bigint | undefined, topicId: bigint | undefined, lang: string | undefined) {
+    const __args = { groupchat, text, clientId, topicId, lang };
+    let __writer = new __BinaryWriter(1024);
+    SendMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_message", __argsBuffer, this.setCallReducerFlags.sendMessageFlags);
+  }
+
+  onSendMessage(callback: (ctx: ReducerEventContext, groupchat: string, text: string, clientId: bigint | undefined, topicId: bigint | undefined, lang: string | undefined) => void) {
+    this.connection.onReducer("send_message", callback);
+  }
+
+  removeOnSendMessage(callback: (ctx: ReducerEventContext, groupchat: string, text: string, clientId: bigint | undefined, topicId: bigint | undefined, lang: string | undefined) => void) {
+    this.connection.offReducer("send_message", callback);
+  }
+
+  sendMessageWithAttachments(groupchat: string, text: string, attachments: AttachmentInput[]) {
+    const __args = { groupchat, text, attachments };
+    let __writer = new __BinaryWriter(1024);
+    SendMessageWithAttachments.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_message_with_attachments", __argsBuffer, this.setCallReducerFlags.sendMessageWithAttachmentsFlags);
+  }
+
+  onSendMessageWithAttachments(callback: (ctx: ReducerEventContext, groupchat: string, text: string, attachments: AttachmentInput[]) => void) {
+    this.connection.onReducer("send_message_with_attachments", callback);
+  }
+
+  removeOnSendMessageWithAttachments(callback: (ctx: ReducerEventContext, groupchat: string, text: string, attachments: AttachmentInput[]) => void) {
+    this.connection.offReducer("send_message_with_attachments", callback);
+  }
+
+  sendNoteToSelf(text: string) {
+    const __args = { text };
+    let __writer = new __BinaryWriter(1024);
+    SendNoteToSelf.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_note_to_self", __argsBuffer, this.setCallReducerFlags.sendNoteToSelfFlags);
+  }
+
+  onSendNoteToSelf(callback: (ctx: ReducerEventContext, text: string) => void) {
+    this.connection.onReducer("send_note_to_self", callback);
+  }
+
+  removeOnSendNoteToSelf(callback: (ctx: ReducerEventContext, text: string) => void) {
+    this.connection.offReducer("send_note_to_self", callback);
+  }
+
+  setChatPin(groupchatId: string, pinned: boolean) {
+    const __args = { groupchatId, pinned };
+    let __writer = new __BinaryWriter(1024);
+    SetChatPin.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_chat_pin", __argsBuffer, this.setCallReducerFlags.setChatPinFlags);
+  }
+
+  onSetChatPin(callback: (ctx: ReducerEventContext, groupchatId: string, pinned: boolean) => void) {
+    this.connection.onReducer("set_chat_pin", callback);
+  }
+
+  removeOnSetChatPin(callback: (ctx: ReducerEventContext, groupchatId: string, pinned: boolean) => void) {
+    this.connection.offReducer("set_chat_pin", callback);
+  }
+
+  setDeletedAccountPolicy(groupchatId: string, deletedAccountPolicy: DeletedAccountPolicy) {
+    const __args = { groupchatId, deletedAccountPolicy };
+    let __writer = new __BinaryWriter(1024);
+    SetDeletedAccountPolicy.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_deleted_account_policy", __argsBuffer, this.setCallReducerFlags.setDeletedAccountPolicyFlags);
+  }
+
+  onSetDeletedAccountPolicy(callback: (ctx: ReducerEventContext, groupchatId: string, deletedAccountPolicy: DeletedAccountPolicy) => void) {
+    this.connection.onReducer("set_deleted_account_policy", callback);
+  }
+
+  removeOnSetDeletedAccountPolicy(callback: (ctx: ReducerEventContext, groupchatId: string, deletedAccountPolicy: DeletedAccountPolicy) => void) {
+    this.connection.offReducer("set_deleted_account_policy", callback);
+  }
+
+  setDiscoverable(groupchatId: string, discoverable: boolean) {
+    const __args = { groupchatId, discoverable };
+    let __writer = new __BinaryWriter(1024);
+    SetDiscoverable.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_discoverable", __argsBuffer, this.setCallReducerFlags.setDiscoverableFlags);
+  }
+
+  onSetDiscoverable(callback: (ctx: ReducerEventContext, groupchatId: string, discoverable: boolean) => void) {
+    this.connection.onReducer("set_discoverable", callback);
+  }
+
+  removeOnSetDiscoverable(callback: (ctx: ReducerEventContext, groupchatId: string, discoverable: boolean) => void) {
+    this.connection.offReducer("set_discoverable", callback);
+  }
+
+  setGroupAvatar(groupchatId: string, avatarUrl: string | undefined) {
+    const __args = { groupchatId, avatarUrl };
+    let __writer = new __BinaryWriter(1024);
+    SetGroupAvatar.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_group_avatar", __argsBuffer, this.setCallReducerFlags.setGroupAvatarFlags);
+  }
+
+  onSetGroupAvatar(callback: (ctx: ReducerEventContext, groupchatId: string, avatarUrl: string | undefined) => void) {
+    this.connection.onReducer("set_group_avatar", callback);
+  }
+
+  removeOnSetGroupAvatar(callback: (ctx: ReducerEventContext, groupchatId: string, avatarUrl: string | undefined) => void) {
+    this.connection.offReducer("set_group_avatar", callback);
+  }
+
+  setGroupChatName(groupchatId: string, name: string) {
+    const __args = { groupchatId, name };
+    let __writer = new __BinaryWriter(1024);
+    SetGroupChatName.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_group_chat_name", __argsBuffer, this.setCallReducerFlags.setGroupChatNameFlags);
+  }
+
+  onSetGroupChatName(callback: (ctx: ReducerEventContext, groupchatId: string, name: string) => void) {
+    this.connection.onReducer("set_group_chat_name", callback);
+  }
+
+  removeOnSetGroupChatName(callback: (ctx: ReducerEventContext, groupchatId: string, name: string) => void) {
+    this.connection.offReducer("set_group_chat_name", callback);
+  }
+
+  setGroupDescription(groupchatId: string, description: string | undefined) {
+    const __args = { groupchatId, description };
+    let __writer = new __BinaryWriter(1024);
+    SetGroupDescription.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_group_description", __argsBuffer, this.setCallReducerFlags.setGroupDescriptionFlags);
+  }
+
+  onSetGroupDescription(callback: (ctx: ReducerEventContext, groupchatId: string, description: string | undefined) => void) {
+    this.connection.onReducer("set_group_description", callback);
+  }
+
+  removeOnSetGroupDescription(callback: (ctx: ReducerEventContext, groupchatId: string, description: string | undefined) => void) {
+    this.connection.offReducer("set_group_description", callback);
+  }
+
+  setGroupNickname(groupchatId: string, nickname: string | undefined) {
+    const __args = { groupchatId, nickname };
+    let __writer = new __BinaryWriter(1024);
+    SetGroupNickname.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_group_nickname", __argsBuffer, this.setCallReducerFlags.setGroupNicknameFlags);
+  }
+
+  onSetGroupNickname(callback: (ctx: ReducerEventContext, groupchatId: string, nickname: string | undefined) => void) {
+    this.connection.onReducer("set_group_nickname", callback);
+  }
+
+  removeOnSetGroupNickname(callback: (ctx: ReducerEventContext, groupchatId: string, nickname: string | undefined) => void) {
+    this.connection.offReducer("set_group_nickname", callback);
+  }
+
+  setGroupTopic(groupchatId: string, topic: string | undefined) {
+    const __args = { groupchatId, topic };
+    let __writer = new __BinaryWriter(1024);
+    SetGroupTopic.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_group_topic", __argsBuffer, this.setCallReducerFlags.setGroupTopicFlags);
+  }
+
+  onSetGroupTopic(callback: (ctx: ReducerEventContext, groupchatId: string, topic: string | undefined) => void) {
+    this.connection.onReducer("set_group_topic", callback);
+  }
+
+  removeOnSetGroupTopic(callback: (ctx: ReducerEventContext, groupchatId: string, topic: string | undefined) => void) {
+    this.connection.offReducer("set_group_topic", callback);
+  }
+
+  setGroupVisibility(groupchatId: string, visibility: GroupVisibility) {
+    const __args = { groupchatId, visibility };
+    let __writer = new __BinaryWriter(1024);
+    SetGroupVisibility.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_group_visibility", __argsBuffer, this.setCallReducerFlags.setGroupVisibilityFlags);
+  }
+
+  onSetGroupVisibility(callback: (ctx: ReducerEventContext, groupchatId: string, visibility: GroupVisibility) => void) {
+    this.connection.onReducer("set_group_visibility", callback);
+  }
+
+  removeOnSetGroupVisibility(callback: (ctx: ReducerEventContext, groupchatId: string, visibility: GroupVisibility) => void) {
+    this.connection.offReducer("set_group_visibility", callback);
+  }
+
+  setMemberLimit(groupchatId: string, maxMembers: number | undefined) {
+    const __args = { groupchatId, maxMembers };
+    let __writer = new __BinaryWriter(1024);
+    SetMemberLimit.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_member_limit", __argsBuffer, this.setCallReducerFlags.setMemberLimitFlags);
+  }
+
+  onSetMemberLimit(callback: (ctx: ReducerEventContext, groupchatId: string, maxMembers: number | undefined) => void) {
+    this.connection.onReducer("set_member_limit", callback);
+  }
+
+  removeOnSetMemberLimit(callback: (ctx: ReducerEventContext, groupchatId: string, maxMembers: number | undefined) => void) {
+    this.connection.offReducer("set_member_limit", callback);
+  }
+
+  setMessageTtl(groupchatId: string, messageTtlSeconds: bigint | undefined) {
+    const __args = { groupchatId, messageTtlSeconds };
+    let __writer = new __BinaryWriter(1024);
+    SetMessageTtl.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_message_ttl", __argsBuffer, this.setCallReducerFlags.setMessageTtlFlags);
+  }
+
+  onSetMessageTtl(callback: (ctx: ReducerEventContext, groupchatId: string, messageTtlSeconds: bigint | undefined) => void) {
+    this.connection.onReducer("set_message_ttl", callback);
+  }
+
+  removeOnSetMessageTtl(callback: (ctx: ReducerEventContext, groupchatId: string, messageTtlSeconds: bigint | undefined) => void) {
+    this.connection.offReducer("set_message_ttl", callback);
+  }
+
+  setName(name: string) {
+    const __args = { name };
+    let __writer = new __BinaryWriter(1024);
+    SetName.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_name", __argsBuffer, this.setCallReducerFlags.setNameFlags);
+  }
+
+  onSetName(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.onReducer("set_name", callback);
+  }
+
+  removeOnSetName(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.offReducer("set_name", callback);
+  }
+
+  setNotificationPref(groupchatId: string, mode: NotificationMode, muteForSecs: bigint | undefined) {
+    const __args = { groupchatId, mode, muteForSecs };
+    let __writer = new __BinaryWriter(1024);
+    SetNotificationPref.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_notification_pref", __argsBuffer, this.setCallReducerFlags.setNotificationPrefFlags);
+  }
+
+  onSetNotificationPref(callback: (ctx: ReducerEventContext, groupchatId: string, mode: NotificationMode, muteForSecs: bigint | undefined) => void) {
+    this.connection.onReducer("set_notification_pref", callback);
+  }
+
+  removeOnSetNotificationPref(callback: (ctx: ReducerEventContext, groupchatId: string, mode: NotificationMode, muteForSecs: bigint | undefined) => void) {
+    this.connection.offReducer("set_notification_pref", callback);
+  }
+
+  setNsfw(groupchatId: string, nsfw: boolean) {
+    const __args = { groupchatId, nsfw };
+    let __writer = new __BinaryWriter(1024);
+    SetNsfw.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_nsfw", __argsBuffer, this.setCallReducerFlags.setNsfwFlags);
+  }
+
+  onSetNsfw(callback: (ctx: ReducerEventContext, groupchatId: string, nsfw: boolean) => void) {
+    this.connection.onReducer("set_nsfw", callback);
+  }
+
+  removeOnSetNsfw(callback: (ctx: ReducerEventContext, groupchatId: string, nsfw: boolean) => void) {
+    this.connection.offReducer("set_nsfw", callback);
+  }
+
+  setPermission(groupchatId: string, action: GroupAction, minRole: MembershipRole) {
+    const __args = { groupchatId, action, minRole };
+    let __writer = new __BinaryWriter(1024);
+    SetPermission.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_permission", __argsBuffer, this.setCallReducerFlags.setPermissionFlags);
+  }
+
+  onSetPermission(callback: (ctx: ReducerEventContext, groupchatId: string, action: GroupAction, minRole: MembershipRole) => void) {
+    this.connection.onReducer("set_permission", callback);
+  }
+
+  removeOnSetPermission(callback: (ctx: ReducerEventContext, groupchatId: string, action: GroupAction, minRole: MembershipRole) => void) {
+    this.connection.offReducer("set_permission", callback);
+  }
+
+  setPostPolicy(groupchatId: string, postPolicy: PostPolicy) {
+    const __args = { groupchatId, postPolicy };
+    let __writer = new __BinaryWriter(1024);
+    SetPostPolicy.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_post_policy", __argsBuffer, this.setCallReducerFlags.setPostPolicyFlags);
+  }
+
+  onSetPostPolicy(callback: (ctx: ReducerEventContext, groupchatId: string, postPolicy: PostPolicy) => void) {
+    this.connection.onReducer("set_post_policy", callback);
+  }
+
+  removeOnSetPostPolicy(callback: (ctx: ReducerEventContext, groupchatId: string, postPolicy: PostPolicy) => void) {
+    this.connection.offReducer("set_post_policy", callback);
+  }
+
+  setPresence(presence: Presence, status: string | undefined) {
+    const __args = { presence, status };
+    let __writer = new __BinaryWriter(1024);
+    SetPresence.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_presence", __argsBuffer, this.setCallReducerFlags.setPresenceFlags);
+  }
+
+  onSetPresence(callback: (ctx: ReducerEventContext, presence: Presence, status: string | undefined) => void) {
+    this.connection.onReducer("set_presence", callback);
+  }
+
+  removeOnSetPresence(callback: (ctx: ReducerEventContext, presence: Presence, status: string | undefined) => void) {
+    this.connection.offReducer("set_presence", callback);
+  }
+
+  setRetentionDays(groupchatId: string, retentionDays: number | undefined) {
+    const __args = { groupchatId, retentionDays };
+    let __writer = new __BinaryWriter(1024);
+    SetRetentionDays.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_retention_days", __argsBuffer, this.setCallReducerFlags.setRetentionDaysFlags);
+  }
+
+  onSetRetentionDays(callback: (ctx: ReducerEventContext, groupchatId: string, retentionDays: number | undefined) => void) {
+    this.connection.onReducer("set_retention_days", callback);
+  }
+
+  removeOnSetRetentionDays(callback: (ctx: ReducerEventContext, groupchatId: string, retentionDays: number | undefined) => void) {
+    this.connection.offReducer("set_retention_days", callback);
+  }
+
+  setSessionHint(clientHint: string) {
+    const __args = { clientHint };
+    let __writer = new __BinaryWriter(1024);
+    SetSessionHint.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_session_hint", __argsBuffer, this.setCallReducerFlags.setSessionHintFlags);
+  }
+
+  onSetSessionHint(callback: (ctx: ReducerEventContext, clientHint: string) => void) {
+    this.connection.onReducer("set_session_hint", callback);
+  }
+
+  removeOnSetSessionHint(callback: (ctx: ReducerEventContext, clientHint: string) => void) {
+    this.connection.offReducer("set_session_hint", callback);
+  }
+
+  setSlowmode(groupchatId: string, slowmodeSeconds: number) {
+    const __args = { groupchatId, slowmodeSeconds };
+    let __writer = new __BinaryWriter(1024);
+    SetSlowmode.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_slowmode", __argsBuffer, this.setCallReducerFlags.setSlowmodeFlags);
+  }
+
+  onSetSlowmode(callback: (ctx: ReducerEventContext, groupchatId: string, slowmodeSeconds: number) => void) {
+    this.connection.onReducer("set_slowmode", callback);
+  }
+
+  removeOnSetSlowmode(callback: (ctx: ReducerEventContext, groupchatId: string, slowmodeSeconds: number) => void) {
+    this.connection.offReducer("set_slowmode", callback);
+  }
+
+  setStrikePolicy(groupchatId: string, strikeThreshold: number, strikeEscalation: StrikeEscalation) {
+    const __args = { groupchatId, strikeThreshold, strikeEscalation };
+    let __writer = new __BinaryWriter(1024);
+    SetStrikePolicy.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_strike_policy", __argsBuffer, this.setCallReducerFlags.setStrikePolicyFlags);
+  }
+
+  onSetStrikePolicy(callback: (ctx: ReducerEventContext, groupchatId: string, strikeThreshold: number, strikeEscalation: StrikeEscalation) => void) {
+    this.connection.onReducer("set_strike_policy", callback);
+  }
+
+  removeOnSetStrikePolicy(callback: (ctx: ReducerEventContext, groupchatId: string, strikeThreshold: number, strikeEscalation: StrikeEscalation) => void) {
+    this.connection.offReducer("set_strike_policy", callback);
+  }
+
+  setVerified(target: __Identity, verified: boolean) {
+    const __args = { target, verified };
+    let __writer = new __BinaryWriter(1024);
+    SetVerified.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_verified", __argsBuffer, this.setCallReducerFlags.setVerifiedFlags);
+  }
+
+  onSetVerified(callback: (ctx: ReducerEventContext, target: __Identity, verified: boolean) => void) {
+    this.connection.onReducer("set_verified", callback);
+  }
+
+  removeOnSetVerified(callback: (ctx: ReducerEventContext, target: __Identity, verified: boolean) => void) {
+    this.connection.offReducer("set_verified", callback);
+  }
+
+  shareGroupKey(groupchatId: string, keyId: string, recipient: __Identity, wrappedKey: Uint8Array) {
+    const __args = { groupchatId, keyId, recipient, wrappedKey };
+    let __writer = new __BinaryWriter(1024);
+    ShareGroupKey.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("share_group_key", __argsBuffer, this.setCallReducerFlags.shareGroupKeyFlags);
+  }
+
+  onShareGroupKey(callback: (ctx: ReducerEventContext, groupchatId: string, keyId: string, recipient: __Identity, wrappedKey: Uint8Array) => void) {
+    this.connection.onReducer("share_group_key", callback);
+  }
+
+  removeOnShareGroupKey(callback: (ctx: ReducerEventContext, groupchatId: string, keyId: string, recipient: __Identity, wrappedKey: Uint8Array) => void) {
+    this.connection.offReducer("share_group_key", callback);
+  }
+
+  solveChallenge(answer: string) {
+    const __args = { answer };
+    let __writer = new __BinaryWriter(1024);
+    SolveChallenge.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("solve_challenge", __argsBuffer, this.setCallReducerFlags.solveChallengeFlags);
+  }
+
+  onSolveChallenge(callback: (ctx: ReducerEventContext, answer: string) => void) {
+    this.connection.onReducer("solve_challenge", callback);
+  }
+
+  removeOnSolveChallenge(callback: (ctx: ReducerEventContext, answer: string) => void) {
+    this.connection.offReducer("solve_challenge", callback);
+  }
+
+  startTyping(groupchatId: string) {
+    const __args = { groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    StartTyping.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("start_typing", __argsBuffer, this.setCallReducerFlags.startTypingFlags);
+  }
+
+  onStartTyping(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.onReducer("start_typing", callback);
+  }
+
+  removeOnStartTyping(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.offReducer("start_typing", callback);
+  }
+
+  storeTranslation(messageId: bigint, targetLang: string, text: string) {
+    const __args = { messageId, targetLang, text };
+    let __writer = new __BinaryWriter(1024);
+    StoreTranslation.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("store_translation", __argsBuffer, this.setCallReducerFlags.storeTranslationFlags);
+  }
+
+  onStoreTranslation(callback: (ctx: ReducerEventContext, messageId: bigint, targetLang: string, text: string) => void) {
+    this.connection.onReducer("store_translation", callback);
+  }
+
+  removeOnStoreTranslation(callback: (ctx: ReducerEventContext, messageId: bigint, targetLang: string, text: string) => void) {
+    this.connection.offReducer("store_translation", callback);
+  }
+
+  sweepPresence(sweep: PresenceSweep) {
+    const __args = { sweep };
+    let __writer = new __BinaryWriter(1024);
+    SweepPresence.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("sweep_presence", __argsBuffer, this.setCallReducerFlags.sweepPresenceFlags);
+  }
+
+  onSweepPresence(callback: (ctx: ReducerEventContext, sweep: PresenceSweep) => void) {
+    this.connection.onReducer("sweep_presence", callback);
+  }
+
+  removeOnSweepPresence(callback: (ctx: ReducerEventContext, sweep: PresenceSweep) => void) {
+    this.connection.offReducer("sweep_presence", callback);
+  }
+
+  terminateSession(connectionId: __ConnectionId) {
+    const __args = { connectionId };
+    let __writer = new __BinaryWriter(1024);
+    TerminateSession.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("terminate_session", __argsBuffer, this.setCallReducerFlags.terminateSessionFlags);
+  }
+
+  onTerminateSession(callback: (ctx: ReducerEventContext, connectionId: __ConnectionId) => void) {
+    this.connection.onReducer("terminate_session", callback);
+  }
+
+  removeOnTerminateSession(callback: (ctx: ReducerEventContext, connectionId: __ConnectionId) => void) {
+    this.connection.offReducer("terminate_session", callback);
+  }
+
+  transferOwnership(groupchatId: string, newOwner: __Identity) {
+    const __args = { groupchatId, newOwner };
+    let __writer = new __BinaryWriter(1024);
+    TransferOwnership.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("transfer_ownership", __argsBuffer, this.setCallReducerFlags.transferOwnershipFlags);
+  }
+
+  onTransferOwnership(callback: (ctx: ReducerEventContext, groupchatId: string, newOwner: __Identity) => void) {
+    this.connection.onReducer("transfer_ownership", callback);
+  }
+
+  removeOnTransferOwnership(callback: (ctx: ReducerEventContext, groupchatId: string, newOwner: __Identity) => void) {
+    this.connection.offReducer("transfer_ownership", callback);
+  }
+
+  unarchiveGroupchat(groupchatId: string) {
+    const __args = { groupchatId };
+    let __writer = new __BinaryWriter(1024);
+    UnarchiveGroupchat.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unarchive_groupchat", __argsBuffer, this.setCallReducerFlags.unarchiveGroupchatFlags);
+  }
+
+  onUnarchiveGroupchat(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.onReducer("unarchive_groupchat", callback);
+  }
+
+  removeOnUnarchiveGroupchat(callback: (ctx: ReducerEventContext, groupchatId: string) => void) {
+    this.connection.offReducer("unarchive_groupchat", callback);
+  }
+
+  unbanMember(groupchatId: string, target: __Identity) {
+    const __args = { groupchatId, target };
+    let __writer = new __BinaryWriter(1024);
+    UnbanMember.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unban_member", __argsBuffer, this.setCallReducerFlags.unbanMemberFlags);
+  }
+
+  onUnbanMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.onReducer("unban_member", callback);
+  }
+
+  removeOnUnbanMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.offReducer("unban_member", callback);
+  }
+
+  unblockUser(target: __Identity) {
+    const __args = { target };
+    let __writer = new __BinaryWriter(1024);
+    UnblockUser.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unblock_user", __argsBuffer, this.setCallReducerFlags.unblockUserFlags);
+  }
+
+  onUnblockUser(callback: (ctx: ReducerEventContext, target: __Identity) => void) {
+    this.connection.onReducer("unblock_user", callback);
+  }
+
+  removeOnUnblockUser(callback: (ctx: ReducerEventContext, target: __Identity) => void) {
+    this.connection.offReducer("unblock_user", callback);
+  }
+
+  unmuteMember(groupchatId: string, target: __Identity) {
+    const __args = { groupchatId, target };
+    let __writer = new __BinaryWriter(1024);
+    UnmuteMember.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unmute_member", __argsBuffer, this.setCallReducerFlags.unmuteMemberFlags);
+  }
+
+  onUnmuteMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.onReducer("unmute_member", callback);
+  }
+
+  removeOnUnmuteMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity) => void) {
+    this.connection.offReducer("unmute_member", callback);
+  }
+
+  unpinMessage(messageId: bigint) {
+    const __args = { messageId };
+    let __writer = new __BinaryWriter(1024);
+    UnpinMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unpin_message", __argsBuffer, this.setCallReducerFlags.unpinMessageFlags);
+  }
+
+  onUnpinMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.onReducer("unpin_message", callback);
+  }
+
+  removeOnUnpinMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.offReducer("unpin_message", callback);
+  }
+
+  unsendMessage(messageId: bigint) {
+    const __args = { messageId };
+    let __writer = new __BinaryWriter(1024);
+    UnsendMessage.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unsend_message", __argsBuffer, this.setCallReducerFlags.unsendMessageFlags);
+  }
+
+  onUnsendMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.onReducer("unsend_message", callback);
+  }
+
+  removeOnUnsendMessage(callback: (ctx: ReducerEventContext, messageId: bigint) => void) {
+    this.connection.offReducer("unsend_message", callback);
+  }
+
+  updateConfig(config: Config) {
+    const __args = { config };
+    let __writer = new __BinaryWriter(1024);
+    UpdateConfig.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_config", __argsBuffer, this.setCallReducerFlags.updateConfigFlags);
+  }
+
+  onUpdateConfig(callback: (ctx: ReducerEventContext, config: Config) => void) {
+    this.connection.onReducer("update_config", callback);
+  }
+
+  removeOnUpdateConfig(callback: (ctx: ReducerEventContext, config: Config) => void) {
+    this.connection.offReducer("update_config", callback);
+  }
+
+  updateProfile(bio: string | undefined, avatarUrl: string | undefined, status: string | undefined) {
+    const __args = { bio, avatarUrl, status };
+    let __writer = new __BinaryWriter(1024);
+    UpdateProfile.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_profile", __argsBuffer, this.setCallReducerFlags.updateProfileFlags);
+  }
+
+  onUpdateProfile(callback: (ctx: ReducerEventContext, bio: string | undefined, avatarUrl: string | undefined, status: string | undefined) => void) {
+    this.connection.onReducer("update_profile", callback);
+  }
+
+  removeOnUpdateProfile(callback: (ctx: ReducerEventContext, bio: string | undefined, avatarUrl: string | undefined, status: string | undefined) => void) {
+    this.connection.offReducer("update_profile", callback);
+  }
+
+  updateSettings(settings: UserSettings) {
+    const __args = { settings };
+    let __writer = new __BinaryWriter(1024);
+    UpdateSettings.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_settings", __argsBuffer, this.setCallReducerFlags.updateSettingsFlags);
+  }
+
+  onUpdateSettings(callback: (ctx: ReducerEventContext, settings: UserSettings) => void) {
+    this.connection.onReducer("update_settings", callback);
+  }
+
+  removeOnUpdateSettings(callback: (ctx: ReducerEventContext, settings: UserSettings) => void) {
+    this.connection.offReducer("update_settings", callback);
+  }
+
+  vote(pollId: bigint, optionIndex: number) {
+    const __args = { pollId, optionIndex };
+    let __writer = new __BinaryWriter(1024);
+    Vote.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("vote", __argsBuffer, this.setCallReducerFlags.voteFlags);
+  }
+
+  onVote(callback: (ctx: ReducerEventContext, pollId: bigint, optionIndex: number) => void) {
+    this.connection.onReducer("vote", callback);
+  }
+
+  removeOnVote(callback: (ctx: ReducerEventContext, pollId: bigint, optionIndex: number) => void) {
+    this.connection.offReducer("vote", callback);
+  }
+
+  warnMember(groupchatId: string, target: __Identity, reason: string | undefined) {
+    const __args = { groupchatId, target, reason };
+    let __writer = new __BinaryWriter(1024);
+    WarnMember.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("warn_member", __argsBuffer, this.setCallReducerFlags.warnMemberFlags);
+  }
+
+  onWarnMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity, reason: string | undefined) => void) {
+    this.connection.onReducer("warn_member", callback);
+  }
+
+  removeOnWarnMember(callback: (ctx: ReducerEventContext, groupchatId: string, target: __Identity, reason: string | undefined) => void) {
+    this.connection.offReducer("warn_member", callback);
+  }
+
+}
+
+export class SetReducerFlags {
+  acceptFriendRequestFlags: __CallReducerFlags = 'FullUpdate';
+  acceptFriendRequest(flags: __CallReducerFlags) {
+    this.acceptFriendRequestFlags = flags;
+  }
+
+  acceptTosFlags: __CallReducerFlags = 'FullUpdate';
+  acceptTos(flags: __CallReducerFlags) {
+    this.acceptTosFlags = flags;
+  }
+
+  ackDeliveryFlags: __CallReducerFlags = 'FullUpdate';
+  ackDelivery(flags: __CallReducerFlags) {
+    this.ackDeliveryFlags = flags;
+  }
+
+  ackMentionFlags: __CallReducerFlags = 'FullUpdate';
+  ackMention(flags: __CallReducerFlags) {
+    this.ackMentionFlags = flags;
+  }
+
+  addBannedWordFlags: __CallReducerFlags = 'FullUpdate';
+  addBannedWord(flags: __CallReducerFlags) {
+    this.addBannedWordFlags = flags;
+  }
+
+  addChatToFolderFlags: __CallReducerFlags = 'FullUpdate';
+  addChatToFolder(flags: __CallReducerFlags) {
+    this.addChatToFolderFlags = flags;
+  }
+
+  addCustomEmojiFlags: __CallReducerFlags = 'FullUpdate';
+  addCustomEmoji(flags: __CallReducerFlags) {
+    this.addCustomEmojiFlags = flags;
+  }
+
+  addGroupTagFlags: __CallReducerFlags = 'FullUpdate';
+  addGroupTag(flags: __CallReducerFlags) {
+    this.addGroupTagFlags = flags;
+  }
+
+  addReactionFlags: __CallReducerFlags = 'FullUpdate';
+  addReaction(flags: __CallReducerFlags) {
+    this.addReactionFlags = flags;
+  }
+
+  addWebhookFlags: __CallReducerFlags = 'FullUpdate';
+  addWebhook(flags: __CallReducerFlags) {
+    this.addWebhookFlags = flags;
+  }
+
+  applyRetentionPoliciesFlags: __CallReducerFlags = 'FullUpdate';
+  applyRetentionPolicies(flags: __CallReducerFlags) {
+    this.applyRetentionPoliciesFlags = flags;
+  }
+
+  approveJoinRequestFlags: __CallReducerFlags = 'FullUpdate';
+  approveJoinRequest(flags: __CallReducerFlags) {
+    this.approveJoinRequestFlags = flags;
+  }
+
+  archiveGroupchatFlags: __CallReducerFlags = 'FullUpdate';
+  archiveGroupchat(flags: __CallReducerFlags) {
+    this.archiveGroupchatFlags = flags;
+  }
+
+  archiveTopicFlags: __CallReducerFlags = 'FullUpdate';
+  archiveTopic(flags: __CallReducerFlags) {
+    this.archiveTopicFlags = flags;
+  }
+
+  awardBadgeFlags: __CallReducerFlags = 'FullUpdate';
+  awardBadge(flags: __CallReducerFlags) {
+    this.awardBadgeFlags = flags;
+  }
+
+  awardPointsFlags: __CallReducerFlags = 'FullUpdate';
+  awardPoints(flags: __CallReducerFlags) {
+    this.awardPointsFlags = flags;
+  }
+
+  banMemberFlags: __CallReducerFlags = 'FullUpdate';
+  banMember(flags: __CallReducerFlags) {
+    this.banMemberFlags = flags;
+  }
+
+  blockUserFlags: __CallReducerFlags = 'FullUpdate';
+  blockUser(flags: __CallReducerFlags) {
+    this.blockUserFlags = flags;
+  }
+
+  bookmarkMessageFlags: __CallReducerFlags = 'FullUpdate';
+  bookmarkMessage(flags: __CallReducerFlags) {
+    this.bookmarkMessageFlags = flags;
+  }
+
+  broadcastFlags: __CallReducerFlags = 'FullUpdate';
+  broadcast(flags: __CallReducerFlags) {
+    this.broadcastFlags = flags;
+  }
+
+  cancelEventFlags: __CallReducerFlags = 'FullUpdate';
+  cancelEvent(flags: __CallReducerFlags) {
+    this.cancelEventFlags = flags;
+  }
+
+  clearDraftFlags: __CallReducerFlags = 'FullUpdate';
+  clearDraft(flags: __CallReducerFlags) {
+    this.clearDraftFlags = flags;
+  }
+
+  clearNotificationsFlags: __CallReducerFlags = 'FullUpdate';
+  clearNotifications(flags: __CallReducerFlags) {
+    this.clearNotificationsFlags = flags;
+  }
+
+  closePollFlags: __CallReducerFlags = 'FullUpdate';
+  closePoll(flags: __CallReducerFlags) {
+    this.closePollFlags = flags;
+  }
+
+  createBadgeFlags: __CallReducerFlags = 'FullUpdate';
+  createBadge(flags: __CallReducerFlags) {
+    this.createBadgeFlags = flags;
+  }
+
+  createEventFlags: __CallReducerFlags = 'FullUpdate';
+  createEvent(flags: __CallReducerFlags) {
+    this.createEventFlags = flags;
+  }
+
+  createFolderFlags: __CallReducerFlags = 'FullUpdate';
+  createFolder(flags: __CallReducerFlags) {
+    this.createFolderFlags = flags;
+  }
+
+  createGroupchatFlags: __CallReducerFlags = 'FullUpdate';
+  createGroupchat(flags: __CallReducerFlags) {
+    this.createGroupchatFlags = flags;
+  }
+
+  createInviteFlags: __CallReducerFlags = 'FullUpdate';
+  createInvite(flags: __CallReducerFlags) {
+    this.createInviteFlags = flags;
+  }
+
+  createLinkTokenFlags: __CallReducerFlags = 'FullUpdate';
+  createLinkToken(flags: __CallReducerFlags) {
+    this.createLinkTokenFlags = flags;
+  }
+
+  createPollFlags: __CallReducerFlags = 'FullUpdate';
+  createPoll(flags: __CallReducerFlags) {
+    this.createPollFlags = flags;
+  }
+
+  createTopicFlags: __CallReducerFlags = 'FullUpdate';
+  createTopic(flags: __CallReducerFlags) {
+    this.createTopicFlags = flags;
+  }
+
+  crosspostMessageFlags: __CallReducerFlags = 'FullUpdate';
+  crosspostMessage(flags: __CallReducerFlags) {
+    this.crosspostMessageFlags = flags;
+  }
+
+  declineFriendRequestFlags: __CallReducerFlags = 'FullUpdate';
+  declineFriendRequest(flags: __CallReducerFlags) {
+    this.declineFriendRequestFlags = flags;
+  }
+
+  deleteAccountFlags: __CallReducerFlags = 'FullUpdate';
+  deleteAccount(flags: __CallReducerFlags) {
+    this.deleteAccountFlags = flags;
+  }
+
+  deleteFolderFlags: __CallReducerFlags = 'FullUpdate';
+  deleteFolder(flags: __CallReducerFlags) {
+    this.deleteFolderFlags = flags;
+  }
+
+  deleteGroupchatFlags: __CallReducerFlags = 'FullUpdate';
+  deleteGroupchat(flags: __CallReducerFlags) {
+    this.deleteGroupchatFlags = flags;
+  }
+
+  deleteMessageFlags: __CallReducerFlags = 'FullUpdate';
+  deleteMessage(flags: __CallReducerFlags) {
+    this.deleteMessageFlags = flags;
+  }
+
+  demoteMemberFlags: __CallReducerFlags = 'FullUpdate';
+  demoteMember(flags: __CallReducerFlags) {
+    this.demoteMemberFlags = flags;
+  }
+
+  dismissReportFlags: __CallReducerFlags = 'FullUpdate';
+  dismissReport(flags: __CallReducerFlags) {
+    this.dismissReportFlags = flags;
+  }
+
+  editMessageFlags: __CallReducerFlags = 'FullUpdate';
+  editMessage(flags: __CallReducerFlags) {
+    this.editMessageFlags = flags;
+  }
+
+  expireDisappearingMessagesFlags: __CallReducerFlags = 'FullUpdate';
+  expireDisappearingMessages(flags: __CallReducerFlags) {
+    this.expireDisappearingMessagesFlags = flags;
+  }
+
+  expireMutesFlags: __CallReducerFlags = 'FullUpdate';
+  expireMutes(flags: __CallReducerFlags) {
+    this.expireMutesFlags = flags;
+  }
+
+  fireReminderFlags: __CallReducerFlags = 'FullUpdate';
+  fireReminder(flags: __CallReducerFlags) {
+    this.fireReminderFlags = flags;
+  }
+
+  forwardMessageFlags: __CallReducerFlags = 'FullUpdate';
+  forwardMessage(flags: __CallReducerFlags) {
+    this.forwardMessageFlags = flags;
+  }
+
+  generateDailyDigestsFlags: __CallReducerFlags = 'FullUpdate';
+  generateDailyDigests(flags: __CallReducerFlags) {
+    this.generateDailyDigestsFlags = flags;
+  }
+
+  heartbeatFlags: __CallReducerFlags = 'FullUpdate';
+  heartbeat(flags: __CallReducerFlags) {
+    this.heartbeatFlags = flags;
+  }
+
+  importMessagesFlags: __CallReducerFlags = 'FullUpdate';
+  importMessages(flags: __CallReducerFlags) {
+    this.importMessagesFlags = flags;
+  }
+
+  joinByCodeFlags: __CallReducerFlags = 'FullUpdate';
+  joinByCode(flags: __CallReducerFlags) {
+    this.joinByCodeFlags = flags;
+  }
+
+  joinBySlugFlags: __CallReducerFlags = 'FullUpdate';
+  joinBySlug(flags: __CallReducerFlags) {
+    this.joinBySlugFlags = flags;
+  }
+
+  joinGroupchatFlags: __CallReducerFlags = 'FullUpdate';
+  joinGroupchat(flags: __CallReducerFlags) {
+    this.joinGroupchatFlags = flags;
+  }
+
+  joinViaInviteFlags: __CallReducerFlags = 'FullUpdate';
+  joinViaInvite(flags: __CallReducerFlags) {
+    this.joinViaInviteFlags = flags;
+  }
+
+  kickMemberFlags: __CallReducerFlags = 'FullUpdate';
+  kickMember(flags: __CallReducerFlags) {
+    this.kickMemberFlags = flags;
+  }
+
+  leaveGroupchatFlags: __CallReducerFlags = 'FullUpdate';
+  leaveGroupchat(flags: __CallReducerFlags) {
+    this.leaveGroupchatFlags = flags;
+  }
+
+  linkDeviceFlags: __CallReducerFlags = 'FullUpdate';
+  linkDevice(flags: __CallReducerFlags) {
+    this.linkDeviceFlags = flags;
+  }
+
+  markNotificationReadFlags: __CallReducerFlags = 'FullUpdate';
+  markNotificationRead(flags: __CallReducerFlags) {
+    this.markNotificationReadFlags = flags;
+  }
+
+  markReadFlags: __CallReducerFlags = 'FullUpdate';
+  markRead(flags: __CallReducerFlags) {
+    this.markReadFlags = flags;
+  }
+
+  muteMemberFlags: __CallReducerFlags = 'FullUpdate';
+  muteMember(flags: __CallReducerFlags) {
+    this.muteMemberFlags = flags;
+  }
+
+  pinMessageFlags: __CallReducerFlags = 'FullUpdate';
+  pinMessage(flags: __CallReducerFlags) {
+    this.pinMessageFlags = flags;
+  }
+
+  promoteMemberFlags: __CallReducerFlags = 'FullUpdate';
+  promoteMember(flags: __CallReducerFlags) {
+    this.promoteMemberFlags = flags;
+  }
+
+  publishGroupKeyFlags: __CallReducerFlags = 'FullUpdate';
+  publishGroupKey(flags: __CallReducerFlags) {
+    this.publishGroupKeyFlags = flags;
+  }
+
+  purgeTypingIndicatorsFlags: __CallReducerFlags = 'FullUpdate';
+  purgeTypingIndicators(flags: __CallReducerFlags) {
+    this.purgeTypingIndicatorsFlags = flags;
+  }
+
+  regenerateJoinCodeFlags: __CallReducerFlags = 'FullUpdate';
+  regenerateJoinCode(flags: __CallReducerFlags) {
+    this.regenerateJoinCodeFlags = flags;
+  }
+
+  registerBotFlags: __CallReducerFlags = 'FullUpdate';
+  registerBot(flags: __CallReducerFlags) {
+    this.registerBotFlags = flags;
+  }
+
+  rejectJoinRequestFlags: __CallReducerFlags = 'FullUpdate';
+  rejectJoinRequest(flags: __CallReducerFlags) {
+    this.rejectJoinRequestFlags = flags;
+  }
+
+  remindMeFlags: __CallReducerFlags = 'FullUpdate';
+  remindMe(flags: __CallReducerFlags) {
+    this.remindMeFlags = flags;
+  }
+
+  removeBannedWordFlags: __CallReducerFlags = 'FullUpdate';
+  removeBannedWord(flags: __CallReducerFlags) {
+    this.removeBannedWordFlags = flags;
+  }
+
+  removeBookmarkFlags: __CallReducerFlags = 'FullUpdate';
+  removeBookmark(flags: __CallReducerFlags) {
+    this.removeBookmarkFlags = flags;
+  }
+
+  removeChatFromFolderFlags: __CallReducerFlags = 'FullUpdate';
+  removeChatFromFolder(flags: __CallReducerFlags) {
+    this.removeChatFromFolderFlags = flags;
+  }
+
+  removeCustomEmojiFlags: __CallReducerFlags = 'FullUpdate';
+  removeCustomEmoji(flags: __CallReducerFlags) {
+    this.removeCustomEmojiFlags = flags;
+  }
+
+  removeFriendFlags: __CallReducerFlags = 'FullUpdate';
+  removeFriend(flags: __CallReducerFlags) {
+    this.removeFriendFlags = flags;
+  }
+
+  removeGroupTagFlags: __CallReducerFlags = 'FullUpdate';
+  removeGroupTag(flags: __CallReducerFlags) {
+    this.removeGroupTagFlags = flags;
+  }
+
+  removeReactionFlags: __CallReducerFlags = 'FullUpdate';
+  removeReaction(flags: __CallReducerFlags) {
+    this.removeReactionFlags = flags;
+  }
+
+  removeWebhookFlags: __CallReducerFlags = 'FullUpdate';
+  removeWebhook(flags: __CallReducerFlags) {
+    this.removeWebhookFlags = flags;
+  }
+
+  reorderChatsFlags: __CallReducerFlags = 'FullUpdate';
+  reorderChats(flags: __CallReducerFlags) {
+    this.reorderChatsFlags = flags;
+  }
+
+  reorderFoldersFlags: __CallReducerFlags = 'FullUpdate';
+  reorderFolders(flags: __CallReducerFlags) {
+    this.reorderFoldersFlags = flags;
+  }
+
+  replyToMessageFlags: __CallReducerFlags = 'FullUpdate';
+  replyToMessage(flags: __CallReducerFlags) {
+    this.replyToMessageFlags = flags;
+  }
+
+  reportMessageFlags: __CallReducerFlags = 'FullUpdate';
+  reportMessage(flags: __CallReducerFlags) {
+    this.reportMessageFlags = flags;
+  }
+
+  requestDataExportFlags: __CallReducerFlags = 'FullUpdate';
+  requestDataExport(flags: __CallReducerFlags) {
+    this.requestDataExportFlags = flags;
+  }
+
+  requestToJoinFlags: __CallReducerFlags = 'FullUpdate';
+  requestToJoin(flags: __CallReducerFlags) {
+    this.requestToJoinFlags = flags;
+  }
+
+  resetBrokenStreaksFlags: __CallReducerFlags = 'FullUpdate';
+  resetBrokenStreaks(flags: __CallReducerFlags) {
+    this.resetBrokenStreaksFlags = flags;
+  }
+
+  resetLeaderboardFlags: __CallReducerFlags = 'FullUpdate';
+  resetLeaderboard(flags: __CallReducerFlags) {
+    this.resetLeaderboardFlags = flags;
+  }
+
+  resolveReportFlags: __CallReducerFlags = 'FullUpdate';
+  resolveReport(flags: __CallReducerFlags) {
+    this.resolveReportFlags = flags;
+  }
+
+  revokeDeviceFlags: __CallReducerFlags = 'FullUpdate';
+  revokeDevice(flags: __CallReducerFlags) {
+    this.revokeDeviceFlags = flags;
+  }
+
+  revokeInviteFlags: __CallReducerFlags = 'FullUpdate';
+  revokeInvite(flags: __CallReducerFlags) {
+    this.revokeInviteFlags = flags;
+  }
+
+  rollUpGroupStatsFlags: __CallReducerFlags = 'FullUpdate';
+  rollUpGroupStats(flags: __CallReducerFlags) {
+    this.rollUpGroupStatsFlags = flags;
+  }
+
+  rsvpFlags: __CallReducerFlags = 'FullUpdate';
+  rsvp(flags: __CallReducerFlags) {
+    this.rsvpFlags = flags;
+  }
+
+  saveDraftFlags: __CallReducerFlags = 'FullUpdate';
+  saveDraft(flags: __CallReducerFlags) {
+    this.saveDraftFlags = flags;
+  }
+
+  searchMessagesFlags: __CallReducerFlags = 'FullUpdate';
+  searchMessages(flags: __CallReducerFlags) {
+    this.searchMessagesFlags = flags;
+  }
+
+  searchUsersFlags: __CallReducerFlags = 'FullUpdate';
+  searchUsers(flags: __CallReducerFlags) {
+    this.searchUsersFlags = flags;
+  }
+
+  sendDmFlags: __CallReducerFlags = 'FullUpdate';
+  sendDm(flags: __CallReducerFlags) {
+    this.sendDmFlags = flags;
+  }
+
+  sendEncryptedMessageFlags: __CallReducerFlags = 'FullUpdate';
+  sendEncryptedMessage(flags: __CallReducerFlags) {
+    this.sendEncryptedMessageFlags = flags;
+  }
+
+  sendEventReminderFlags: __CallReducerFlags = 'FullUpdate';
+  sendEventReminder(flags: __CallReducerFlags) {
+    this.sendEventReminderFlags = flags;
+  }
+
+  sendFriendRequestFlags: __CallReducerFlags = 'FullUpdate';
+  sendFriendRequest(flags: __CallReducerFlags) {
+    this.sendFriendRequestFlags = flags;
+  }
+
+  sendMessageFlags: __CallReducerFlags = 'FullUpdate';
+  sendMessage(flags: __CallReducerFlags) {
+    this.sendMessageFlags = flags;
+  }
+
+  sendMessageWithAttachmentsFlags: __CallReducerFlags = 'FullUpdate';
+  sendMessageWithAttachments(flags: __CallReducerFlags) {
+    this.sendMessageWithAttachmentsFlags = flags;
+  }
+
+  sendNoteToSelfFlags: __CallReducerFlags = 'FullUpdate';
+  sendNoteToSelf(flags: __CallReducerFlags) {
+    this.sendNoteToSelfFlags = flags;
+  }
+
+  setChatPinFlags: __CallReducerFlags = 'FullUpdate';
+  setChatPin(flags: __CallReducerFlags) {
+    this.setChatPinFlags = flags;
+  }
+
+  setDeletedAccountPolicyFlags: __CallReducerFlags = 'FullUpdate';
+  setDeletedAccountPolicy(flags: __CallReducerFlags) {
+    this.setDeletedAccountPolicyFlags = flags;
+  }
+
+  setDiscoverableFlags: __CallReducerFlags = 'FullUpdate';
+  setDiscoverable(flags: __CallReducerFlags) {
+    this.setDiscoverableFlags = flags;
+  }
+
+  setGroupAvatarFlags: __CallReducerFlags = 'FullUpdate';
+  setGroupAvatar(flags: __CallReducerFlags) {
+    this.setGroupAvatarFlags = flags;
+  }
+
+  setGroupChatNameFlags: __CallReducerFlags = 'FullUpdate';
+  setGroupChatName(flags: __CallReducerFlags) {
+    this.setGroupChatNameFlags = flags;
+  }
+
+  setGroupDescriptionFlags: __CallReducerFlags = 'FullUpdate';
+  setGroupDescription(flags: __CallReducerFlags) {
+    this.setGroupDescriptionFlags = flags;
+  }
+
+  setGroupNicknameFlags: __CallReducerFlags = 'FullUpdate';
+  setGroupNickname(flags: __CallReducerFlags) {
+    this.setGroupNicknameFlags = flags;
+  }
+
+  setGroupTopicFlags: __CallReducerFlags = 'FullUpdate';
+  setGroupTopic(flags: __CallReducerFlags) {
+    this.setGroupTopicFlags = flags;
+  }
+
+  setGroupVisibilityFlags: __CallReducerFlags = 'FullUpdate';
+  setGroupVisibility(flags: __CallReducerFlags) {
+    this.setGroupVisibilityFlags = flags;
+  }
+
+  setMemberLimitFlags: __CallReducerFlags = 'FullUpdate';
+  setMemberLimit(flags: __CallReducerFlags) {
+    this.setMemberLimitFlags = flags;
+  }
+
+  setMessageTtlFlags: __CallReducerFlags = 'FullUpdate';
+  setMessageTtl(flags: __CallReducerFlags) {
+    this.setMessageTtlFlags = flags;
+  }
+
+  setNameFlags: __CallReducerFlags = 'FullUpdate';
+  setName(flags: __CallReducerFlags) {
+    this.setNameFlags = flags;
+  }
+
+  setNotificationPrefFlags: __CallReducerFlags = 'FullUpdate';
+  setNotificationPref(flags: __CallReducerFlags) {
+    this.setNotificationPrefFlags = flags;
+  }
+
+  setNsfwFlags: __CallReducerFlags = 'FullUpdate';
+  setNsfw(flags: __CallReducerFlags) {
+    this.setNsfwFlags = flags;
+  }
+
+  setPermissionFlags: __CallReducerFlags = 'FullUpdate';
+  setPermission(flags: __CallReducerFlags) {
+    this.setPermissionFlags = flags;
+  }
+
+  setPostPolicyFlags: __CallReducerFlags = 'FullUpdate';
+  setPostPolicy(flags: __CallReducerFlags) {
+    this.setPostPolicyFlags = flags;
+  }
+
+  setPresenceFlags: __CallReducerFlags = 'FullUpdate';
+  setPresence(flags: __CallReducerFlags) {
+    this.setPresenceFlags = flags;
+  }
+
+  setRetentionDaysFlags: __CallReducerFlags = 'FullUpdate';
+  setRetentionDays(flags: __CallReducerFlags) {
+    this.setRetentionDaysFlags = flags;
+  }
+
+  setSessionHintFlags: __CallReducerFlags = 'FullUpdate';
+  setSessionHint(flags: __CallReducerFlags) {
+    this.setSessionHintFlags = flags;
+  }
+
+  setSlowmodeFlags: __CallReducerFlags = 'FullUpdate';
+  setSlowmode(flags: __CallReducerFlags) {
+    this.setSlowmodeFlags = flags;
+  }
+
+  setStrikePolicyFlags: __CallReducerFlags = 'FullUpdate';
+  setStrikePolicy(flags: __CallReducerFlags) {
+    this.setStrikePolicyFlags = flags;
+  }
+
+  setVerifiedFlags: __CallReducerFlags = 'FullUpdate';
+  setVerified(flags: __CallReducerFlags) {
+    this.setVerifiedFlags = flags;
+  }
+
+  shareGroupKeyFlags: __CallReducerFlags = 'FullUpdate';
+  shareGroupKey(flags: __CallReducerFlags) {
+    this.shareGroupKeyFlags = flags;
+  }
+
+  solveChallengeFlags: __CallReducerFlags = 'FullUpdate';
+  solveChallenge(flags: __CallReducerFlags) {
+    this.solveChallengeFlags = flags;
+  }
+
+  startTypingFlags: __CallReducerFlags = 'FullUpdate';
+  startTyping(flags: __CallReducerFlags) {
+    this.startTypingFlags = flags;
+  }
+
+  storeTranslationFlags: __CallReducerFlags = 'FullUpdate';
+  storeTranslation(flags: __CallReducerFlags) {
+    this.storeTranslationFlags = flags;
+  }
+
+  sweepPresenceFlags: __CallReducerFlags = 'FullUpdate';
+  sweepPresence(flags: __CallReducerFlags) {
+    this.sweepPresenceFlags = flags;
+  }
+
+  terminateSessionFlags: __CallReducerFlags = 'FullUpdate';
+  terminateSession(flags: __CallReducerFlags) {
+    this.terminateSessionFlags = flags;
+  }
+
+  transferOwnershipFlags: __CallReducerFlags = 'FullUpdate';
+  transferOwnership(flags: __CallReducerFlags) {
+    this.transferOwnershipFlags = flags;
+  }
+
+  unarchiveGroupchatFlags: __CallReducerFlags = 'FullUpdate';
+  unarchiveGroupchat(flags: __CallReducerFlags) {
+    this.unarchiveGroupchatFlags = flags;
+  }
+
+  unbanMemberFlags: __CallReducerFlags = 'FullUpdate';
+  unbanMember(flags: __CallReducerFlags) {
+    this.unbanMemberFlags = flags;
+  }
+
+  unblockUserFlags: __CallReducerFlags = 'FullUpdate';
+  unblockUser(flags: __CallReducerFlags) {
+    this.unblockUserFlags = flags;
+  }
+
+  unmuteMemberFlags: __CallReducerFlags = 'FullUpdate';
+  unmuteMember(flags: __CallReducerFlags) {
+    this.unmuteMemberFlags = flags;
+  }
+
+  unpinMessageFlags: __CallReducerFlags = 'FullUpdate';
+  unpinMessage(flags: __CallReducerFlags) {
+    this.unpinMessageFlags = flags;
+  }
+
+  unsendMessageFlags: __CallReducerFlags = 'FullUpdate';
+  unsendMessage(flags: __CallReducerFlags) {
+    this.unsendMessageFlags = flags;
+  }
+
+  updateConfigFlags: __CallReducerFlags = 'FullUpdate';
+  updateConfig(flags: __CallReducerFlags) {
+    this.updateConfigFlags = flags;
+  }
+
+  updateProfileFlags: __CallReducerFlags = 'FullUpdate';
+  updateProfile(flags: __CallReducerFlags) {
+    this.updateProfileFlags = flags;
+  }
+
+  updateSettingsFlags: __CallReducerFlags = 'FullUpdate';
+  updateSettings(flags: __CallReducerFlags) {
+    this.updateSettingsFlags = flags;
+  }
+
+  voteFlags: __CallReducerFlags = 'FullUpdate';
+  vote(flags: __CallReducerFlags) {
+    this.voteFlags = flags;
+  }
+
+  warnMemberFlags: __CallReducerFlags = 'FullUpdate';
+  warnMember(flags: __CallReducerFlags) {
+    this.warnMemberFlags = flags;
+  }
+
+}
+
+export class RemoteTables {
+  constructor(private connection: __DbConnectionImpl) {}
+
+  get announcement(): AnnouncementTableHandle<'announcement'> {
+    // clientCache is a private property
+    return new AnnouncementTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Announcement>(REMOTE_MODULE.tables.announcement));
+  }
+
+  get attachment(): AttachmentTableHandle<'attachment'> {
+    // clientCache is a private property
+    return new AttachmentTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Attachment>(REMOTE_MODULE.tables.attachment));
+  }
+
+  get auditLog(): AuditLogTableHandle<'audit_log'> {
+    // clientCache is a private property
+    return new AuditLogTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<AuditLog>(REMOTE_MODULE.tables.audit_log));
   }
-}
 
-// A type representing all the possible variants of a reducer.
-export type Reducer = never
-| { name: "CreateGroupchat", args: CreateGroupchat }
-| { name: "IdentityConnected", args: IdentityConnected }
-| { name: "IdentityDisconnected", args: IdentityDisconnected }
-| { name: "JoinGroupchat", args: JoinGroupchat }
-| { name: "SendMessage", args: SendMessage }
-| { name: "SetName", args: SetName }
-;
+  get awardedBadge(): AwardedBadgeTableHandle<'awarded_badge'> {
+    // clientCache is a private property
+    return new AwardedBadgeTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<AwardedBadge>(REMOTE_MODULE.tables.awarded_badge));
+  }
 
-export class RemoteReducers {
-  constructor(private connection: __DbConnectionImpl, private setCallReducerFlags: SetReducerFlags) {}
+  get badge(): BadgeTableHandle<'badge'> {
+    // clientCache is a private property
+    return new BadgeTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Badge>(REMOTE_MODULE.tables.badge));
+  }
 
-  createGroupchat(name: string) {
-    const __args = { name };
-    let __writer = new __BinaryWriter(1024);
-    CreateGroupchat.serialize(__writer, __args);
-    let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("create_groupchat", __argsBuffer, this.setCallReducerFlags.createGroupchatFlags);
+  get bannedWord(): BannedWordTableHandle<'banned_word'> {
+    // clientCache is a private property
+    return new BannedWordTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<BannedWord>(REMOTE_MODULE.tables.banned_word));
   }
 
-  onCreateGroupchat(callback: (ctx: ReducerEventContext, name: string) => void) {
-    this.connection.onReducer("create_groupchat", callback);
+  get block(): BlockTableHandle<'block'> {
+    // clientCache is a private property
+    return new BlockTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Block>(REMOTE_MODULE.tables.block));
   }
 
-  removeOnCreateGroupchat(callback: (ctx: ReducerEventContext, name: string) => void) {
-    this.connection.offReducer("create_groupchat", callback);
+  get bookmark(): BookmarkTableHandle<'bookmark'> {
+    // clientCache is a private property
+    return new BookmarkTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Bookmark>(REMOTE_MODULE.tables.bookmark));
   }
 
-  onIdentityConnected(callback: (ctx: ReducerEventContext) => void) {
-    this.connection.onReducer("identity_connected", callback);
+  get challenge(): ChallengeTableHandle<'challenge'> {
+    // clientCache is a private property
+    return new ChallengeTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Challenge>(REMOTE_MODULE.tables.challenge));
   }
 
-  removeOnIdentityConnected(callback: (ctx: ReducerEventContext) => void) {
-    this.connection.offReducer("identity_connected", callback);
+  get challengeAnswer(): ChallengeAnswerTableHandle<'challenge_answer'> {
+    // clientCache is a private property
+    return new ChallengeAnswerTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ChallengeAnswer>(REMOTE_MODULE.tables.challenge_answer));
   }
 
-  onIdentityDisconnected(callback: (ctx: ReducerEventContext) => void) {
-    this.connection.onReducer("identity_disconnected", callback);
+  get chatFolder(): ChatFolderTableHandle<'chat_folder'> {
+    // clientCache is a private property
+    return new ChatFolderTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ChatFolder>(REMOTE_MODULE.tables.chat_folder));
   }
 
-  removeOnIdentityDisconnected(callback: (ctx: ReducerEventContext) => void) {
-    this.connection.offReducer("identity_disconnected", callback);
+  get config(): ConfigTableHandle<'config'> {
+    // clientCache is a private property
+    return new ConfigTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Config>(REMOTE_MODULE.tables.config));
   }
 
-  joinGroupchat(groupchat: string) {
-    const __args = { groupchat };
-    let __writer = new __BinaryWriter(1024);
-    JoinGroupchat.serialize(__writer, __args);
-    let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("join_groupchat", __argsBuffer, this.setCallReducerFlags.joinGroupchatFlags);
+  get customEmoji(): CustomEmojiTableHandle<'custom_emoji'> {
+    // clientCache is a private property
+    return new CustomEmojiTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<CustomEmoji>(REMOTE_MODULE.tables.custom_emoji));
   }
 
-  onJoinGroupchat(callback: (ctx: ReducerEventContext, groupchat: string) => void) {
-    this.connection.onReducer("join_groupchat", callback);
+  get dailyDigest(): DailyDigestTableHandle<'daily_digest'> {
+    // clientCache is a private property
+    return new DailyDigestTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<DailyDigest>(REMOTE_MODULE.tables.daily_digest));
   }
 
-  removeOnJoinGroupchat(callback: (ctx: ReducerEventContext, groupchat: string) => void) {
-    this.connection.offReducer("join_groupchat", callback);
+  get dailyDigestSchedule(): DailyDigestScheduleTableHandle<'daily_digest_schedule'> {
+    // clientCache is a private property
+    return new DailyDigestScheduleTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<DailyDigestSchedule>(REMOTE_MODULE.tables.daily_digest_schedule));
   }
 
-  sendMessage(groupchat: string, text: string) {
-    const __args = { groupchat, text };
-    let __writer = new __BinaryWriter(1024);
-    SendMessage.serialize(__writer, __args);
-    let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("send_message", __argsBuffer, this.setCallReducerFlags.sendMessageFlags);
+  get dataExport(): DataExportTableHandle<'data_export'> {
+    // clientCache is a private property
+    return new DataExportTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<DataExport>(REMOTE_MODULE.tables.data_export));
   }
 
-  onSendMessage(callback: (ctx: ReducerEventContext, groupchat: string, text: string) => void) {
-    this.connection.onReducer("send_message", callback);
+  get directConversation(): DirectConversationTableHandle<'direct_conversation'> {
+    // clientCache is a private property
+    return new DirectConversationTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<DirectConversation>(REMOTE_MODULE.tables.direct_conversation));
   }
 
-  removeOnSendMessage(callback: (ctx: ReducerEventContext, groupchat: string, text: string) => void) {
-    this.connection.offReducer("send_message", callback);
+  get dmMessage(): DmMessageTableHandle<'dm_message'> {
+    // clientCache is a private property
+    return new DmMessageTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<DmMessage>(REMOTE_MODULE.tables.dm_message));
   }
 
-  setName(name: string) {
-    const __args = { name };
-    let __writer = new __BinaryWriter(1024);
-    SetName.serialize(__writer, __args);
-    let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("set_name", __argsBuffer, this.setCallReducerFlags.setNameFlags);
+  get draft(): DraftTableHandle<'draft'> {
+    // clientCache is a private property
+    return new DraftTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Draft>(REMOTE_MODULE.tables.draft));
   }
 
-  onSetName(callback: (ctx: ReducerEventContext, name: string) => void) {
-    this.connection.onReducer("set_name", callback);
+  get eventReminder(): EventReminderTableHandle<'event_reminder'> {
+    // clientCache is a private property
+    return new EventReminderTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<EventReminder>(REMOTE_MODULE.tables.event_reminder));
   }
 
-  removeOnSetName(callback: (ctx: ReducerEventContext, name: string) => void) {
-    this.connection.offReducer("set_name", callback);
+  get eventRsvp(): EventRsvpTableHandle<'event_rsvp'> {
+    // clientCache is a private property
+    return new EventRsvpTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<EventRsvp>(REMOTE_MODULE.tables.event_rsvp));
   }
 
-}
+  get folderMembership(): FolderMembershipTableHandle<'folder_membership'> {
+    // clientCache is a private property
+    return new FolderMembershipTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<FolderMembership>(REMOTE_MODULE.tables.folder_membership));
+  }
 
-export class SetReducerFlags {
-  createGroupchatFlags: __CallReducerFlags = 'FullUpdate';
-  createGroupchat(flags: __CallReducerFlags) {
-    this.createGroupchatFlags = flags;
+  get friendRequest(): FriendRequestTableHandle<'friend_request'> {
+    // clientCache is a private property
+    return new FriendRequestTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<FriendRequest>(REMOTE_MODULE.tables.friend_request));
   }
 
-  joinGroupchatFlags: __CallReducerFlags = 'FullUpdate';
-  joinGroupchat(flags: __CallReducerFlags) {
-    this.joinGroupchatFlags = flags;
+  get friendship(): FriendshipTableHandle<'friendship'> {
+    // clientCache is a private property
+    return new FriendshipTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Friendship>(REMOTE_MODULE.tables.friendship));
   }
 
-  sendMessageFlags: __CallReducerFlags = 'FullUpdate';
-  sendMessage(flags: __CallReducerFlags) {
-    this.sendMessageFlags = flags;
+  get groupCreationLimit(): GroupCreationLimitTableHandle<'group_creation_limit'> {
+    // clientCache is a private property
+    return new GroupCreationLimitTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupCreationLimit>(REMOTE_MODULE.tables.group_creation_limit));
   }
 
-  setNameFlags: __CallReducerFlags = 'FullUpdate';
-  setName(flags: __CallReducerFlags) {
-    this.setNameFlags = flags;
+  get groupEvent(): GroupEventTableHandle<'group_event'> {
+    // clientCache is a private property
+    return new GroupEventTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupEvent>(REMOTE_MODULE.tables.group_event));
   }
 
-}
+  get groupJoinCode(): GroupJoinCodeTableHandle<'group_join_code'> {
+    // clientCache is a private property
+    return new GroupJoinCodeTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupJoinCode>(REMOTE_MODULE.tables.group_join_code));
+  }
 
-export class RemoteTables {
-  constructor(private connection: __DbConnectionImpl) {}
+  get groupKey(): GroupKeyTableHandle<'group_key'> {
+    // clientCache is a private property
+    return new GroupKeyTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupKey>(REMOTE_MODULE.tables.group_key));
+  }
+
+  get groupKeyEpoch(): GroupKeyEpochTableHandle<'group_key_epoch'> {
+    // clientCache is a private property
+    return new GroupKeyEpochTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupKeyEpoch>(REMOTE_MODULE.tables.group_key_epoch));
+  }
+
+  get groupPermission(): GroupPermissionTableHandle<'group_permission'> {
+    // clientCache is a private property
+    return new GroupPermissionTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupPermission>(REMOTE_MODULE.tables.group_permission));
+  }
+
+  get groupStats(): GroupStatsTableHandle<'group_stats'> {
+    // clientCache is a private property
+    return new GroupStatsTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupStats>(REMOTE_MODULE.tables.group_stats));
+  }
+
+  get groupStatsRollup(): GroupStatsRollupTableHandle<'group_stats_rollup'> {
+    // clientCache is a private property
+    return new GroupStatsRollupTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupStatsRollup>(REMOTE_MODULE.tables.group_stats_rollup));
+  }
+
+  get groupTag(): GroupTagTableHandle<'group_tag'> {
+    // clientCache is a private property
+    return new GroupTagTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupTag>(REMOTE_MODULE.tables.group_tag));
+  }
 
   get groupchat(): GroupchatTableHandle<'groupchat'> {
     // clientCache is a private property
     return new GroupchatTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupChat>(REMOTE_MODULE.tables.groupchat));
   }
 
+  get groupchatBan(): GroupchatBanTableHandle<'groupchat_ban'> {
+    // clientCache is a private property
+    return new GroupchatBanTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupChatBan>(REMOTE_MODULE.tables.groupchat_ban));
+  }
+
+  get groupchatInvite(): GroupchatInviteTableHandle<'groupchat_invite'> {
+    // clientCache is a private property
+    return new GroupchatInviteTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupChatInvite>(REMOTE_MODULE.tables.groupchat_invite));
+  }
+
   get groupchatMembership(): GroupchatMembershipTableHandle<'groupchat_membership'> {
     // clientCache is a private property
     return new GroupchatMembershipTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GroupChatMembership>(REMOTE_MODULE.tables.groupchat_membership));
   }
 
+  get joinCodeAttempt(): JoinCodeAttemptTableHandle<'join_code_attempt'> {
+    // clientCache is a private property
+    return new JoinCodeAttemptTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<JoinCodeAttempt>(REMOTE_MODULE.tables.join_code_attempt));
+  }
+
+  get joinRequest(): JoinRequestTableHandle<'join_request'> {
+    // clientCache is a private property
+    return new JoinRequestTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<JoinRequest>(REMOTE_MODULE.tables.join_request));
+  }
+
+  get karma(): KarmaTableHandle<'karma'> {
+    // clientCache is a private property
+    return new KarmaTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Karma>(REMOTE_MODULE.tables.karma));
+  }
+
+  get karmaTransaction(): KarmaTransactionTableHandle<'karma_transaction'> {
+    // clientCache is a private property
+    return new KarmaTransactionTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<KarmaTransaction>(REMOTE_MODULE.tables.karma_transaction));
+  }
+
+  get linkToken(): LinkTokenTableHandle<'link_token'> {
+    // clientCache is a private property
+    return new LinkTokenTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<LinkToken>(REMOTE_MODULE.tables.link_token));
+  }
+
+  get linkedDevice(): LinkedDeviceTableHandle<'linked_device'> {
+    // clientCache is a private property
+    return new LinkedDeviceTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<LinkedDevice>(REMOTE_MODULE.tables.linked_device));
+  }
+
+  get memberStats(): MemberStatsTableHandle<'member_stats'> {
+    // clientCache is a private property
+    return new MemberStatsTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<MemberStats>(REMOTE_MODULE.tables.member_stats));
+  }
+
+  get mention(): MentionTableHandle<'mention'> {
+    // clientCache is a private property
+    return new MentionTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Mention>(REMOTE_MODULE.tables.mention));
+  }
+
   get message(): MessageTableHandle<'message'> {
     // clientCache is a private property
     return new MessageTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Message>(REMOTE_MODULE.tables.message));
   }
 
+  get messageEdit(): MessageEditTableHandle<'message_edit'> {
+    // clientCache is a private property
+    return new MessageEditTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<MessageEdit>(REMOTE_MODULE.tables.message_edit));
+  }
+
+  get messageExpirySweep(): MessageExpirySweepTableHandle<'message_expiry_sweep'> {
+    // clientCache is a private property
+    return new MessageExpirySweepTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<MessageExpirySweep>(REMOTE_MODULE.tables.message_expiry_sweep));
+  }
+
+  get messageReceipt(): MessageReceiptTableHandle<'message_receipt'> {
+    // clientCache is a private property
+    return new MessageReceiptTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<MessageReceipt>(REMOTE_MODULE.tables.message_receipt));
+  }
+
+  get mute(): MuteTableHandle<'mute'> {
+    // clientCache is a private property
+    return new MuteTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Mute>(REMOTE_MODULE.tables.mute));
+  }
+
+  get muteExpirySweep(): MuteExpirySweepTableHandle<'mute_expiry_sweep'> {
+    // clientCache is a private property
+    return new MuteExpirySweepTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<MuteExpirySweep>(REMOTE_MODULE.tables.mute_expiry_sweep));
+  }
+
+  get notification(): NotificationTableHandle<'notification'> {
+    // clientCache is a private property
+    return new NotificationTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Notification>(REMOTE_MODULE.tables.notification));
+  }
+
+  get notificationPref(): NotificationPrefTableHandle<'notification_pref'> {
+    // clientCache is a private property
+    return new NotificationPrefTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<NotificationPref>(REMOTE_MODULE.tables.notification_pref));
+  }
+
+  get pinnedMessage(): PinnedMessageTableHandle<'pinned_message'> {
+    // clientCache is a private property
+    return new PinnedMessageTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<PinnedMessage>(REMOTE_MODULE.tables.pinned_message));
+  }
+
+  get poll(): PollTableHandle<'poll'> {
+    // clientCache is a private property
+    return new PollTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Poll>(REMOTE_MODULE.tables.poll));
+  }
+
+  get pollVote(): PollVoteTableHandle<'poll_vote'> {
+    // clientCache is a private property
+    return new PollVoteTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<PollVote>(REMOTE_MODULE.tables.poll_vote));
+  }
+
+  get presencePreference(): PresencePreferenceTableHandle<'presence_preference'> {
+    // clientCache is a private property
+    return new PresencePreferenceTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<PresencePreference>(REMOTE_MODULE.tables.presence_preference));
+  }
+
+  get presenceSweep(): PresenceSweepTableHandle<'presence_sweep'> {
+    // clientCache is a private property
+    return new PresenceSweepTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<PresenceSweep>(REMOTE_MODULE.tables.presence_sweep));
+  }
+
+  get rateLimit(): RateLimitTableHandle<'rate_limit'> {
+    // clientCache is a private property
+    return new RateLimitTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<RateLimit>(REMOTE_MODULE.tables.rate_limit));
+  }
+
+  get reaction(): ReactionTableHandle<'reaction'> {
+    // clientCache is a private property
+    return new ReactionTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Reaction>(REMOTE_MODULE.tables.reaction));
+  }
+
+  get reactionCount(): ReactionCountTableHandle<'reaction_count'> {
+    // clientCache is a private property
+    return new ReactionCountTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ReactionCount>(REMOTE_MODULE.tables.reaction_count));
+  }
+
+  get readState(): ReadStateTableHandle<'read_state'> {
+    // clientCache is a private property
+    return new ReadStateTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ReadState>(REMOTE_MODULE.tables.read_state));
+  }
+
+  get reminder(): ReminderTableHandle<'reminder'> {
+    // clientCache is a private property
+    return new ReminderTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Reminder>(REMOTE_MODULE.tables.reminder));
+  }
+
+  get report(): ReportTableHandle<'report'> {
+    // clientCache is a private property
+    return new ReportTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Report>(REMOTE_MODULE.tables.report));
+  }
+
+  get retentionCleanup(): RetentionCleanupTableHandle<'retention_cleanup'> {
+    // clientCache is a private property
+    return new RetentionCleanupTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<RetentionCleanup>(REMOTE_MODULE.tables.retention_cleanup));
+  }
+
+  get searchResult(): SearchResultTableHandle<'search_result'> {
+    // clientCache is a private property
+    return new SearchResultTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<SearchResult>(REMOTE_MODULE.tables.search_result));
+  }
+
+  get sentClientId(): SentClientIdTableHandle<'sent_client_id'> {
+    // clientCache is a private property
+    return new SentClientIdTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<SentClientId>(REMOTE_MODULE.tables.sent_client_id));
+  }
+
+  get session(): SessionTableHandle<'session'> {
+    // clientCache is a private property
+    return new SessionTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Session>(REMOTE_MODULE.tables.session));
+  }
+
+  get streak(): StreakTableHandle<'streak'> {
+    // clientCache is a private property
+    return new StreakTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Streak>(REMOTE_MODULE.tables.streak));
+  }
+
+  get streakResetSweep(): StreakResetSweepTableHandle<'streak_reset_sweep'> {
+    // clientCache is a private property
+    return new StreakResetSweepTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<StreakResetSweep>(REMOTE_MODULE.tables.streak_reset_sweep));
+  }
+
+  get superadmin(): SuperadminTableHandle<'superadmin'> {
+    // clientCache is a private property
+    return new SuperadminTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Superadmin>(REMOTE_MODULE.tables.superadmin));
+  }
+
+  get threadMeta(): ThreadMetaTableHandle<'thread_meta'> {
+    // clientCache is a private property
+    return new ThreadMetaTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ThreadMeta>(REMOTE_MODULE.tables.thread_meta));
+  }
+
+  get topic(): TopicTableHandle<'topic'> {
+    // clientCache is a private property
+    return new TopicTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Topic>(REMOTE_MODULE.tables.topic));
+  }
+
+  get translationCache(): TranslationCacheTableHandle<'translation_cache'> {
+    // clientCache is a private property
+    return new TranslationCacheTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<TranslationCache>(REMOTE_MODULE.tables.translation_cache));
+  }
+
+  get typingIndicator(): TypingIndicatorTableHandle<'typing_indicator'> {
+    // clientCache is a private property
+    return new TypingIndicatorTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<TypingIndicator>(REMOTE_MODULE.tables.typing_indicator));
+  }
+
+  get typingIndicatorCleanup(): TypingIndicatorCleanupTableHandle<'typing_indicator_cleanup'> {
+    // clientCache is a private property
+    return new TypingIndicatorCleanupTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<TypingIndicatorCleanup>(REMOTE_MODULE.tables.typing_indicator_cleanup));
+  }
+
   get user(): UserTableHandle<'user'> {
     // clientCache is a private property
     return new UserTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<User>(REMOTE_MODULE.tables.user));
   }
+
+  get userSearchResult(): UserSearchResultTableHandle<'user_search_result'> {
+    // clientCache is a private property
+    return new UserSearchResultTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<UserSearchResult>(REMOTE_MODULE.tables.user_search_result));
+  }
+
+  get userSettings(): UserSettingsTableHandle<'user_settings'> {
+    // clientCache is a private property
+    return new UserSettingsTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<UserSettings>(REMOTE_MODULE.tables.user_settings));
+  }
+
+  get username(): UsernameTableHandle<'username'> {
+    // clientCache is a private property
+    return new UsernameTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Username>(REMOTE_MODULE.tables.username));
+  }
+
+  get warning(): WarningTableHandle<'warning'> {
+    // clientCache is a private property
+    return new WarningTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Warning>(REMOTE_MODULE.tables.warning));
+  }
+
+  get webhook(): WebhookTableHandle<'webhook'> {
+    // clientCache is a private property
+    return new WebhookTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Webhook>(REMOTE_MODULE.tables.webhook));
+  }
+
+  get webhookOutbox(): WebhookOutboxTableHandle<'webhook_outbox'> {
+    // clientCache is a private property
+    return new WebhookOutboxTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<WebhookOutbox>(REMOTE_MODULE.tables.webhook_outbox));
+  }
 }
 
 export class SubscriptionBuilder extends __SubscriptionBuilderImpl<RemoteTables, RemoteReducers, SetReducerFlags> { }
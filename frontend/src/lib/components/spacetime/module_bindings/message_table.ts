@@ -57,6 +57,28 @@ export class MessageTableHandle<TableName extends string> implements __TableHand
   iter(): Iterable<Message> {
     return this.tableCache.iter();
   }
+  /**
+   * Access to the `id` unique index on the table `message`,
+   * which allows point queries on the field of the same name
+   * via the [`MessageIdUnique.find`] method.
+   *
+   * Users are encouraged not to explicitly reference this type,
+   * but to directly chain method calls,
+   * like `ctx.db.message.id().find(...)`.
+   *
+   * Get a handle on the `id` unique index on the table `message`.
+   */
+  id = {
+    // Find the subscribed row whose `id` column value is equal to `col_val`,
+    // if such a row is present in the client cache.
+    find: (col_val: bigint): Message | undefined => {
+      for (let row of this.tableCache.iter()) {
+        if (__deepEqual(row.id, col_val)) {
+          return row;
+        }
+      }
+    },
+  };
 
   onInsert = (cb: (ctx: EventContext, row: Message) => void) => {
     return this.tableCache.onInsert(cb);
@@ -73,4 +95,12 @@ export class MessageTableHandle<TableName extends string> implements __TableHand
   removeOnDelete = (cb: (ctx: EventContext, row: Message) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
-}
+
+  // Updates are only defined for tables with primary keys.
+  onUpdate = (cb: (ctx: EventContext, oldRow: Message, newRow: Message) => void) => {
+    return this.tableCache.onUpdate(cb);
+  }
+
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: Message, newRow: Message) => void) => {
+    return this.tableCache.removeOnUpdate(cb);
+  }}
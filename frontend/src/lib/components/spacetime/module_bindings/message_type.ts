@@ -27,12 +27,32 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
+import { MessageKind } from "./message_kind_type";
+// Mark import as potentially unused
+declare type __keep_MessageKind = MessageKind;
 
 export type Message = {
+  id: bigint,
   sender: __Identity,
   sent: __Timestamp,
   text: string,
   groupchatId: string,
+  editedAt: __Timestamp | undefined,
+  deleted: boolean,
+  replyTo: bigint | undefined,
+  seq: bigint,
+  pollId: bigint | undefined,
+  forwardedFrom: bigint | undefined,
+  originalSender: __Identity | undefined,
+  importedSender: string | undefined,
+  kind: MessageKind,
+  clientId: bigint | undefined,
+  topicId: bigint | undefined,
+  lang: string | undefined,
+  ciphertext: Uint8Array | undefined,
+  nonce: Uint8Array | undefined,
+  keyId: string | undefined,
+  day: number,
 };
 let _cached_Message_type_value: __AlgebraicTypeType | null = null;
 
@@ -48,10 +68,27 @@ export const Message = {
     if (_cached_Message_type_value) return _cached_Message_type_value;
     _cached_Message_type_value = __AlgebraicTypeValue.Product({ elements: [] });
     _cached_Message_type_value.value.elements.push(
+      { name: "id", algebraicType: __AlgebraicTypeValue.U64 },
       { name: "sender", algebraicType: __AlgebraicTypeValue.createIdentityType() },
       { name: "sent", algebraicType: __AlgebraicTypeValue.createTimestampType() },
       { name: "text", algebraicType: __AlgebraicTypeValue.String },
       { name: "groupchatId", algebraicType: __AlgebraicTypeValue.String },
+      { name: "editedAt", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.createTimestampType()) },
+      { name: "deleted", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "replyTo", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U64) },
+      { name: "seq", algebraicType: __AlgebraicTypeValue.U64 },
+      { name: "pollId", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U64) },
+      { name: "forwardedFrom", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U64) },
+      { name: "originalSender", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.createIdentityType()) },
+      { name: "importedSender", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "kind", algebraicType: MessageKind.getTypeScriptAlgebraicType() },
+      { name: "clientId", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U128) },
+      { name: "topicId", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U64) },
+      { name: "lang", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "ciphertext", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.Array(__AlgebraicTypeValue.U8)) },
+      { name: "nonce", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.Array(__AlgebraicTypeValue.U8)) },
+      { name: "keyId", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "day", algebraicType: __AlgebraicTypeValue.U32 },
     );
     return _cached_Message_type_value;
   },
@@ -31,6 +31,9 @@ import {
 export type SendMessage = {
   groupchat: string,
   text: string,
+  clientId: bigint | undefined,
+  topicId: bigint | undefined,
+  lang: string | undefined,
 };
 let _cached_SendMessage_type_value: __AlgebraicTypeType | null = null;
 
@@ -48,6 +51,9 @@ export const SendMessage = {
     _cached_SendMessage_type_value.value.elements.push(
       { name: "groupchat", algebraicType: __AlgebraicTypeValue.String },
       { name: "text", algebraicType: __AlgebraicTypeValue.String },
+      { name: "clientId", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U128) },
+      { name: "topicId", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U64) },
+      { name: "lang", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
     );
     return _cached_SendMessage_type_value;
   },
@@ -27,10 +27,22 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
+import { Presence } from "./presence_type";
+// Mark import as potentially unused
+declare type __keep_Presence = Presence;
 
 export type User = {
   identity: __Identity,
   name: string | undefined,
+  presence: Presence,
+  lastSeen: __Timestamp,
+  lastActive: __Timestamp,
+  bio: string | undefined,
+  avatarUrl: string | undefined,
+  status: string | undefined,
+  isBot: boolean,
+  verified: boolean,
+  tosAcceptedVersion: number,
 };
 let _cached_User_type_value: __AlgebraicTypeType | null = null;
 
@@ -48,6 +60,15 @@ export const User = {
     _cached_User_type_value.value.elements.push(
       { name: "identity", algebraicType: __AlgebraicTypeValue.createIdentityType() },
       { name: "name", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "presence", algebraicType: Presence.getTypeScriptAlgebraicType() },
+      { name: "lastSeen", algebraicType: __AlgebraicTypeValue.createTimestampType() },
+      { name: "lastActive", algebraicType: __AlgebraicTypeValue.createTimestampType() },
+      { name: "bio", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "avatarUrl", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "status", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.String) },
+      { name: "isBot", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "verified", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "tosAcceptedVersion", algebraicType: __AlgebraicTypeValue.U32 },
     );
     return _cached_User_type_value;
   },